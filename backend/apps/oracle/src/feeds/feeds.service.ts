@@ -2,6 +2,7 @@ import { Injectable, Logger } from '@nestjs/common';
 import { OracleSdkService } from '../oracle-sdk.service';
 import { RegisterFeedDto } from './dto/register-feed.dto';
 import { Keypair } from '@solana/web3.js';
+import { SourceKind } from '@stbr/sss-token';
 import bs58 from 'bs58';
 import { ConfigService } from '@nestjs/config';
 
@@ -38,7 +39,7 @@ export class FeedsService {
         baseCurrency: dto.baseCurrency,
         quoteCurrency: dto.quoteCurrency,
         decimals: dto.decimals,
-        switchboardFeed: dto.switchboardFeed,
+        sources: [{ account: dto.switchboardFeed, kind: SourceKind.Switchboard }],
       }
     );
 
@@ -154,7 +154,7 @@ pub fn remove_from_blacklist(ctx: Context<RemoveFromBlacklist>, target: Pubkey)
     entry.removed_by = Some(ctx.accounts.blacklister.key());
     entry.removed_at = Some(Clock::get()?.unix_timestamp);
 
-    config.blacklist_count = config.blacklist_count.checked_sub(1).unwrap_or(0);
+    config.blacklist_count = config.blacklist_count.saturating_sub(1);
 
     let mint_key = config.mint.key();
     let config_bump = config.bump;
@@ -34,7 +34,7 @@ pub fn burn(ctx: Context<BurnTokens>, amount: u64) -> Result<()> {
     require!(!config.paused, SSSError::Paused);
     require!(amount > 0, SSSError::ZeroAmount);
 
-    config.total_supply = config.total_supply.checked_sub(amount).unwrap_or(0);
+    config.total_supply = config.total_supply.saturating_sub(amount);
     config.total_burned_all_time = config.total_burned_all_time.checked_add(amount).unwrap();
 
     // Analytics counters
@@ -156,13 +156,13 @@ impl AllowlistEntry {
 
     pub fn is_valid_for_send(&self) -> bool {
         self.active
-            && (self.expiry == 0 || Clock::get().map_or(false, |c| c.unix_timestamp < self.expiry))
+            && (self.expiry == 0 || Clock::get().is_ok_and(|c| c.unix_timestamp < self.expiry))
             && (self.allowed_operations & allowlist_ops::SEND != 0)
     }
 
     pub fn is_valid_for_receive(&self) -> bool {
         self.active
-            && (self.expiry == 0 || Clock::get().map_or(false, |c| c.unix_timestamp < self.expiry))
+            && (self.expiry == 0 || Clock::get().is_ok_and(|c| c.unix_timestamp < self.expiry))
             && (self.allowed_operations & allowlist_ops::RECEIVE != 0)
     }
 }
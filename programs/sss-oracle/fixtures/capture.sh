@@ -0,0 +1,26 @@
+#!/usr/bin/env bash
+# Capture a live account as a test fixture.
+#
+#   ./capture.sh <cluster-url> <address> <name>
+#
+# Writes <name>.json (the `solana account --output json` dump, kept as the
+# record of what was captured), <name>.bin (its decoded data, which the unit
+# tests `include_bytes!`) and prints the README row with the address and the
+# slot the dump was taken at. Needs the Solana CLI and jq.
+set -euo pipefail
+
+if [ $# -ne 3 ]; then
+    echo "usage: $0 <cluster-url> <address> <name>" >&2
+    exit 1
+fi
+
+url=$1
+address=$2
+name=$3
+dir=$(cd "$(dirname "$0")" && pwd)
+
+slot=$(solana slot --url "$url")
+solana account "$address" --url "$url" --output json > "$dir/$name.json"
+jq -r '.account.data[0]' "$dir/$name.json" | base64 -d > "$dir/$name.bin"
+
+echo "| \`$name.bin\` | \`$address\` | $url | $slot |"
@@ -0,0 +1,334 @@
+use anchor_lang::prelude::*;
+use crate::errors::OracleError;
+use crate::math::*;
+use crate::pyth::{parse_pyth_price, validate_pyth_price, PythPrice};
+use crate::state::{CompositeLeg, CompositeOp, FeedEntry, FeedType, PriceSource, SourceKind, SourceReport};
+use crate::switchboard::{parse_switchboard_price, validate_switchboard_price, SwitchboardPrice};
+
+// ─── Output type ─────────────────────────────────────────────────────────────
+
+/// Median price across the fresh sources of a feed.
+pub struct AggregatedPrice {
+    /// Median of the used readings (fixed-point, `feed.decimals` decimals)
+    pub price_scaled:     u64,
+    /// Widest confidence among the used readings (same scale as the price).
+    /// For composite feeds, the legs' relative confidences combined.
+    pub conf_scaled:      u64,
+    /// Oldest update timestamp among the used readings (of every leg)
+    pub oldest_timestamp: i64,
+    /// Source accounts that entered the median
+    pub sources_used:     Vec<Pubkey>,
+    /// One report per registered source, in registration order
+    pub reports:          Vec<SourceReport>,
+}
+
+// ─── Public entry point ───────────────────────────────────────────────────────
+
+/// Read every source of `feed` and aggregate them into a single price.
+///
+/// `source_accounts` must be exactly `feed.sources`, in order. Each source is
+/// parsed with its provider's parser (Switchboard or Pyth), rescaled to the
+/// feed's `decimals` with `rounding`, and checked on its own against `max_staleness_secs` and
+/// `max_confidence_bps`; failing sources are reported but left out of the median. The quote is rejected when fewer than
+/// `feed.min_sources` remain, or when their spread exceeds `feed.max_spread_bps`.
+///
+/// # Errors
+/// Returns `OracleError::FeedMismatch`        – accounts don't match `feed.sources`
+/// Returns `OracleError::InsufficientSources` – too few fresh sources
+/// Returns `OracleError::SourceSpreadTooWide` – sources disagree beyond the limit
+/// For single-source feeds, the source's own error (e.g. `PriceTooStale`) is returned.
+///
+/// Composite feeds are priced from their legs instead; see `aggregate_composite`.
+pub fn aggregate_feed_price(
+    feed:               &FeedEntry,
+    source_accounts:    &[AccountInfo],
+    rounding:           Rounding,
+    max_staleness_secs: i64,
+    max_confidence_bps: u16,
+) -> Result<AggregatedPrice> {
+    if let FeedType::Composite { legs, .. } = &feed.feed_type {
+        return aggregate_composite(
+            feed,
+            legs,
+            source_accounts,
+            rounding,
+            max_staleness_secs,
+            max_confidence_bps,
+        );
+    }
+    aggregate_sources(feed, source_accounts, rounding, max_staleness_secs, max_confidence_bps)
+}
+
+/// Number of leading `accounts` that price `feed`: its sources, or for a
+/// composite feed each leg's feed account followed by that leg's sources.
+/// Lets callers pricing several feeds split their remaining accounts.
+pub fn feed_account_span(feed: &FeedEntry, accounts: &[AccountInfo]) -> Result<usize> {
+    let FeedType::Composite { legs, .. } = &feed.feed_type else {
+        return Ok(feed.sources.len());
+    };
+
+    let mut span = 0usize;
+    for leg in legs {
+        let account  = accounts.get(span).ok_or(OracleError::FeedMismatch)?;
+        let leg_feed = load_leg_feed(account, leg)?;
+        span += 1 + leg_feed.sources.len();
+    }
+    Ok(span)
+}
+
+// ─── Composite feeds ─────────────────────────────────────────────────────────
+
+/// Load and check the registered feed behind one composite leg: the `FeedEntry`
+/// PDA of `leg.symbol`, active and not itself composite.
+///
+/// # Errors
+/// Returns `OracleError::FeedMismatch`         – not that symbol's feed PDA
+/// Returns `OracleError::FeedInactive`         – the leg feed was deactivated
+/// Returns `OracleError::InvalidCompositeLegs` – the leg feed is composite
+pub(crate) fn load_leg_feed(account: &AccountInfo, leg: &CompositeLeg) -> Result<FeedEntry> {
+    require!(account.owner == &crate::ID, OracleError::FeedMismatch);
+    let leg_feed = FeedEntry::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+
+    let expected = Pubkey::create_program_address(
+        &[b"sss-feed", leg.symbol.as_bytes(), &[leg_feed.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| error!(OracleError::FeedMismatch))?;
+    require!(
+        leg_feed.symbol == leg.symbol && account.key() == expected,
+        OracleError::FeedMismatch
+    );
+    require!(leg_feed.active, OracleError::FeedInactive);
+    require!(!leg_feed.feed_type.is_composite(), OracleError::InvalidCompositeLegs);
+    Ok(leg_feed)
+}
+
+/// Price a composite feed from its legs.
+///
+/// `accounts` holds, for each leg in order, the leg's `FeedEntry` PDA followed
+/// by exactly that feed's sources. Each leg is aggregated like a plain feed,
+/// under the same staleness and confidence limits, with `Divide` legs rounded
+/// the other way so the composed price rounds with `rounding`. The composite
+/// is then as stale as its oldest leg, and its confidence — the legs'
+/// relative confidences added up — must also stay within `max_confidence_bps`.
+fn aggregate_composite(
+    feed:               &FeedEntry,
+    legs:               &[CompositeLeg],
+    accounts:           &[AccountInfo],
+    rounding:           Rounding,
+    max_staleness_secs: i64,
+    max_confidence_bps: u16,
+) -> Result<AggregatedPrice> {
+    let mut rest         = accounts;
+    let mut leg_prices   = Vec::with_capacity(legs.len());
+    let mut leg_conf_bps = Vec::with_capacity(legs.len());
+    let mut sources_used = Vec::new();
+    let mut reports      = Vec::new();
+    let mut oldest       = i64::MAX;
+
+    for leg in legs {
+        let (leg_account, tail) = rest.split_first().ok_or(OracleError::FeedMismatch)?;
+        let leg_feed = load_leg_feed(leg_account, leg)?;
+        require!(tail.len() >= leg_feed.sources.len(), OracleError::FeedMismatch);
+        let (leg_sources, tail) = tail.split_at(leg_feed.sources.len());
+        rest = tail;
+
+        let leg_rounding = match leg.op {
+            CompositeOp::Multiply => rounding,
+            CompositeOp::Divide   => rounding.reversed(),
+        };
+        let agg = aggregate_sources(
+            &leg_feed,
+            leg_sources,
+            leg_rounding,
+            max_staleness_secs,
+            max_confidence_bps,
+        )?;
+
+        leg_conf_bps.push(confidence_bps(agg.price_scaled, agg.conf_scaled)?);
+        leg_prices.push(LegPrice {
+            price_scaled: agg.price_scaled,
+            decimals:     leg_feed.decimals,
+            op:           leg.op,
+        });
+        oldest = oldest.min(agg.oldest_timestamp);
+        sources_used.extend(agg.sources_used);
+        reports.extend(agg.reports);
+    }
+    require!(rest.is_empty(), OracleError::FeedMismatch);
+
+    let price_scaled = compose_price(&leg_prices, feed.decimals, rounding)?;
+    validate_staleness(oldest, max_staleness_secs)?;
+
+    let conf_bps = combined_confidence_bps(&leg_conf_bps)?;
+    require!(
+        max_confidence_bps == 0 || conf_bps <= max_confidence_bps as u64,
+        OracleError::ConfidenceTooWide
+    );
+    let conf_scaled = div_round(
+        (price_scaled as u128)
+            .checked_mul(conf_bps as u128)
+            .ok_or(OracleError::MathOverflow)?,
+        10_000,
+        Rounding::Up,
+    )?;
+
+    Ok(AggregatedPrice {
+        price_scaled,
+        conf_scaled: u64::try_from(conf_scaled).map_err(|_| error!(OracleError::MathOverflow))?,
+        oldest_timestamp: oldest,
+        sources_used,
+        reports,
+    })
+}
+
+// ─── Plain feeds ─────────────────────────────────────────────────────────────
+
+/// Median of a source-backed feed; see `aggregate_feed_price`.
+fn aggregate_sources(
+    feed:               &FeedEntry,
+    source_accounts:    &[AccountInfo],
+    rounding:           Rounding,
+    max_staleness_secs: i64,
+    max_confidence_bps: u16,
+) -> Result<AggregatedPrice> {
+    require!(
+        feed.matches_sources(source_accounts.iter().map(|a| a.key)),
+        OracleError::FeedMismatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    let mut prices       = Vec::with_capacity(source_accounts.len());
+    let mut sources_used = Vec::with_capacity(source_accounts.len());
+    let mut reports      = Vec::with_capacity(source_accounts.len());
+    let mut conf_scaled  = 0u64;
+    let mut oldest       = i64::MAX;
+    let mut last_err     = None;
+
+    for (account, source) in source_accounts.iter().zip(feed.sources.iter()) {
+        let reading = match read_source(account, source, feed.decimals as u32, rounding) {
+            Ok(reading) => reading,
+            Err(err) => {
+                reports.push(SourceReport {
+                    source:         account.key(),
+                    kind:           source.kind,
+                    price:          0,
+                    confidence_bps: 0,
+                    age_secs:       -1,
+                    used:           false,
+                });
+                last_err = Some(err);
+                continue;
+            }
+        };
+
+        let checks = reading.sanity_check(now)
+            .and_then(|_| validate_staleness(reading.timestamp, max_staleness_secs))
+            .and_then(|_| validate_confidence(reading.price_scaled, reading.conf_scaled, max_confidence_bps));
+
+        reports.push(SourceReport {
+            source:         account.key(),
+            kind:           source.kind,
+            price:          reading.price_scaled,
+            confidence_bps: confidence_bps(reading.price_scaled, reading.conf_scaled).unwrap_or(u64::MAX),
+            age_secs:       now.saturating_sub(reading.timestamp),
+            used:           checks.is_ok(),
+        });
+
+        match checks {
+            Ok(()) => {
+                prices.push(reading.price_scaled);
+                sources_used.push(account.key());
+                conf_scaled = conf_scaled.max(reading.conf_scaled);
+                oldest      = oldest.min(reading.timestamp);
+            }
+            Err(err) => last_err = Some(err),
+        }
+    }
+
+    let required = feed.min_sources.max(1) as usize;
+    if prices.len() < required {
+        // Keep the precise error for single-source feeds
+        if let (1, Some(err)) = (feed.sources.len(), last_err) {
+            return Err(err);
+        }
+        return Err(error!(OracleError::InsufficientSources));
+    }
+
+    let min = *prices.iter().min().ok_or(OracleError::InsufficientSources)?;
+    let max = *prices.iter().max().ok_or(OracleError::InsufficientSources)?;
+    let price_scaled = median(&mut prices, rounding)?;
+    require!(price_scaled > 0, OracleError::InvalidPrice);
+
+    if feed.max_spread_bps > 0 && prices.len() > 1 {
+        require!(
+            spread_bps(min, max, price_scaled)? <= feed.max_spread_bps as u64,
+            OracleError::SourceSpreadTooWide
+        );
+    }
+
+    Ok(AggregatedPrice {
+        price_scaled,
+        conf_scaled,
+        oldest_timestamp: oldest,
+        sources_used,
+        reports,
+    })
+}
+
+// ─── Per-source reading ──────────────────────────────────────────────────────
+
+/// Raw provider reading, kept for the provider-specific sanity checks.
+enum RawPrice {
+    Switchboard(SwitchboardPrice),
+    Pyth(PythPrice),
+}
+
+/// Provider-neutral reading of one source account.
+struct SourceReading {
+    raw:          RawPrice,
+    price_scaled: u64,
+    conf_scaled:  u64,
+    timestamp:    i64,
+}
+
+impl SourceReading {
+    /// Provider-specific hard staleness cap and sanity checks.
+    fn sanity_check(&self, now: i64) -> Result<()> {
+        match &self.raw {
+            RawPrice::Switchboard(price) => validate_switchboard_price(price, now),
+            RawPrice::Pyth(price)        => validate_pyth_price(price, now),
+        }
+    }
+}
+
+fn read_source(
+    account:  &AccountInfo,
+    source:   &PriceSource,
+    decimals: u32,
+    rounding: Rounding,
+) -> Result<SourceReading> {
+    let data = account.try_borrow_data()?;
+
+    match source.kind {
+        SourceKind::Switchboard => {
+            let raw = parse_switchboard_price(&data)?;
+            Ok(SourceReading {
+                price_scaled: raw.value.rescale(decimals, rounding)?,
+                conf_scaled:  raw.std_deviation.abs().rescale(decimals, Rounding::Up)?,
+                timestamp:    raw.round_open_timestamp,
+                raw:          RawPrice::Switchboard(raw),
+            })
+        }
+        SourceKind::Pyth => {
+            let raw = parse_pyth_price(&data)?;
+            Ok(SourceReading {
+                price_scaled: raw.value.rescale(decimals, rounding)?,
+                conf_scaled:  raw.confidence.abs().rescale(decimals, Rounding::Up)?,
+                timestamp:    raw.publish_time,
+                raw:          RawPrice::Pyth(raw),
+            })
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked;
+use crate::errors::OracleError;
+
+// ─── Constants ───────────────────────────────────────────────────────────────
+
+/// Domain prefix of every CPI attestation message, so an attester key cannot
+/// be tricked into signing something that parses as one. v2 binds the
+/// message to one oracle config.
+pub const CPI_ATTESTATION_DOMAIN: &[u8] = b"sss-oracle:cpi-attestation:v2";
+
+/// Native Ed25519 signature-verification program.
+pub const ED25519_PROGRAM_ID: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+
+/// Reference months are `YYYY-MM`.
+pub const REFERENCE_MONTH_LEN: usize = 7;
+
+/// Ed25519 precompile layout: `num_signatures u8, padding u8`, then one
+/// 14-byte offsets record per signature.
+const ED25519_HEADER_LEN: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+const ED25519_PUBKEY_LEN: usize = 32;
+
+/// Instruction index meaning "data lives in this same instruction".
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+// ─── Output type ─────────────────────────────────────────────────────────────
+
+/// One attester's signed CPI multiplier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CpiAttestation {
+    pub attester:   Pubkey,
+    pub multiplier: u64,
+}
+
+// ─── Message format ──────────────────────────────────────────────────────────
+
+/// The message an attester signs for `(oracle_config, reference_month, multiplier)`:
+///
+/// ```text
+/// CPI_ATTESTATION_DOMAIN | oracle_config (32 bytes) | reference_month (7 bytes, "YYYY-MM") | multiplier (u64 LE)
+/// ```
+///
+/// The oracle config PDA pins the attestation to one mint, so a signature for
+/// one CPI-indexed token cannot be replayed on another sharing the attesters.
+pub fn cpi_attestation_message(oracle_config: &Pubkey, reference_month: &str, multiplier: u64) -> Vec<u8> {
+    let mut msg = Vec::with_capacity(CPI_ATTESTATION_DOMAIN.len() + 32 + REFERENCE_MONTH_LEN + 8);
+    msg.extend_from_slice(CPI_ATTESTATION_DOMAIN);
+    msg.extend_from_slice(oracle_config.as_ref());
+    msg.extend_from_slice(reference_month.as_bytes());
+    msg.extend_from_slice(&multiplier.to_le_bytes());
+    msg
+}
+
+/// Parse a CPI attestation message into `(oracle_config, reference_month,
+/// multiplier)`. Returns `None` for any other message.
+pub fn parse_cpi_attestation_message(msg: &[u8]) -> Option<(Pubkey, &str, u64)> {
+    let rest = msg.strip_prefix(CPI_ATTESTATION_DOMAIN)?;
+    if rest.len() != 32 + REFERENCE_MONTH_LEN + 8 {
+        return None;
+    }
+    let (oracle_config, rest) = rest.split_at(32);
+    let (month, multiplier)   = rest.split_at(REFERENCE_MONTH_LEN);
+    let oracle_config = Pubkey::try_from(oracle_config).ok()?;
+    let month         = std::str::from_utf8(month).ok()?;
+    Some((oracle_config, month, u64::from_le_bytes(multiplier.try_into().ok()?)))
+}
+
+/// Whether `month` is a well-formed `YYYY-MM` string. Well-formed months
+/// order correctly as plain strings.
+pub fn is_valid_reference_month(month: &str) -> bool {
+    let b = month.as_bytes();
+    b.len() == REFERENCE_MONTH_LEN
+        && b[..4].iter().all(u8::is_ascii_digit)
+        && b[4] == b'-'
+        && matches!(&b[5..], [b'0', b'1'..=b'9'] | [b'1', b'0'..=b'2'])
+}
+
+// ─── Ed25519 precompile instructions ─────────────────────────────────────────
+
+/// `(public key, message)` of every signature in an Ed25519 precompile
+/// instruction's data.
+///
+/// Only self-contained instructions are accepted: every offset must point
+/// into the instruction itself. The runtime has already verified each
+/// signature by the time the program runs, so a listed pair is a valid
+/// signature of `message` by `public key`.
+///
+/// # Errors
+/// Returns `OracleError::MalformedEd25519Instruction` – truncated data, out-of-range
+/// offsets, or data referenced from another instruction
+pub fn ed25519_signed_messages(data: &[u8]) -> Result<Vec<(Pubkey, &[u8])>> {
+    let malformed = || error!(OracleError::MalformedEd25519Instruction);
+
+    let count = *data.first().ok_or_else(malformed)? as usize;
+    let mut out = Vec::with_capacity(count);
+
+    for i in 0..count {
+        let start   = ED25519_HEADER_LEN + i * ED25519_OFFSETS_LEN;
+        let offsets = data.get(start..start + ED25519_OFFSETS_LEN).ok_or_else(malformed)?;
+        let field   = |n: usize| u16::from_le_bytes([offsets[2 * n], offsets[2 * n + 1]]);
+
+        // signature_offset(0), signature_ix(1), pubkey_offset(2), pubkey_ix(3),
+        // message_offset(4), message_size(5), message_ix(6)
+        require!(
+            field(1) == CURRENT_INSTRUCTION
+                && field(3) == CURRENT_INSTRUCTION
+                && field(6) == CURRENT_INSTRUCTION,
+            OracleError::MalformedEd25519Instruction
+        );
+
+        let pk_start  = field(2) as usize;
+        let msg_start = field(4) as usize;
+        let pubkey    = data
+            .get(pk_start..pk_start + ED25519_PUBKEY_LEN)
+            .ok_or_else(malformed)?;
+        let message   = data
+            .get(msg_start..msg_start + field(5) as usize)
+            .ok_or_else(malformed)?;
+
+        out.push((Pubkey::try_from(pubkey).map_err(|_| malformed())?, message));
+    }
+    Ok(out)
+}
+
+/// Collect the CPI attestations for `oracle_config` and `reference_month`
+/// signed by `attesters` anywhere in the current transaction, at most one
+/// per attester.
+///
+/// Ed25519 signatures by other keys or over other messages, including
+/// attestations for another oracle config, are ignored.
+pub fn collect_cpi_attestations(
+    instructions_sysvar: &AccountInfo,
+    oracle_config:       &Pubkey,
+    attesters:           &[Pubkey],
+    reference_month:     &str,
+) -> Result<Vec<CpiAttestation>> {
+    let mut found: Vec<CpiAttestation> = Vec::with_capacity(attesters.len());
+
+    // load_instruction_at_checked errors past the last instruction
+    let mut index = 0usize;
+    while let Ok(ix) = load_instruction_at_checked(index, instructions_sysvar) {
+        index += 1;
+        if ix.program_id != ED25519_PROGRAM_ID {
+            continue;
+        }
+        for (signer, message) in ed25519_signed_messages(&ix.data)? {
+            let Some((oracle, month, multiplier)) = parse_cpi_attestation_message(message) else {
+                continue;
+            };
+            if oracle == *oracle_config
+                && month == reference_month
+                && attesters.contains(&signer)
+                && !found.iter().any(|a| a.attester == signer)
+            {
+                found.push(CpiAttestation { attester: signer, multiplier });
+            }
+        }
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build self-contained Ed25519 precompile data for `(pubkey, message)`
+    /// pairs, laid out like `solana_sdk::ed25519_instruction` does.
+    fn ed25519_data(entries: &[(Pubkey, Vec<u8>)]) -> Vec<u8> {
+        let mut data = vec![entries.len() as u8, 0];
+        let mut body = Vec::new();
+        let body_start = ED25519_HEADER_LEN + entries.len() * ED25519_OFFSETS_LEN;
+        for (pubkey, message) in entries {
+            let pk_off  = body_start + body.len();
+            body.extend_from_slice(pubkey.as_ref());
+            let sig_off = body_start + body.len();
+            body.extend_from_slice(&[0u8; 64]);
+            let msg_off = body_start + body.len();
+            body.extend_from_slice(message);
+            for v in [
+                sig_off as u16, CURRENT_INSTRUCTION,
+                pk_off as u16, CURRENT_INSTRUCTION,
+                msg_off as u16, message.len() as u16, CURRENT_INSTRUCTION,
+            ] {
+                data.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+        data.extend_from_slice(&body);
+        data
+    }
+
+    #[test]
+    fn test_message_roundtrip() {
+        let oracle = Pubkey::new_unique();
+        let msg = cpi_attestation_message(&oracle, "2026-01", 1_083_000);
+        assert_eq!(parse_cpi_attestation_message(&msg), Some((oracle, "2026-01", 1_083_000)));
+        assert_eq!(parse_cpi_attestation_message(&msg[1..]), None);
+        assert_eq!(parse_cpi_attestation_message(&msg[..msg.len() - 1]), None);
+    }
+
+    #[test]
+    fn test_message_bound_to_oracle() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let msg = cpi_attestation_message(&a, "2026-01", 1_083_000);
+        assert_ne!(msg, cpi_attestation_message(&b, "2026-01", 1_083_000));
+        assert_eq!(parse_cpi_attestation_message(&msg).map(|(o, ..)| o), Some(a));
+
+        // A v1 message (no oracle config) no longer parses
+        let mut v1 = b"sss-oracle:cpi-attestation:v1".to_vec();
+        v1.extend_from_slice(b"2026-01");
+        v1.extend_from_slice(&1_083_000u64.to_le_bytes());
+        assert_eq!(parse_cpi_attestation_message(&v1), None);
+    }
+
+    #[test]
+    fn test_reference_month() {
+        assert!(is_valid_reference_month("2026-01"));
+        assert!(is_valid_reference_month("2026-12"));
+        assert!(!is_valid_reference_month("2026-00"));
+        assert!(!is_valid_reference_month("2026-13"));
+        assert!(!is_valid_reference_month("2026-1"));
+        assert!(!is_valid_reference_month("26-01-1"));
+        assert!("2025-12" < "2026-01");
+    }
+
+    #[test]
+    fn test_parse_ed25519_instruction() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let oracle = Pubkey::new_unique();
+        let ma = cpi_attestation_message(&oracle, "2026-01", 1_083_000);
+        let mb = cpi_attestation_message(&oracle, "2026-01", 1_083_100);
+        let data = ed25519_data(&[(a, ma.clone()), (b, mb.clone())]);
+
+        let signed = ed25519_signed_messages(&data).unwrap();
+        assert_eq!(signed, vec![(a, ma.as_slice()), (b, mb.as_slice())]);
+    }
+
+    #[test]
+    fn test_external_references_rejected() {
+        let data = ed25519_data(&[(Pubkey::new_unique(), cpi_attestation_message(&Pubkey::new_unique(), "2026-01", 1))]);
+        for field in [1usize, 3, 6] {
+            let mut bad = data.clone();
+            let at = ED25519_HEADER_LEN + 2 * field;
+            bad[at..at + 2].copy_from_slice(&0u16.to_le_bytes());
+            assert!(ed25519_signed_messages(&bad).is_err());
+        }
+    }
+
+    #[test]
+    fn test_truncated_rejected() {
+        let data = ed25519_data(&[(Pubkey::new_unique(), cpi_attestation_message(&Pubkey::new_unique(), "2026-01", 1))]);
+        assert!(ed25519_signed_messages(&data[..data.len() - 1]).is_err());
+        assert!(ed25519_signed_messages(&data[..10]).is_err());
+        assert!(ed25519_signed_messages(&[]).is_err());
+    }
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use sss_core::state::{AllowlistEntry, BlacklistEntry, StablecoinConfig};
+use crate::errors::OracleError;
+use crate::state::QuoteDirection;
+
+// ─── sss-core compliance for oracle issuance ────────────────────────────────
+//
+// The oracle mints and burns through sss-core, so the transfer hook never
+// sees the issuance or the burn itself, and SSS-1 mints have no hook at all.
+// These checks apply the same participant rules to the oracle paths: the
+// mint's sss-core `BlacklistEntry` and `AllowlistEntry` PDAs for the quote
+// requester and the recipient, with the hook's `is_valid_for_receive`
+// semantics for tokens going to a wallet and `is_valid_for_send` for tokens
+// leaving one, plus the oracle's own `min_kyc_tier`.
+
+/// Whether oracle issuance for this mint needs participant checks at all:
+/// an SSS-2 blacklist, an SSS-3 allowlist, or a KYC tier floor.
+pub fn compliance_required(core_config: &StablecoinConfig, min_kyc_tier: u8) -> bool {
+    core_config.blacklister.is_some() || core_config.allowlist_active || min_kyc_tier > 0
+}
+
+/// Check one wallet taking part in an oracle mint or redemption.
+///
+/// - Blacklist: when the mint has a blacklister, `blacklist_entry` must be
+///   the wallet's `["sss-blacklist", mint, wallet]` PDA. It may be empty; an
+///   existing entry that has not been removed rejects the wallet.
+/// - Allowlist: when the mint's allowlist is active or `min_kyc_tier > 0`,
+///   `allowlist_entry` must be the wallet's `["sss-allowlist", mint, wallet]`
+///   entry, valid for the direction (active, unexpired, RECEIVE permitted for
+///   `Mint`, SEND for `Redeem`) and at `min_kyc_tier` or above.
+///
+/// # Errors
+/// Returns `OracleError::ComplianceAccountMismatch` – an entry is missing or not the wallet's PDA
+/// Returns `OracleError::ParticipantBlacklisted`    – the wallet is blacklisted for the mint
+/// Returns `OracleError::NotAllowlisted`            – no allowlist entry valid for the direction
+/// Returns `OracleError::KycTierTooLow`             – the entry's tier is below `min_kyc_tier`
+pub fn check_participant(
+    core_config:     &StablecoinConfig,
+    min_kyc_tier:    u8,
+    direction:       &QuoteDirection,
+    wallet:          &Pubkey,
+    allowlist_entry: Option<&AccountInfo>,
+    blacklist_entry: Option<&AccountInfo>,
+) -> Result<()> {
+    let mint = core_config.mint;
+
+    if core_config.blacklister.is_some() {
+        let entry = blacklist_entry.ok_or(OracleError::ComplianceAccountMismatch)?;
+        check_entry_address(entry, b"sss-blacklist", &mint, wallet)?;
+        if !entry.data_is_empty() {
+            let blacklist = load_entry::<BlacklistEntry>(entry)?;
+            require!(blacklist.removed, OracleError::ParticipantBlacklisted);
+        }
+    }
+
+    if core_config.allowlist_active || min_kyc_tier > 0 {
+        let entry = allowlist_entry.ok_or(OracleError::ComplianceAccountMismatch)?;
+        check_entry_address(entry, b"sss-allowlist", &mint, wallet)?;
+        require!(!entry.data_is_empty(), OracleError::NotAllowlisted);
+
+        let allowlist = load_entry::<AllowlistEntry>(entry)?;
+        let valid = match direction {
+            QuoteDirection::Mint   => allowlist.is_valid_for_receive(),
+            QuoteDirection::Redeem => allowlist.is_valid_for_send(),
+        };
+        require!(valid, OracleError::NotAllowlisted);
+        require!(allowlist.kyc_tier >= min_kyc_tier, OracleError::KycTierTooLow);
+    }
+
+    Ok(())
+}
+
+/// `check_participant` for the owner of the recipient token account
+/// `destination`, as the transfer hook keys recipients. The account is only
+/// read when a check applies.
+pub fn check_recipient(
+    core_config:     &StablecoinConfig,
+    min_kyc_tier:    u8,
+    destination:     &AccountInfo,
+    allowlist_entry: Option<&AccountInfo>,
+    blacklist_entry: Option<&AccountInfo>,
+) -> Result<()> {
+    if !compliance_required(core_config, min_kyc_tier) {
+        return Ok(());
+    }
+
+    require!(
+        destination.owner == &anchor_spl::token_2022::ID,
+        OracleError::ComplianceAccountMismatch
+    );
+    let token_account = TokenAccount::try_deserialize(&mut &destination.try_borrow_data()?[..])?;
+    require!(token_account.mint == core_config.mint, OracleError::MintMismatch);
+
+    check_participant(
+        core_config,
+        min_kyc_tier,
+        &QuoteDirection::Mint,
+        &token_account.owner,
+        allowlist_entry,
+        blacklist_entry,
+    )
+}
+
+fn check_entry_address(entry: &AccountInfo, seed: &[u8], mint: &Pubkey, wallet: &Pubkey) -> Result<()> {
+    let (expected, _) = Pubkey::find_program_address(
+        &[seed, mint.as_ref(), wallet.as_ref()],
+        &sss_core::ID,
+    );
+    require!(entry.key() == expected, OracleError::ComplianceAccountMismatch);
+    Ok(())
+}
+
+fn load_entry<T: AccountDeserialize>(entry: &AccountInfo) -> Result<T> {
+    require!(entry.owner == &sss_core::ID, OracleError::ComplianceAccountMismatch);
+    T::try_deserialize(&mut &entry.try_borrow_data()?[..])
+}
@@ -41,6 +41,7 @@ pub enum OracleError {
     #[msg("Feed symbol exceeds maximum length of 12 characters")]
     SymbolTooLong,
 
+    /// Unused since feeds moved to per-symbol PDAs; kept so later codes stay stable
     #[msg("Registry is at maximum capacity (64 feeds)")]
     RegistryFull,
 
@@ -48,7 +49,7 @@ pub enum OracleError {
     #[msg("Oracle is paused — mint and redeem operations are suspended")]
     OraclePaused,
 
-    #[msg("Signer is not the oracle authority")]
+    #[msg("Signer does not hold the oracle role or authority this instruction requires")]
     Unauthorized,
 
     #[msg("No pending authority transfer exists")]
@@ -71,4 +72,231 @@ pub enum OracleError {
     // ── Feed account ────────────────────────────────────────────────────────
     #[msg("Switchboard feed account mismatch — wrong feed passed")]
     FeedMismatch,
+
+    // ── Multi-source aggregation ────────────────────────────────────────────
+    #[msg("A feed needs between 1 and 3 source accounts, and min_sources within that count")]
+    InvalidSourceCount,
+
+    #[msg("The same source account was passed more than once")]
+    DuplicateSource,
+
+    #[msg("Not enough fresh price sources to produce a quote")]
+    InsufficientSources,
+
+    #[msg("Spread between price sources exceeds max_spread_bps")]
+    SourceSpreadTooWide,
+
+    // ── sss-core CPI ────────────────────────────────────────────────────────
+    #[msg("Mint account does not match the oracle config mint")]
+    MintMismatch,
+
+    // ── Settlement ──────────────────────────────────────────────────────────
+    #[msg("Redemption settlement has already been confirmed")]
+    AlreadySettled,
+
+    #[msg("Payout reference exceeds maximum length of 64 characters")]
+    PayoutReferenceTooLong,
+
+    // ── Pyth ────────────────────────────────────────────────────────────────
+    #[msg("Pyth price update is not fully verified")]
+    InsufficientVerification,
+
+    #[msg("source_kinds must list one provider per source account")]
+    SourceKindMismatch,
+
+    // ── Circuit breaker ─────────────────────────────────────────────────────
+    #[msg("Circuit breaker needs a positive window when max_price_move_bps is set")]
+    InvalidCircuitBreakerConfig,
+
+    #[msg("Unpause must acknowledge the current pause reason")]
+    PauseAcknowledgementMismatch,
+
+    // ── Price accumulator ───────────────────────────────────────────────────
+    #[msg("Accumulator periods must be positive and the TWAP window must fit the observation buffer")]
+    InvalidAccumulatorConfig,
+
+    #[msg("Accumulator update too soon — minimum interval not reached")]
+    AccumulatorUpdateTooSoon,
+
+    #[msg("Pricing mode needs the feed's price accumulator account")]
+    AccumulatorRequired,
+
+    #[msg("Accumulator has not been cranked for a full TWAP window yet")]
+    AccumulatorNotReady,
+
+    #[msg("Accumulator is stale — last crank exceeds max_staleness threshold")]
+    AccumulatorStale,
+
+    // ── Config updates ──────────────────────────────────────────────────────
+    #[msg("Fee exceeds the maximum of 1000 bps")]
+    FeeTooHigh,
+
+    #[msg("Risk parameter is outside its allowed bounds")]
+    RiskParamOutOfBounds,
+
+    #[msg("Description exceeds maximum length of 100 characters")]
+    DescriptionTooLong,
+
+    #[msg("Config update changes nothing")]
+    EmptyConfigUpdate,
+
+    #[msg("A config update is already queued — cancel it first")]
+    ConfigUpdatePending,
+
+    #[msg("No config update is queued")]
+    NoPendingConfigUpdate,
+
+    #[msg("Config update timelock has not elapsed")]
+    ConfigTimelockNotElapsed,
+
+    // ── Fees ────────────────────────────────────────────────────────────────
+    #[msg("Fee splits: at most 4 distinct recipients, non-zero shares, 10000 bps total")]
+    InvalidFeeSplits,
+
+    #[msg("Fee split recipient accounts do not match the configured splits")]
+    FeeSplitAccountMismatch,
+
+    #[msg("Withdrawal exceeds the fee vault balance")]
+    InsufficientFees,
+
+    // ── Decimals ────────────────────────────────────────────────────────────
+    #[msg("Mint or feed decimals exceed the supported maximum of 12")]
+    UnsupportedDecimals,
+
+    // ── Feed lifecycle ──────────────────────────────────────────────────────
+    #[msg("Feed has been deactivated")]
+    FeedInactive,
+
+    // ── Manual price ────────────────────────────────────────────────────────
+    #[msg("Manual price overrides are disabled (manual_price_band_bps is 0)")]
+    ManualPriceDisabled,
+
+    #[msg("Manual price needs a last good price to bound it")]
+    NoReferencePrice,
+
+    #[msg("Manual price is outside the allowed band around the last good price")]
+    ManualPriceOutOfBand,
+
+    #[msg("Manual price must be non-zero, last at most 24h and carry a reason of at most 64 chars")]
+    InvalidManualPrice,
+
+    #[msg("No manual price is active")]
+    NoManualPrice,
+
+    // ── CPI accrual ─────────────────────────────────────────────────────────
+    #[msg("CPI update exceeds cpi_max_change_bps of the current multiplier")]
+    CpiChangeTooLarge,
+
+    #[msg("CPI accrual window must be between 0 and 366 days")]
+    InvalidCpiAccrualWindow,
+
+    // ── CPI attestations ────────────────────────────────────────────────────
+    #[msg("CPI attesters: at most 5 distinct keys, threshold within 1..=N (0 with none), tolerance at most 500 bps")]
+    InvalidAttesterSet,
+
+    #[msg("Ed25519 instruction is malformed or references another instruction's data")]
+    MalformedEd25519Instruction,
+
+    #[msg("Fewer CPI attestations than the attester threshold")]
+    InsufficientAttestations,
+
+    #[msg("Attested CPI multipliers disagree beyond the tolerance")]
+    AttestationsDisagree,
+
+    #[msg("CPI multiplier does not match the median of the attested values")]
+    AttestedMultiplierMismatch,
+
+    #[msg("Reference month must be YYYY-MM and later than the last attested month")]
+    InvalidReferenceMonth,
+
+    // ── Swaps ───────────────────────────────────────────────────────────────
+    #[msg("Swap input and output mints must differ")]
+    SwapSameMint,
+
+    #[msg("Swaps need the input oracle config to hold the sss-core burner role")]
+    SwapRequiresBurner,
+
+    // ── Composite feeds ─────────────────────────────────────────────────────
+    #[msg("Composite feeds need 2 to 3 distinct legs, each an active, registered, non-composite feed")]
+    InvalidCompositeLegs,
+
+    #[msg("Composite feeds have no sources, and cannot become or stop being composite")]
+    CompositeFeedChange,
+
+    // ── Peg stability module ────────────────────────────────────────────────
+    #[msg("PSM collateral is not accepting new mints")]
+    PsmCollateralInactive,
+
+    #[msg("PSM mint would exceed the collateral's debt ceiling")]
+    DebtCeilingExceeded,
+
+    #[msg("PSM redemptions need the oracle config to hold the sss-core burner role")]
+    PsmRequiresBurner,
+
+    #[msg("PSM redemption exceeds the debt outstanding against this collateral")]
+    InsufficientPsmCollateral,
+
+    // ── Quote cleanup ───────────────────────────────────────────────────────
+    #[msg("Quote has not expired yet — only the requester can use it until valid_until")]
+    QuoteNotExpired,
+
+    #[msg("Expired quotes must be passed as (quote, requester) account pairs")]
+    InvalidQuoteAccounts,
+
+    // ── Market mint ─────────────────────────────────────────────────────────
+    #[msg("All-in mint price exceeds max_price")]
+    PriceAboveMax,
+
+    // ── Issuance compliance ─────────────────────────────────────────────────
+    #[msg("sss-core allowlist or blacklist entry missing or not the participant's PDA")]
+    ComplianceAccountMismatch,
+
+    #[msg("Participant is blacklisted for this mint")]
+    ParticipantBlacklisted,
+
+    #[msg("Participant has no sss-core allowlist entry valid for receiving this mint")]
+    NotAllowlisted,
+
+    #[msg("Participant's KYC tier is below the oracle's min_kyc_tier")]
+    KycTierTooLow,
+
+    // ── Issuance caps ───────────────────────────────────────────────────────
+    #[msg("Mint would exceed the oracle's rolling 24h global mint cap")]
+    GlobalMintCapExceeded,
+
+    #[msg("Redemption would exceed the oracle's rolling 24h global redeem cap")]
+    GlobalRedeemCapExceeded,
+
+    #[msg("Mint would exceed the requester's rolling 24h mint cap")]
+    UserMintCapExceeded,
+
+    #[msg("Redemption would exceed the requester's rolling 24h redeem cap")]
+    UserRedeemCapExceeded,
+
+    #[msg("Per-user caps are set — pass the requester's UserUsage account")]
+    UserUsageRequired,
+
+    // ── Peg fee curve ───────────────────────────────────────────────────────
+    #[msg("Peg fee curve needs 2..=8 points with strictly increasing deviation and a market feed")]
+    InvalidFeeCurve,
+
+    #[msg("Peg fee curve is set — pass its market feed and that feed's sources")]
+    MarketFeedRequired,
+
+    // ── Quote denomination ──────────────────────────────────────────────────
+    #[msg("Quote denomination needs an uppercase ISO 4217 code and at most 12 decimals")]
+    InvalidQuoteDenomination,
+
+    #[msg("Swap legs' oracles quote in different denominations")]
+    QuoteDenominationMismatch,
+
+    #[msg("The peg stability module only backs oracles quoted in USD")]
+    PsmRequiresUsdQuote,
+
+    #[msg("Feed's quote currency differs from the oracle's quote denomination")]
+    FeedCurrencyMismatch,
+
+    // ── Redemption ──────────────────────────────────────────────────────────
+    #[msg("Redemptions need the oracle config to hold the sss-core burner role")]
+    RedeemRequiresBurner,
 }
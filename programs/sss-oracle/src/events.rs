@@ -1,10 +1,15 @@
 use anchor_lang::prelude::*;
+use crate::state::{
+    FeeSplit, OracleParamsUpdate, OracleRole, PauseReason, PriceSource, PricingMode,
+    QuoteDenomination, SourceReport,
+};
 
 /// Emitted when a new oracle config is initialized for a mint
 #[event]
 pub struct OracleInitialized {
     pub mint:             Pubkey,
-    pub authority:        Pubkey,
+    /// Initial holder of every role
+    pub admin:            Pubkey,
     pub feed_symbol:      String,
     pub mint_fee_bps:     u16,
     pub redeem_fee_bps:   u16,
@@ -15,7 +20,9 @@ pub struct OracleInitialized {
 #[event]
 pub struct FeedRegistered {
     pub symbol:           String,
-    pub switchboard_feed: Pubkey,
+    pub sources:          Vec<PriceSource>,
+    pub min_sources:      u8,
+    pub max_spread_bps:   u16,
     pub feed_type:        String,
     pub base_currency:    String,
     pub quote_currency:   String,
@@ -23,6 +30,53 @@ pub struct FeedRegistered {
     pub timestamp:        i64,
 }
 
+/// Emitted when a feed is deactivated
+#[event]
+pub struct FeedDeactivated {
+    pub symbol:           String,
+    pub reason:           String,
+    pub by:               Pubkey,
+    pub timestamp:        i64,
+}
+
+/// Emitted when a feed's source accounts are replaced
+#[event]
+pub struct FeedSourceRotated {
+    pub symbol:           String,
+    pub old_sources:      Vec<PriceSource>,
+    pub new_sources:      Vec<PriceSource>,
+    pub min_sources:      u8,
+    pub max_spread_bps:   u16,
+    pub by:               Pubkey,
+    pub timestamp:        i64,
+}
+
+/// Emitted when a feed's price interpretation changes
+#[event]
+pub struct FeedTypeUpdated {
+    pub symbol:           String,
+    pub old_type:         String,
+    pub new_type:         String,
+    pub by:               Pubkey,
+    pub timestamp:        i64,
+}
+
+/// Emitted when a registry authority transfer is proposed
+#[event]
+pub struct RegistryAuthorityProposed {
+    pub current:          Pubkey,
+    pub proposed:         Pubkey,
+    pub timestamp:        i64,
+}
+
+/// Emitted when a registry authority transfer is accepted
+#[event]
+pub struct RegistryAuthorityTransferred {
+    pub from:             Pubkey,
+    pub to:               Pubkey,
+    pub timestamp:        i64,
+}
+
 /// Emitted every time a quote is requested
 #[event]
 pub struct QuoteGenerated {
@@ -33,17 +87,47 @@ pub struct QuoteGenerated {
     pub input_amount:     u64,
     pub output_amount:    u64,
     pub fee_amount:       u64,
+    /// Fee charged, in bps: the flat fee or the peg fee curve's
+    pub fee_bps:          u16,
+    /// Market deviation from peg the curve was read at (`None` = flat fee)
+    pub deviation_bps:    Option<i64>,
     pub price_used:       u64,
+    /// Decimals of `price_used` and `spot_price`
+    pub price_decimals:   u8,
+    /// How `price_used` was derived
+    pub pricing_mode:     PricingMode,
+    /// Median spot price at quote time
+    pub spot_price:       u64,
+    /// Per-source price, confidence, staleness and whether it was used
+    pub sources:          Vec<SourceReport>,
+    /// Priced off the emergency manual price (`sources` is then empty)
+    pub manual_override:  bool,
     pub valid_until:      i64,
     pub timestamp:        i64,
 }
 
+/// Emitted when an expired, unexecuted quote is closed and its rent refunded
+#[event]
+pub struct QuoteClosed {
+    pub quote_id:         Pubkey,
+    /// The quote's mint; the input mint for a swap quote
+    pub mint:             Pubkey,
+    pub requester:        Pubkey,
+    pub valid_until:      i64,
+    /// Lamports returned to the requester
+    pub rent_refunded:    u64,
+    pub closed_by:        Pubkey,
+    pub timestamp:        i64,
+}
+
 /// Emitted on a successful oracle-priced mint execution
 #[event]
 pub struct OracleMint {
     pub mint:             Pubkey,
     pub recipient:        Pubkey,
-    pub usd_amount:       u64,
+    /// Amount paid, in `denomination` units
+    pub quote_amount:     u64,
+    pub denomination:     QuoteDenomination,
     pub token_amount:     u64,
     pub fee_amount:       u64,
     pub price_used:       u64,
@@ -56,11 +140,104 @@ pub struct OracleMint {
 pub struct OracleRedeem {
     pub mint:             Pubkey,
     pub redeemer:         Pubkey,
+    pub settlement:       Pubkey,
     pub token_amount:     u64,
-    pub usd_amount:       u64,
+    /// Amount owed after the fee, in `denomination` units
+    pub quote_amount:     u64,
+    pub denomination:     QuoteDenomination,
+    /// Fee withheld, in `denomination` units
     pub fee_amount:       u64,
     pub price_used:       u64,
     pub feed_symbol:      String,
+    /// Token share of the fee moved to the fee vault
+    pub fee_tokens:       u64,
+    pub timestamp:        i64,
+}
+
+/// Emitted when a cross-stablecoin swap quote is stored
+#[event]
+pub struct SwapQuoteGenerated {
+    pub quote_id:         Pubkey,
+    pub mint_in:          Pubkey,
+    pub mint_out:         Pubkey,
+    pub feed_symbol_in:   String,
+    pub feed_symbol_out:  String,
+    pub input_amount:     u64,
+    pub output_amount:    u64,
+    pub fee_amount:       u64,
+    /// Value of the input in the `quote_denomination` both oracles share
+    /// (not necessarily USD)
+    pub usd_value:        u64,
+    pub price_in:         u64,
+    pub price_out:        u64,
+    /// Per-source readings of the input feed
+    pub sources_in:       Vec<SourceReport>,
+    /// Per-source readings of the output feed
+    pub sources_out:      Vec<SourceReport>,
+    /// Either leg priced off an emergency manual price
+    pub manual_override:  bool,
+    pub valid_until:      i64,
+    pub timestamp:        i64,
+}
+
+/// Emitted when a swap quote is executed: `input_amount` of `mint_in`
+/// burned, `output_amount` of `mint_out` minted
+#[event]
+pub struct SwapExecuted {
+    pub mint_in:          Pubkey,
+    pub mint_out:         Pubkey,
+    pub requester:        Pubkey,
+    pub recipient:        Pubkey,
+    pub input_amount:     u64,
+    pub output_amount:    u64,
+    pub fee_amount:       u64,
+    /// Value of the input in the `quote_denomination` both oracles share
+    pub usd_value:        u64,
+    pub price_in:         u64,
+    pub price_out:        u64,
+    pub timestamp:        i64,
+}
+
+/// Emitted when the fiat payout for a redemption is confirmed
+#[event]
+pub struct RedemptionSettled {
+    pub mint:             Pubkey,
+    pub settlement:       Pubkey,
+    pub redeemer:         Pubkey,
+    /// Amount paid out, in the oracle's `quote_denomination` units
+    pub usd_owed:         u64,
+    pub payout_reference: String,
+    pub settled_by:       Pubkey,
+    pub timestamp:        i64,
+}
+
+/// Emitted when the fee vault is created
+#[event]
+pub struct FeeVaultInitialized {
+    pub mint:             Pubkey,
+    pub fee_vault:        Pubkey,
+    pub timestamp:        i64,
+}
+
+/// Emitted when fee-split recipients are replaced
+#[event]
+pub struct FeeSplitsUpdated {
+    pub mint:             Pubkey,
+    pub splits:           Vec<FeeSplit>,
+    pub by:               Pubkey,
+    pub timestamp:        i64,
+}
+
+/// Emitted when fees are paid out of the fee vault
+#[event]
+pub struct FeesWithdrawn {
+    pub mint:             Pubkey,
+    pub treasury:         Pubkey,
+    pub amount:           u64,
+    pub to_treasury:      u64,
+    /// Paid to each `fee_splits` recipient, in order
+    pub split_amounts:    Vec<u64>,
+    pub by:               Pubkey,
     pub timestamp:        i64,
 }
 
@@ -68,10 +245,17 @@ pub struct OracleRedeem {
 #[event]
 pub struct CpiMultiplierUpdated {
     pub mint:             Pubkey,
+    /// Multiplier in effect when the update landed
     pub old_multiplier:   u64,
+    /// Target reached at `accrual_end`
     pub new_multiplier:   u64,
+    pub change_bps:       u64,
+    pub accrual_start:    i64,
+    pub accrual_end:      i64,
     pub reference_month:  String,
     pub data_source:      String,
+    /// Attesters whose signatures backed the update (empty without an attester set)
+    pub attested_by:      Vec<Pubkey>,
     pub updated_by:       Pubkey,
     pub timestamp:        i64,
 }
@@ -81,25 +265,191 @@ pub struct CpiMultiplierUpdated {
 pub struct OraclePaused {
     pub mint:             Pubkey,
     pub paused:           bool,
+    pub code:             PauseReason,
     pub reason:           String,
     pub by:               Pubkey,
     pub timestamp:        i64,
 }
 
-/// Emitted when oracle authority transfer is proposed
+/// Emitted when a feed reading breaches the price band and pauses the oracle
+#[event]
+pub struct CircuitBreakerTripped {
+    pub mint:             Pubkey,
+    pub feed_symbol:      String,
+    pub last_good_price:  u64,
+    pub last_good_at:     i64,
+    pub new_price:        u64,
+    pub move_bps:         u64,
+    pub allowed_bps:      u64,
+    pub timestamp:        i64,
+}
+
+/// Emitted when a feed's price accumulator is created
+#[event]
+pub struct AccumulatorInitialized {
+    pub feed_symbol:      String,
+    pub twap_window_secs: i64,
+    pub ema_period_secs:  i64,
+    pub timestamp:        i64,
+}
+
+/// Emitted on every accumulator crank
+#[event]
+pub struct AccumulatorUpdated {
+    pub feed_symbol:      String,
+    pub price:            u64,
+    pub ema_price:        u64,
+    pub cumulative_price: u128,
+    pub timestamp:        i64,
+}
+
+/// Emitted when a risk-parameter update is queued behind the timelock
+#[event]
+pub struct ConfigUpdateQueued {
+    pub mint:             Pubkey,
+    pub update:           OracleParamsUpdate,
+    pub eta:              i64,
+    pub by:               Pubkey,
+    pub timestamp:        i64,
+}
+
+/// Emitted when the admin sets an emergency manual price
+#[event]
+pub struct ManualPriceSet {
+    pub mint:             Pubkey,
+    pub feed_symbol:      String,
+    pub price:            u64,
+    /// Last good feed price the override was bounded against
+    pub reference_price:  u64,
+    pub deviation_bps:    u64,
+    pub expires_at:       i64,
+    pub reason:           String,
+    pub by:               Pubkey,
+    pub timestamp:        i64,
+}
+
+/// Emitted when the admin clears the manual price before it expires
+#[event]
+pub struct ManualPriceCleared {
+    pub mint:             Pubkey,
+    pub price:            u64,
+    pub by:               Pubkey,
+    pub timestamp:        i64,
+}
+
+/// Emitted when a queued risk-parameter update takes effect
+#[event]
+pub struct ConfigUpdateApplied {
+    pub mint:             Pubkey,
+    pub update:           OracleParamsUpdate,
+    pub timestamp:        i64,
+}
+
+/// Emitted when a queued risk-parameter update is cancelled
+#[event]
+pub struct ConfigUpdateCancelled {
+    pub mint:             Pubkey,
+    pub update:           OracleParamsUpdate,
+    pub by:               Pubkey,
+    pub timestamp:        i64,
+}
+
+/// Emitted when a transfer of an oracle role is proposed
 #[event]
 pub struct AuthorityTransferProposed {
     pub mint:             Pubkey,
+    pub role:             OracleRole,
     pub current:          Pubkey,
     pub proposed:         Pubkey,
+    /// The role's holder or the admin
+    pub proposed_by:      Pubkey,
     pub timestamp:        i64,
 }
 
-/// Emitted when oracle authority transfer is accepted
+/// Emitted when a transfer of an oracle role is accepted
 #[event]
 pub struct AuthorityTransferred {
     pub mint:             Pubkey,
+    pub role:             OracleRole,
     pub from:             Pubkey,
     pub to:               Pubkey,
     pub timestamp:        i64,
 }
+
+/// Emitted when a collateral is added to a mint's peg stability module
+#[event]
+pub struct PsmCollateralRegistered {
+    pub mint:             Pubkey,
+    pub collateral_mint:  Pubkey,
+    pub vault:            Pubkey,
+    /// USD, in the oracle's quote decimals
+    pub debt_ceiling_usd: u64,
+    pub by:               Pubkey,
+    pub timestamp:        i64,
+}
+
+/// Emitted when a PSM collateral's debt ceiling or active flag changes
+#[event]
+pub struct PsmCollateralUpdated {
+    pub mint:             Pubkey,
+    pub collateral_mint:  Pubkey,
+    /// USD, in the oracle's quote decimals
+    pub debt_ceiling_usd: u64,
+    pub active:           bool,
+    pub by:               Pubkey,
+    pub timestamp:        i64,
+}
+
+/// Emitted when collateral is deposited and SSS tokens minted against it
+#[event]
+pub struct PsmMint {
+    pub mint:              Pubkey,
+    pub collateral_mint:   Pubkey,
+    pub recipient:         Pubkey,
+    /// Collateral deposited (collateral base units)
+    pub collateral_amount: u64,
+    /// Par value of the collateral (USD, in the oracle's quote decimals)
+    pub usd_amount:        u64,
+    pub token_amount:      u64,
+    pub fee_amount:        u64,
+    pub price_used:        u64,
+    /// Debt outstanding against the collateral afterwards (USD, quote decimals)
+    pub debt_usd:          u64,
+    pub timestamp:         i64,
+}
+
+/// Emitted when SSS tokens are burned and collateral released for them
+#[event]
+pub struct PsmRedeem {
+    pub mint:              Pubkey,
+    pub collateral_mint:   Pubkey,
+    pub redeemer:          Pubkey,
+    pub token_amount:      u64,
+    /// Token share of the fee moved to the fee vault
+    pub fee_tokens:        u64,
+    /// Value redeemed after fees (USD, in the oracle's quote decimals)
+    pub usd_amount:        u64,
+    /// Collateral released (collateral base units)
+    pub collateral_amount: u64,
+    pub price_used:        u64,
+    /// Debt outstanding against the collateral afterwards (USD, quote decimals)
+    pub debt_usd:          u64,
+    pub timestamp:         i64,
+}
+
+/// Emitted when an oracle mint or redemption is rejected by a rolling issuance cap.
+/// Amounts are in the oracle's `quote_denomination` units.
+#[event]
+pub struct IssuanceCapExceeded {
+    pub mint:           Pubkey,
+    /// The requester, for a per-user cap; `None` for a global cap
+    pub user:           Option<Pubkey>,
+    /// "Mint" or "Redeem"
+    pub direction:      String,
+    /// Value the rejected operation would have added
+    pub attempted_usd:  u64,
+    /// Value already used in the rolling window
+    pub window_usage:   u64,
+    pub cap_usd:        u64,
+    pub timestamp:      i64,
+}
@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::OracleError;
+use crate::events::*;
+use crate::math::*;
+use crate::aggregation::aggregate_feed_price;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// initialize_accumulator — Create the TWAP/EMA accumulator for a feed
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeAccumulatorParams {
+    pub feed_symbol:              String,
+    /// TWAP lookback window; at most (MAX_OBSERVATIONS - 1) crank intervals
+    pub twap_window_secs:         i64,
+    pub ema_period_secs:          i64,
+    pub min_update_interval_secs: i64,
+    pub max_staleness_secs:       i64,
+    pub max_confidence_bps:       u16,
+}
+
+#[derive(Accounts)]
+#[instruction(params: InitializeAccumulatorParams)]
+pub struct InitializeAccumulator<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = authority.key() == registry.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"sss-feed-registry"],
+        bump  = registry.bump,
+    )]
+    pub registry: Account<'info, FeedRegistry>,
+
+    #[account(
+        seeds = [b"sss-feed", params.feed_symbol.as_bytes()],
+        bump  = feed.bump,
+        constraint = feed.active @ OracleError::FeedInactive,
+    )]
+    pub feed: Account<'info, FeedEntry>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PriceAccumulator::LEN,
+        seeds = [b"sss-accumulator", params.feed_symbol.as_bytes()],
+        bump,
+    )]
+    pub accumulator: Account<'info, PriceAccumulator>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_accumulator(
+    ctx: Context<InitializeAccumulator>,
+    params: InitializeAccumulatorParams,
+) -> Result<()> {
+    // The ring buffer must always reach back a full TWAP window
+    let max_window = params
+        .min_update_interval_secs
+        .checked_mul(MAX_OBSERVATIONS as i64 - 1)
+        .ok_or(OracleError::MathOverflow)?;
+    require!(
+        params.min_update_interval_secs > 0
+            && params.ema_period_secs > 0
+            && params.twap_window_secs > 0
+            && params.twap_window_secs <= max_window,
+        OracleError::InvalidAccumulatorConfig
+    );
+
+    let acc = &mut ctx.accounts.accumulator;
+    acc.feed_symbol              = params.feed_symbol.clone();
+    acc.last_price               = 0;
+    acc.last_update              = 0;
+    acc.cumulative_price         = 0;
+    acc.ema_price                = 0;
+    acc.twap_window_secs         = params.twap_window_secs;
+    acc.ema_period_secs          = params.ema_period_secs;
+    acc.min_update_interval_secs = params.min_update_interval_secs;
+    acc.max_staleness_secs       = params.max_staleness_secs;
+    acc.max_confidence_bps       = params.max_confidence_bps;
+    acc.observations             = [Observation::default(); MAX_OBSERVATIONS];
+    acc.observation_index        = 0;
+    acc.observation_count        = 0;
+    acc.bump                     = ctx.bumps.accumulator;
+
+    emit!(AccumulatorInitialized {
+        feed_symbol:      params.feed_symbol,
+        twap_window_secs: params.twap_window_secs,
+        ema_period_secs:  params.ema_period_secs,
+        timestamp:        Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// update_accumulator — Permissionless crank
+//
+// The feed's source accounts are passed as `remaining_accounts`, exactly as
+// for quotes. The median is folded into the cumulative price and the EMA,
+// and a new observation is written to the ring buffer.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct UpdateAccumulator<'info> {
+    #[account(
+        mut,
+        seeds = [b"sss-accumulator", accumulator.feed_symbol.as_bytes()],
+        bump  = accumulator.bump,
+    )]
+    pub accumulator: Account<'info, PriceAccumulator>,
+
+    #[account(
+        seeds = [b"sss-feed", accumulator.feed_symbol.as_bytes()],
+        bump  = feed.bump,
+        constraint = feed.active @ OracleError::FeedInactive,
+    )]
+    pub feed: Account<'info, FeedEntry>,
+}
+
+pub fn update_accumulator(ctx: Context<UpdateAccumulator>) -> Result<()> {
+    let acc = &mut ctx.accounts.accumulator;
+    let now = Clock::get()?.unix_timestamp;
+
+    let feed = &ctx.accounts.feed;
+
+    if acc.observation_count > 0 {
+        require!(
+            now.saturating_sub(acc.last_update) >= acc.min_update_interval_secs,
+            OracleError::AccumulatorUpdateTooSoon
+        );
+    }
+
+    let agg = aggregate_feed_price(
+        feed,
+        ctx.remaining_accounts,
+        Rounding::Down,
+        acc.max_staleness_secs,
+        acc.max_confidence_bps,
+    )?;
+    let price = agg.price_scaled;
+
+    if acc.observation_count > 0 {
+        acc.cumulative_price = acc.cumulative_at(now).ok_or(OracleError::MathOverflow)?;
+        acc.ema_price        = ema_step(acc.ema_price, price, now - acc.last_update, acc.ema_period_secs)?;
+    } else {
+        acc.ema_price        = price;
+    }
+    acc.last_price  = price;
+    acc.last_update = now;
+
+    let index = acc.observation_index as usize;
+    acc.observations[index] = Observation {
+        timestamp:        now,
+        cumulative_price: acc.cumulative_price,
+    };
+    acc.observation_index = ((index + 1) % MAX_OBSERVATIONS) as u8;
+    acc.observation_count = (acc.observation_count as usize + 1).min(MAX_OBSERVATIONS) as u8;
+
+    emit!(AccumulatorUpdated {
+        feed_symbol:      acc.feed_symbol.clone(),
+        price,
+        ema_price:        acc.ema_price,
+        cumulative_price: acc.cumulative_price,
+        timestamp:        now,
+    });
+
+    Ok(())
+}
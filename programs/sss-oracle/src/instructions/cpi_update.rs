@@ -2,27 +2,42 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::OracleError;
 use crate::events::*;
+use crate::math::{median, price_move_bps, spread_bps, Rounding};
+use crate::attestation::{collect_cpi_attestations, is_valid_reference_month};
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
 
 // ═══════════════════════════════════════════════════════════════════════════
-// update_cpi_multiplier — Monthly admin operation for CpiIndexed tokens
+// update_cpi_multiplier — Monthly CPI updater operation for CpiIndexed tokens
+//
+// Publishes a target multiplier instead of stepping to it. The multiplier
+// accrues linearly per second from its current value to the target over
+// `accrual_window_secs`, so there is no jump to trade around. A new update
+// mid-window restarts the schedule from the value in effect at that moment.
+//
+// With a CPI attester set configured, the transaction must also carry Ed25519
+// precompile instructions in which at least `threshold` attesters sign
+// (oracle_config, reference_month, multiplier). The attested values must agree within
+// `tolerance_bps` and the target must be their median.
 // ═══════════════════════════════════════════════════════════════════════════
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateCpiParams {
-    /// New CPI multiplier (fixed-point * 1_000_000). 1.083 = 1_083_000
-    pub new_multiplier:   u64,
+    /// Target CPI multiplier (fixed-point * 1_000_000). 1.083 = 1_083_000
+    pub new_multiplier:      u64,
+    /// Seconds over which the multiplier moves to the target (0 = at once)
+    pub accrual_window_secs: i64,
     /// Reference month string, e.g. "2026-01"
-    pub reference_month:  String,
+    pub reference_month:     String,
     /// Data source URL/description for audit trail
-    pub data_source:      String,
+    pub data_source:         String,
 }
 
 #[derive(Accounts)]
 pub struct UpdateCpiMultiplier<'info> {
     #[account(
-        constraint = authority.key() == oracle_config.authority @ OracleError::Unauthorized
+        constraint = cpi_updater.key() == oracle_config.cpi_updater @ OracleError::Unauthorized
     )]
-    pub authority: Signer<'info>,
+    pub cpi_updater: Signer<'info>,
 
     #[account(
         mut,
@@ -30,6 +45,11 @@ pub struct UpdateCpiMultiplier<'info> {
         bump  = oracle_config.bump,
     )]
     pub oracle_config: Account<'info, OracleConfig>,
+
+    /// CHECK: instructions sysvar, read for the attesters' Ed25519 signatures.
+    /// Required when `oracle_config.cpi_attesters` is enabled.
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
 }
 
 pub fn update_cpi_multiplier(
@@ -40,6 +60,10 @@ pub fn update_cpi_multiplier(
     let now    = Clock::get()?.unix_timestamp;
 
     require!(params.new_multiplier > 0, OracleError::InvalidCpiMultiplier);
+    require!(
+        (0..=MAX_CPI_ACCRUAL_WINDOW_SECS).contains(&params.accrual_window_secs),
+        OracleError::InvalidCpiAccrualWindow
+    );
 
     // Enforce minimum update interval
     require!(
@@ -47,22 +71,86 @@ pub fn update_cpi_multiplier(
         OracleError::CpiUpdateTooSoon
     );
 
-    let old_multiplier = oracle.cpi_multiplier;
+    let attested_by = if oracle.cpi_attesters.enabled() {
+        verify_cpi_attestations(
+            oracle,
+            ctx.accounts.instructions.as_ref().ok_or(OracleError::InsufficientAttestations)?,
+            &params,
+        )?
+    } else {
+        Vec::new()
+    };
+
+    // The schedule restarts from the multiplier in effect now
+    let old_multiplier = oracle.cpi_multiplier_at(now, Rounding::Down)?;
+    let change_bps     = price_move_bps(old_multiplier, params.new_multiplier)?;
+    require!(
+        change_bps <= oracle.cpi_max_change_bps as u64,
+        OracleError::CpiChangeTooLarge
+    );
 
-    oracle.cpi_multiplier   = params.new_multiplier;
-    oracle.cpi_last_updated = now;
-    oracle.cpi_data_source  = params.data_source.clone();
-    oracle.last_updated_at  = now;
+    let accrual_end = now
+        .checked_add(params.accrual_window_secs)
+        .ok_or(OracleError::MathOverflow)?;
+    oracle.cpi_multiplier        = old_multiplier;
+    oracle.cpi_target_multiplier = params.new_multiplier;
+    oracle.cpi_accrual_start     = now;
+    oracle.cpi_accrual_end       = accrual_end;
+    oracle.cpi_last_updated      = now;
+    oracle.cpi_data_source       = params.data_source.clone();
+    oracle.cpi_reference_month   = params.reference_month.clone();
+    oracle.last_updated_at       = now;
 
     emit!(CpiMultiplierUpdated {
         mint:            oracle.mint,
         old_multiplier,
         new_multiplier:  params.new_multiplier,
+        change_bps,
+        accrual_start:   now,
+        accrual_end,
         reference_month: params.reference_month,
         data_source:     params.data_source,
-        updated_by:      ctx.accounts.authority.key(),
+        attested_by,
+        updated_by:      ctx.accounts.cpi_updater.key(),
         timestamp:       now,
     });
 
     Ok(())
 }
+
+/// Check the M-of-N attestations for `params` carried by this transaction.
+/// Returns the attesters that signed.
+fn verify_cpi_attestations(
+    oracle:       &Account<OracleConfig>,
+    instructions: &AccountInfo,
+    params:       &UpdateCpiParams,
+) -> Result<Vec<Pubkey>> {
+    // Months only move forward, so an old attestation cannot be replayed
+    require!(
+        is_valid_reference_month(&params.reference_month)
+            && params.reference_month.as_str() > oracle.cpi_reference_month.as_str(),
+        OracleError::InvalidReferenceMonth
+    );
+
+    let set          = &oracle.cpi_attesters;
+    let attestations = collect_cpi_attestations(
+        instructions,
+        &oracle.key(),
+        &set.attesters,
+        &params.reference_month,
+    )?;
+    require!(
+        attestations.len() >= set.threshold as usize,
+        OracleError::InsufficientAttestations
+    );
+
+    let mut values: Vec<u64> = attestations.iter().map(|a| a.multiplier).collect();
+    let mid = median(&mut values, Rounding::Down)?; // sorts `values`
+    require!(mid > 0, OracleError::InvalidCpiMultiplier);
+    require!(
+        spread_bps(values[0], values[values.len() - 1], mid)? <= set.tolerance_bps as u64,
+        OracleError::AttestationsDisagree
+    );
+    require!(params.new_multiplier == mid, OracleError::AttestedMultiplierMismatch);
+    Ok(attestations.iter().map(|a| a.attester).collect())
+}
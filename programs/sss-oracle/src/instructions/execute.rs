@@ -1,17 +1,30 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use sss_core::program::SssCore;
+use sss_core::state::{MinterConfig, StablecoinConfig};
 use crate::state::*;
 use crate::errors::OracleError;
 use crate::events::*;
+use crate::math::*;
+use crate::compliance::{check_participant, check_recipient};
+use crate::limits::enforce_caps;
+use crate::instructions::quotes::{
+    quote_fee_bps, quote_price, split_market_accounts, tripped_result, PriceOutcome, QuotePrice,
+};
 
 // ═══════════════════════════════════════════════════════════════════════════
-// mint_with_oracle — Atomic quote consumption + stats update
+// mint_with_oracle — Atomic quote consumption + sss-core mint
 //
 // Validates the stored PendingQuote, checks expiry and slippage,
-// marks it as used, and updates oracle lifetime stats.
+// marks it as used, updates oracle lifetime stats and mints
+// `quote.output_amount` to the destination and `quote.fee_amount` to the
+// oracle fee vault through sss-core.
 //
-// NOTE: The actual CPI to sss-core::mint is stubbed — wiring it
-// requires importing sss-core's CPI feature as a dependency.
-// The event emission and stats tracking are fully functional.
+// The oracle config PDA must be registered as an sss-core minter
+// (`add_minter(oracle_config, ...)`). sss-core keeps enforcing the
+// per-minter quota, `max_supply` and its own pause flag.
 // ═══════════════════════════════════════════════════════════════════════════
 
 #[derive(Accounts)]
@@ -42,20 +55,74 @@ pub struct MintWithOracle<'info> {
     )]
     pub quote: Account<'info, PendingQuote>,
 
-    // TODO: Add remaining accounts for sss-core CPI when wiring:
-    //   - sss_core_program: Program
-    //   - config PDA
-    //   - minter_config PDA
-    //   - mint account
-    //   - destination token account
-    //   - token_program
+    /// sss-core stablecoin config for the oracle's mint
+    #[account(
+        mut,
+        seeds = [b"sss-config", mint.key().as_ref()],
+        seeds::program = sss_core::ID,
+        bump = core_config.bump,
+        has_one = mint,
+    )]
+    pub core_config: Box<Account<'info, StablecoinConfig>>,
+
+    /// sss-core minter entry registered for the oracle config PDA
+    #[account(
+        mut,
+        seeds = [b"sss-minter", mint.key().as_ref(), oracle_config.key().as_ref()],
+        seeds::program = sss_core::ID,
+        bump = minter_config.bump,
+    )]
+    pub minter_config: Box<Account<'info, MinterConfig>>,
+
+    /// CHECK: Must match oracle_config.mint — validated by Token-2022 in the sss-core CPI
+    #[account(
+        mut,
+        constraint = mint.key() == oracle_config.mint @ OracleError::MintMismatch,
+    )]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Recipient token account — validated by Token-2022 in the sss-core CPI
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// CHECK: Oracle fee vault PDA (created by `initialize_fee_vault`) — receives the mint fee
+    #[account(
+        mut,
+        seeds = [b"sss-fee-vault", mint.key().as_ref()],
+        bump,
+    )]
+    pub fee_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Requester's sss-core allowlist entry — checked in `check_participant`.
+    /// Required when the mint has an active allowlist or the oracle a `min_kyc_tier`
+    pub requester_allowlist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Requester's sss-core blacklist entry PDA (may be empty) — checked in
+    /// `check_participant`. Required when the mint has a blacklister
+    pub requester_blacklist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Recipient's sss-core allowlist entry — checked in `check_recipient`
+    pub recipient_allowlist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Recipient's sss-core blacklist entry PDA (may be empty) — checked in `check_recipient`
+    pub recipient_blacklist: Option<UncheckedAccount<'info>>,
+
+    /// Requester's rolling USD usage — required when the oracle sets a per-user mint cap
+    #[account(
+        mut,
+        seeds = [b"sss-usage", oracle_config.mint.as_ref(), requester.key().as_ref()],
+        bump  = user_usage.bump,
+    )]
+    pub user_usage: Option<Box<Account<'info, UserUsage>>>,
+
+    pub sss_core_program: Program<'info, SssCore>,
+    pub token_program:    Program<'info, Token2022>,
 }
 
-pub fn mint_with_oracle(ctx: Context<MintWithOracle>) -> Result<()> {
-    let oracle = &mut ctx.accounts.oracle_config;
-    let quote  = &ctx.accounts.quote;
+pub fn mint_with_oracle<'info>(ctx: Context<'_, '_, '_, 'info, MintWithOracle<'info>>) -> Result<()> {
+    let quote = &ctx.accounts.quote;
 
-    require!(!oracle.paused, OracleError::OraclePaused);
+    require!(!ctx.accounts.oracle_config.paused, OracleError::OraclePaused);
 
     // Check quote expiry
     let now = Clock::get()?.unix_timestamp;
@@ -67,7 +134,36 @@ pub fn mint_with_oracle(ctx: Context<MintWithOracle>) -> Result<()> {
         OracleError::SlippageExceeded
     );
 
+    // sss-core compliance: requester and recipient
+    let min_kyc_tier = ctx.accounts.oracle_config.min_kyc_tier;
+    check_participant(
+        &ctx.accounts.core_config,
+        min_kyc_tier,
+        &QuoteDirection::Mint,
+        &ctx.accounts.requester.key(),
+        ctx.accounts.requester_allowlist.as_deref(),
+        ctx.accounts.requester_blacklist.as_deref(),
+    )?;
+    check_recipient(
+        &ctx.accounts.core_config,
+        min_kyc_tier,
+        &ctx.accounts.destination,
+        ctx.accounts.recipient_allowlist.as_deref(),
+        ctx.accounts.recipient_blacklist.as_deref(),
+    )?;
+
+    // Rolling USD caps
+    enforce_caps(
+        &mut ctx.accounts.oracle_config,
+        ctx.accounts.user_usage.as_deref_mut().map(|u| &mut **u),
+        ctx.accounts.requester.key(),
+        QuoteDirection::Mint,
+        quote.input_amount,
+        now,
+    )?;
+
     // Update oracle lifetime stats
+    let oracle = &mut ctx.accounts.oracle_config;
     oracle.total_minted_usd = oracle
         .total_minted_usd
         .checked_add(quote.input_amount)
@@ -78,20 +174,37 @@ pub fn mint_with_oracle(ctx: Context<MintWithOracle>) -> Result<()> {
         .ok_or(OracleError::MathOverflow)?;
     oracle.last_updated_at = now;
 
-    // ── CPI to sss-core::mint would go here ──
-    // When wired, this would:
-    //   1. Build CPI accounts from remaining_accounts
-    //   2. Call sss_core::cpi::mint(cpi_ctx, quote.output_amount)
-    //   3. The oracle PDA would need to be a registered minter in sss-core
-    //
-    // For now, the quote consumption + event emission is functional.
-    // The integrator can read the emitted event and execute the mint
-    // through the sss-core SDK separately.
+    // ── CPI to sss-core::mint, signed by the oracle config PDA ──
+    let mint_key = oracle.mint;
+    let bump     = oracle.bump;
+    let seeds    = &[b"sss-oracle".as_ref(), mint_key.as_ref(), &[bump]];
+    let signer   = &[&seeds[..]];
+
+    let mint_to = |destination: AccountInfo<'info>, amount: u64| -> Result<()> {
+        let cpi_accounts = sss_core::cpi::accounts::MintTokens {
+            minter:        ctx.accounts.oracle_config.to_account_info(),
+            config:        ctx.accounts.core_config.to_account_info(),
+            minter_config: ctx.accounts.minter_config.to_account_info(),
+            mint:          ctx.accounts.mint.to_account_info(),
+            destination,
+            token_program: ctx.accounts.token_program.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.sss_core_program.to_account_info();
+        let cpi_ctx     = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+        sss_core::cpi::mint(cpi_ctx, amount)
+    };
+
+    mint_to(ctx.accounts.destination.to_account_info(), quote.output_amount)?;
+    if quote.fee_amount > 0 {
+        mint_to(ctx.accounts.fee_vault.to_account_info(), quote.fee_amount)?;
+    }
 
     emit!(OracleMint {
-        mint:         oracle.mint,
-        recipient:    ctx.accounts.requester.key(),
-        usd_amount:   quote.input_amount,
+        mint:         mint_key,
+        recipient:    ctx.accounts.destination.key(),
+        quote_amount: quote.input_amount,
+        denomination: ctx.accounts.oracle_config.quote_denomination,
         token_amount: quote.output_amount,
         fee_amount:   quote.fee_amount,
         price_used:   quote.price_snapshot,
@@ -101,3 +214,595 @@ pub fn mint_with_oracle(ctx: Context<MintWithOracle>) -> Result<()> {
 
     Ok(())
 }
+
+// ═══════════════════════════════════════════════════════════════════════════
+// mint_at_market — Price and mint in one instruction, no quote account
+//
+// For callers that have already checked the price off-chain: the feed is
+// read and the fee applied exactly as in `get_mint_quote`, then the tokens
+// are minted exactly as in `mint_with_oracle`. Nothing is stored, so there
+// is no rent and no second transaction; the caller's protection is
+// `min_output` plus `max_price`, the all-in quote-currency price per whole
+// token (fee included, PRICE_SCALE) — see `effective_mint_price`.
+//
+// A reading that trips the circuit breaker pauses the oracle as a quote
+// would; the instruction then mints nothing and returns `tripped = true`.
+//
+// The feed's source accounts are passed as `remaining_accounts`, as for
+// `get_mint_quote`.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MintAtMarketParams {
+    /// Quote units paid (`OracleConfig.quote_denomination`)
+    pub input_amount: u64,
+    /// Minimum net tokens to receive (slippage floor)
+    pub min_output:   u64,
+    /// Highest all-in price accepted: quote currency per whole token, fee included (PRICE_SCALE)
+    pub max_price:    u64,
+}
+
+#[derive(Accounts)]
+pub struct MintAtMarket<'info> {
+    pub requester: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump  = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        seeds = [b"sss-feed", oracle_config.feed_symbol.as_bytes()],
+        bump  = feed.bump,
+        constraint = feed.active @ OracleError::FeedInactive,
+    )]
+    pub feed: Box<Account<'info, FeedEntry>>,
+
+    /// Required unless `oracle_config.pricing_mode` is `Spot`
+    #[account(
+        seeds = [b"sss-accumulator", oracle_config.feed_symbol.as_bytes()],
+        bump  = accumulator.bump,
+    )]
+    pub accumulator: Option<Box<Account<'info, PriceAccumulator>>>,
+
+    /// The peg fee curve's market feed — required when `oracle_config.peg_fee_curve` is set
+    pub market_feed: Option<Box<Account<'info, FeedEntry>>>,
+
+    /// sss-core stablecoin config for the oracle's mint
+    #[account(
+        mut,
+        seeds = [b"sss-config", mint.key().as_ref()],
+        seeds::program = sss_core::ID,
+        bump = core_config.bump,
+        has_one = mint,
+    )]
+    pub core_config: Box<Account<'info, StablecoinConfig>>,
+
+    /// sss-core minter entry registered for the oracle config PDA
+    #[account(
+        mut,
+        seeds = [b"sss-minter", mint.key().as_ref(), oracle_config.key().as_ref()],
+        seeds::program = sss_core::ID,
+        bump = minter_config.bump,
+    )]
+    pub minter_config: Box<Account<'info, MinterConfig>>,
+
+    /// CHECK: Must match oracle_config.mint — validated by Token-2022 in the sss-core CPI
+    #[account(
+        mut,
+        constraint = mint.key() == oracle_config.mint @ OracleError::MintMismatch,
+    )]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Recipient token account — validated by Token-2022 in the sss-core CPI
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// CHECK: Oracle fee vault PDA (created by `initialize_fee_vault`) — receives the mint fee
+    #[account(
+        mut,
+        seeds = [b"sss-fee-vault", mint.key().as_ref()],
+        bump,
+    )]
+    pub fee_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Requester's sss-core allowlist entry — checked in `check_participant`.
+    /// Required when the mint has an active allowlist or the oracle a `min_kyc_tier`
+    pub requester_allowlist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Requester's sss-core blacklist entry PDA (may be empty) — checked in
+    /// `check_participant`. Required when the mint has a blacklister
+    pub requester_blacklist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Recipient's sss-core allowlist entry — checked in `check_recipient`
+    pub recipient_allowlist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Recipient's sss-core blacklist entry PDA (may be empty) — checked in `check_recipient`
+    pub recipient_blacklist: Option<UncheckedAccount<'info>>,
+
+    /// Requester's rolling USD usage — required when the oracle sets a per-user mint cap
+    #[account(
+        mut,
+        seeds = [b"sss-usage", oracle_config.mint.as_ref(), requester.key().as_ref()],
+        bump  = user_usage.bump,
+    )]
+    pub user_usage: Option<Box<Account<'info, UserUsage>>>,
+
+    pub sss_core_program: Program<'info, SssCore>,
+    pub token_program:    Program<'info, Token2022>,
+}
+
+pub fn mint_at_market<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintAtMarket<'info>>,
+    params: MintAtMarketParams,
+) -> Result<QuoteResult> {
+    require!(!ctx.accounts.oracle_config.paused, OracleError::OraclePaused);
+    require!(params.input_amount > 0, OracleError::ZeroAmount);
+
+    // sss-core compliance: requester and recipient
+    let min_kyc_tier = ctx.accounts.oracle_config.min_kyc_tier;
+    check_participant(
+        &ctx.accounts.core_config,
+        min_kyc_tier,
+        &QuoteDirection::Mint,
+        &ctx.accounts.requester.key(),
+        ctx.accounts.requester_allowlist.as_deref(),
+        ctx.accounts.requester_blacklist.as_deref(),
+    )?;
+    check_recipient(
+        &ctx.accounts.core_config,
+        min_kyc_tier,
+        &ctx.accounts.destination,
+        ctx.accounts.recipient_allowlist.as_deref(),
+        ctx.accounts.recipient_blacklist.as_deref(),
+    )?;
+
+    let feed     = &ctx.accounts.feed;
+    let rounding = price_rounding(&feed.feed_type, &QuoteDirection::Mint);
+    let now      = Clock::get()?.unix_timestamp;
+    let (feed_accounts, market_accounts) =
+        split_market_accounts(&ctx.accounts.oracle_config, feed, ctx.remaining_accounts)?;
+    let quoted = quote_price(
+        &mut ctx.accounts.oracle_config,
+        feed,
+        feed_accounts,
+        ctx.accounts.accumulator.as_deref().map(|a| &**a),
+        rounding,
+        now,
+    )?;
+    let QuotePrice { price: price_scaled, .. } = match quoted {
+        PriceOutcome::Priced(p)     => p,
+        PriceOutcome::Tripped(spot) => return Ok(tripped_result(spot, now)),
+    };
+
+    // ── 1. Price the mint ──
+    let oracle = &mut ctx.accounts.oracle_config;
+    // A higher multiplier mints fewer tokens
+    let fair = SwapLeg {
+        price_scaled,
+        feed_type:      &feed.feed_type,
+        cpi_multiplier: oracle.cpi_multiplier_at(now, Rounding::Up)?,
+        token_decimals: oracle.token_decimals,
+        price_decimals: feed.decimals,
+    };
+    let gross_tokens = calc_token_amount_for_quote(
+        params.input_amount,
+        price_scaled,
+        &feed.feed_type,
+        fair.cpi_multiplier,
+        oracle.token_decimals,
+        feed.decimals,
+        oracle.quote_denomination.decimals,
+    )?;
+    require!(gross_tokens > 0, OracleError::ZeroOutput);
+
+    let (fee_bps, _) = quote_fee_bps(
+        oracle,
+        &QuoteDirection::Mint,
+        &fair,
+        ctx.accounts.market_feed.as_deref(),
+        market_accounts,
+    )?;
+    let (net_tokens, fee_tokens) = apply_fee(gross_tokens, fee_bps)?;
+
+    // ── 2. Slippage: output floor and price ceiling ──
+    check_slippage(net_tokens, params.min_output)?;
+    require!(
+        effective_mint_price(
+            params.input_amount,
+            net_tokens,
+            oracle.token_decimals,
+            oracle.quote_denomination.decimals,
+        )? <= params.max_price,
+        OracleError::PriceAboveMax
+    );
+
+    // ── 3. Rolling USD caps, then oracle lifetime stats ──
+    enforce_caps(
+        oracle,
+        ctx.accounts.user_usage.as_deref_mut().map(|u| &mut **u),
+        ctx.accounts.requester.key(),
+        QuoteDirection::Mint,
+        params.input_amount,
+        now,
+    )?;
+    oracle.total_minted_usd = oracle
+        .total_minted_usd
+        .checked_add(params.input_amount)
+        .ok_or(OracleError::MathOverflow)?;
+    oracle.total_fees_collected = oracle
+        .total_fees_collected
+        .checked_add(fee_tokens)
+        .ok_or(OracleError::MathOverflow)?;
+    oracle.last_updated_at = now;
+
+    // ── 4. CPI to sss-core::mint, signed by the oracle config PDA ──
+    let mint_key           = oracle.mint;
+    let feed_symbol        = oracle.feed_symbol.clone();
+    let quote_denomination = oracle.quote_denomination;
+    let bump               = oracle.bump;
+    let seeds              = &[b"sss-oracle".as_ref(), mint_key.as_ref(), &[bump]];
+    let signer             = &[&seeds[..]];
+
+    let mint_to = |destination: AccountInfo<'info>, amount: u64| -> Result<()> {
+        let cpi_accounts = sss_core::cpi::accounts::MintTokens {
+            minter:        ctx.accounts.oracle_config.to_account_info(),
+            config:        ctx.accounts.core_config.to_account_info(),
+            minter_config: ctx.accounts.minter_config.to_account_info(),
+            mint:          ctx.accounts.mint.to_account_info(),
+            destination,
+            token_program: ctx.accounts.token_program.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.sss_core_program.to_account_info();
+        let cpi_ctx     = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+        sss_core::cpi::mint(cpi_ctx, amount)
+    };
+
+    mint_to(ctx.accounts.destination.to_account_info(), net_tokens)?;
+    if fee_tokens > 0 {
+        mint_to(ctx.accounts.fee_vault.to_account_info(), fee_tokens)?;
+    }
+
+    emit!(OracleMint {
+        mint:         mint_key,
+        recipient:    ctx.accounts.destination.key(),
+        quote_amount: params.input_amount,
+        denomination: quote_denomination,
+        token_amount: net_tokens,
+        fee_amount:   fee_tokens,
+        price_used:   price_scaled,
+        feed_symbol,
+        timestamp:    now,
+    });
+
+    Ok(QuoteResult {
+        output_amount: net_tokens,
+        fee_amount:    fee_tokens,
+        price_used:    price_scaled,
+        valid_until:   now,
+        quote_account: Pubkey::default(),
+        tripped:       false,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// redeem_with_oracle — Atomic redeem quote consumption + settlement record
+//
+// Takes `quote.input_amount` tokens from the requester. The token share of
+// the redeem fee goes to the oracle fee vault; the rest goes into the oracle
+// escrow (the oracle config PDA's associated token account) and is burned
+// through sss-core in the same instruction, so the oracle config PDA must
+// hold the sss-core burner role. Nothing is left in the escrow.
+//
+// Writes a RedemptionSettlement with the USD owed so the off-chain fiat
+// payout can be reconciled and later confirmed with `confirm_settlement`.
+//
+// Transfer-hook extra accounts (SSS-2/SSS-3 mints) are passed through
+// `remaining_accounts` to the Token-2022 transfer.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct RedeemWithOracle<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump  = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sss-quote",
+            oracle_config.mint.as_ref(),
+            requester.key().as_ref(),
+            &quote.nonce.to_le_bytes(),
+        ],
+        bump  = quote.bump,
+        constraint = quote.requester == requester.key() @ OracleError::Unauthorized,
+        constraint = !quote.used @ OracleError::QuoteAlreadyUsed,
+        constraint = quote.direction == QuoteDirection::Redeem @ OracleError::Unauthorized,
+        close = requester,
+    )]
+    pub quote: Account<'info, PendingQuote>,
+
+    #[account(
+        init,
+        payer  = requester,
+        space  = RedemptionSettlement::LEN,
+        seeds  = [
+            b"sss-settlement",
+            oracle_config.mint.as_ref(),
+            requester.key().as_ref(),
+            &quote.nonce.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub settlement: Account<'info, RedemptionSettlement>,
+
+    /// sss-core stablecoin config for the oracle's mint
+    #[account(
+        mut,
+        seeds = [b"sss-config", mint.key().as_ref()],
+        seeds::program = sss_core::ID,
+        bump = core_config.bump,
+        has_one = mint,
+    )]
+    pub core_config: Box<Account<'info, StablecoinConfig>>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == oracle_config.mint @ OracleError::MintMismatch,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Requester's token account the redeemed tokens are taken from
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = requester,
+        token::token_program = token_program,
+    )]
+    pub source: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Oracle escrow — associated token account of the oracle config PDA
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = oracle_config,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Oracle fee vault — receives the token share of the redeem fee
+    #[account(
+        mut,
+        seeds = [b"sss-fee-vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Requester's sss-core allowlist entry — checked in `check_participant`.
+    /// Required when the mint has an active allowlist or the oracle a `min_kyc_tier`
+    pub requester_allowlist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Requester's sss-core blacklist entry PDA (may be empty) — checked in
+    /// `check_participant`. Required when the mint has a blacklister
+    pub requester_blacklist: Option<UncheckedAccount<'info>>,
+
+    /// Requester's rolling USD usage — required when the oracle sets a per-user redeem cap
+    #[account(
+        mut,
+        seeds = [b"sss-usage", oracle_config.mint.as_ref(), requester.key().as_ref()],
+        bump  = user_usage.bump,
+    )]
+    pub user_usage: Option<Box<Account<'info, UserUsage>>>,
+
+    pub sss_core_program: Program<'info, SssCore>,
+    pub token_program:    Program<'info, Token2022>,
+    pub system_program:   Program<'info, System>,
+}
+
+pub fn redeem_with_oracle<'info>(
+    ctx: Context<'_, '_, '_, 'info, RedeemWithOracle<'info>>,
+) -> Result<()> {
+    let quote = &ctx.accounts.quote;
+
+    require!(!ctx.accounts.oracle_config.paused, OracleError::OraclePaused);
+    require!(
+        ctx.accounts.core_config.burner == ctx.accounts.oracle_config.key(),
+        OracleError::RedeemRequiresBurner
+    );
+
+    // Check quote expiry
+    let now = Clock::get()?.unix_timestamp;
+    require!(!quote.is_expired(now), OracleError::QuoteExpired);
+
+    // Slippage validation against the stored min_output
+    require!(
+        quote.output_amount >= quote.min_output,
+        OracleError::SlippageExceeded
+    );
+
+    // sss-core compliance: the requester sends the tokens
+    check_participant(
+        &ctx.accounts.core_config,
+        ctx.accounts.oracle_config.min_kyc_tier,
+        &QuoteDirection::Redeem,
+        &ctx.accounts.requester.key(),
+        ctx.accounts.requester_allowlist.as_deref(),
+        ctx.accounts.requester_blacklist.as_deref(),
+    )?;
+
+    let token_amount = quote.input_amount;
+    let gross_usd    = quote
+        .output_amount
+        .checked_add(quote.fee_amount)
+        .ok_or(OracleError::MathOverflow)?;
+
+    // Rolling USD caps
+    enforce_caps(
+        &mut ctx.accounts.oracle_config,
+        ctx.accounts.user_usage.as_deref_mut().map(|u| &mut **u),
+        ctx.accounts.requester.key(),
+        QuoteDirection::Redeem,
+        gross_usd,
+        now,
+    )?;
+
+    // Token share of the USD fee, rounded up like every fee
+    let fee_tokens    = pro_rata(token_amount, quote.fee_amount, gross_usd, Rounding::Up)?;
+    let escrow_amount = token_amount.checked_sub(fee_tokens).ok_or(OracleError::MathOverflow)?;
+
+    let mint_key = ctx.accounts.oracle_config.mint;
+    let bump     = ctx.accounts.oracle_config.bump;
+    let seeds    = &[b"sss-oracle".as_ref(), mint_key.as_ref(), &[bump]];
+    let signer   = &[&seeds[..]];
+
+    // ── 1. Escrow the requester's tokens, fee share to the fee vault ──
+    // Both destinations are owned by the oracle config PDA, so the same
+    // transfer-hook extra accounts resolve for either transfer.
+    let transfer_in = |destination: AccountInfo<'info>, amount: u64| -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        invoke_transfer_checked(
+            &ctx.accounts.token_program.key(),
+            ctx.accounts.source.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            destination,
+            ctx.accounts.requester.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[],
+        )?;
+        Ok(())
+    };
+    transfer_in(ctx.accounts.escrow.to_account_info(), escrow_amount)?;
+    transfer_in(ctx.accounts.fee_vault.to_account_info(), fee_tokens)?;
+
+    // ── 2. Burn the escrowed tokens through sss-core ──
+    if escrow_amount > 0 {
+        let cpi_accounts = sss_core::cpi::accounts::BurnTokens {
+            burner:        ctx.accounts.oracle_config.to_account_info(),
+            config:        ctx.accounts.core_config.to_account_info(),
+            source:        ctx.accounts.escrow.to_account_info(),
+            mint:          ctx.accounts.mint.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.sss_core_program.to_account_info();
+        let cpi_ctx     = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+        sss_core::cpi::burn(cpi_ctx, escrow_amount)?;
+    }
+
+    // ── 3. Update oracle lifetime stats ──
+    let oracle = &mut ctx.accounts.oracle_config;
+    oracle.total_redeemed_usd = oracle
+        .total_redeemed_usd
+        .checked_add(gross_usd)
+        .ok_or(OracleError::MathOverflow)?;
+    oracle.total_fees_collected = oracle
+        .total_fees_collected
+        .checked_add(fee_tokens)
+        .ok_or(OracleError::MathOverflow)?;
+    oracle.last_updated_at = now;
+
+    // ── 4. Record the USD owed for fiat payout reconciliation ──
+    let settlement = &mut ctx.accounts.settlement;
+    settlement.mint             = oracle.mint;
+    settlement.redeemer         = ctx.accounts.requester.key();
+    settlement.token_amount     = token_amount;
+    settlement.usd_owed         = quote.output_amount;
+    settlement.fee_amount       = quote.fee_amount;
+    settlement.price_used       = quote.price_snapshot;
+    settlement.feed_symbol      = quote.feed_symbol.clone();
+    settlement.settled          = false;
+    settlement.payout_reference = String::new();
+    settlement.created_at       = now;
+    settlement.settled_at       = 0;
+    settlement.nonce            = quote.nonce;
+    settlement.bump             = ctx.bumps.settlement;
+
+    emit!(OracleRedeem {
+        mint:         oracle.mint,
+        redeemer:     ctx.accounts.requester.key(),
+        settlement:   settlement.key(),
+        token_amount,
+        quote_amount: quote.output_amount,
+        denomination: oracle.quote_denomination,
+        fee_amount:   quote.fee_amount,
+        price_used:   quote.price_snapshot,
+        feed_symbol:  quote.feed_symbol.clone(),
+        fee_tokens,
+        timestamp:    now,
+    });
+
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// confirm_settlement — Admin records the fiat payout for a redemption
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct ConfirmSettlement<'info> {
+    #[account(
+        constraint = admin.key() == oracle_config.admin @ OracleError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump  = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sss-settlement",
+            oracle_config.mint.as_ref(),
+            settlement.redeemer.as_ref(),
+            &settlement.nonce.to_le_bytes(),
+        ],
+        bump  = settlement.bump,
+        constraint = !settlement.settled @ OracleError::AlreadySettled,
+    )]
+    pub settlement: Account<'info, RedemptionSettlement>,
+}
+
+pub fn confirm_settlement(ctx: Context<ConfirmSettlement>, payout_reference: String) -> Result<()> {
+    require!(
+        payout_reference.len() <= MAX_PAYOUT_REFERENCE,
+        OracleError::PayoutReferenceTooLong
+    );
+
+    let now        = Clock::get()?.unix_timestamp;
+    let settlement = &mut ctx.accounts.settlement;
+
+    settlement.settled          = true;
+    settlement.payout_reference = payout_reference.clone();
+    settlement.settled_at       = now;
+
+    emit!(RedemptionSettled {
+        mint:             settlement.mint,
+        settlement:       settlement.key(),
+        redeemer:         settlement.redeemer,
+        usd_owed:         settlement.usd_owed,
+        payout_reference,
+        settled_by:       ctx.accounts.admin.key(),
+        timestamp:        now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use crate::state::*;
+use crate::errors::OracleError;
+use crate::events::*;
+use crate::math::split_by_bps;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// initialize_fee_vault — Token account owned by the oracle config PDA
+// PDA seed: ["sss-fee-vault", mint]
+//
+// Receives mint fees (minted through sss-core) and the token share of
+// redeem fees.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct InitializeFeeVault<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = fee_manager.key() == oracle_config.fee_manager @ OracleError::Unauthorized
+    )]
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump  = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    #[account(
+        constraint = mint.key() == oracle_config.mint @ OracleError::MintMismatch,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"sss-fee-vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = oracle_config,
+        token::token_program = token_program,
+    )]
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program:  Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_fee_vault(ctx: Context<InitializeFeeVault>) -> Result<()> {
+    emit!(FeeVaultInitialized {
+        mint:      ctx.accounts.mint.key(),
+        fee_vault: ctx.accounts.fee_vault.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// set_fee_splits — Configure revenue-share recipients
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct SetFeeSplits<'info> {
+    #[account(
+        constraint = fee_manager.key() == oracle_config.fee_manager @ OracleError::Unauthorized
+    )]
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump  = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+}
+
+pub fn set_fee_splits(ctx: Context<SetFeeSplits>, splits: Vec<FeeSplit>) -> Result<()> {
+    require!(splits.len() <= MAX_FEE_SPLITS, OracleError::InvalidFeeSplits);
+    let mut total_bps = 0u32;
+    for (i, split) in splits.iter().enumerate() {
+        require!(split.bps > 0, OracleError::InvalidFeeSplits);
+        require!(
+            !splits[..i].iter().any(|s| s.recipient == split.recipient),
+            OracleError::InvalidFeeSplits
+        );
+        total_bps += split.bps as u32;
+    }
+    require!(total_bps <= 10_000, OracleError::InvalidFeeSplits);
+
+    let oracle = &mut ctx.accounts.oracle_config;
+    let now    = Clock::get()?.unix_timestamp;
+
+    oracle.fee_splits      = splits.clone();
+    oracle.last_updated_at = now;
+
+    emit!(FeeSplitsUpdated {
+        mint:      oracle.mint,
+        splits,
+        by:        ctx.accounts.fee_manager.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// withdraw_fees — Pay out the fee vault
+//
+// Each `fee_splits` recipient gets its bps share (rounded down); the
+// remainder goes to `treasury`. `remaining_accounts` starts with the split
+// recipients' token accounts, in `fee_splits` order, followed by any
+// transfer-hook extra accounts.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(
+        constraint = fee_manager.key() == oracle_config.fee_manager @ OracleError::Unauthorized
+    )]
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump  = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    #[account(
+        constraint = mint.key() == oracle_config.mint @ OracleError::MintMismatch,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-fee-vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury token account receiving the non-split remainder
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn withdraw_fees<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawFees<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, OracleError::ZeroAmount);
+    require!(amount <= ctx.accounts.fee_vault.amount, OracleError::InsufficientFees);
+
+    let oracle      = &ctx.accounts.oracle_config;
+    let split_count = oracle.fee_splits.len();
+    require!(
+        ctx.remaining_accounts.len() >= split_count,
+        OracleError::FeeSplitAccountMismatch
+    );
+    let (recipients, hook_accounts) = ctx.remaining_accounts.split_at(split_count);
+    for (account, split) in recipients.iter().zip(oracle.fee_splits.iter()) {
+        require!(account.key() == split.recipient, OracleError::FeeSplitAccountMismatch);
+    }
+
+    let shares: Vec<u16> = oracle.fee_splits.iter().map(|s| s.bps).collect();
+    let (split_amounts, to_treasury) = split_by_bps(amount, &shares)?;
+
+    let mint_key = oracle.mint;
+    let bump     = oracle.bump;
+    let seeds    = &[b"sss-oracle".as_ref(), mint_key.as_ref(), &[bump]];
+    let signer   = &[&seeds[..]];
+
+    let transfer = |destination: AccountInfo<'info>, amount: u64| -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        invoke_transfer_checked(
+            &ctx.accounts.token_program.key(),
+            ctx.accounts.fee_vault.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            destination,
+            ctx.accounts.oracle_config.to_account_info(),
+            hook_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            signer,
+        )?;
+        Ok(())
+    };
+
+    for (account, part) in recipients.iter().zip(split_amounts.iter()) {
+        transfer(account.clone(), *part)?;
+    }
+    transfer(ctx.accounts.treasury.to_account_info(), to_treasury)?;
+
+    emit!(FeesWithdrawn {
+        mint:          mint_key,
+        treasury:      ctx.accounts.treasury.key(),
+        amount,
+        to_treasury,
+        split_amounts,
+        by:            ctx.accounts.fee_manager.key(),
+        timestamp:     Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -3,9 +3,19 @@ pub mod oracle_config;
 pub mod quotes;
 pub mod execute;
 pub mod cpi_update;
+pub mod accumulator;
+pub mod fees;
+pub mod swap;
+pub mod psm;
+pub mod usage;
 
 pub use registry::*;
 pub use oracle_config::*;
 pub use quotes::*;
 pub use execute::*;
 pub use cpi_update::*;
+pub use accumulator::*;
+pub use fees::*;
+pub use swap::*;
+pub use psm::*;
+pub use usage::*;
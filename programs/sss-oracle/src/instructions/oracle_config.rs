@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::OracleError;
 use crate::events::*;
+use crate::math::{price_move_bps, MAX_AMOUNT_DECIMALS};
+use anchor_spl::token_interface::Mint;
+use sss_core::state::StablecoinConfig;
 
 // ═══════════════════════════════════════════════════════════════════════════
 // initialize_oracle — Per-mint oracle config setup
@@ -11,14 +14,42 @@ use crate::events::*;
 pub struct InitializeOracleParams {
     pub feed_symbol:             String,
     pub description:             String,
+    /// Unit of mint inputs, redeem outputs and caps; the feed must price the
+    /// token in its currency. Fixed for the oracle's lifetime.
+    pub quote_denomination:      QuoteDenomination,
     pub max_staleness_secs:      i64,
     pub mint_fee_bps:            u16,
     pub redeem_fee_bps:          u16,
+    /// Fee on swaps into this mint
+    pub swap_fee_bps:            u16,
     pub max_confidence_bps:      u16,
     pub quote_validity_secs:     i64,
     pub cpi_multiplier:          u64,
     pub cpi_min_update_interval: i64,
+    /// Max change of one CPI update, in bps of the current multiplier
+    pub cpi_max_change_bps:      u16,
+    /// M-of-N attesters required for CPI updates (empty = CPI updater alone)
+    pub cpi_attesters:           CpiAttesterSet,
     pub cpi_data_source:         String,
+    /// Max price move per window in bps (0 = circuit breaker off)
+    pub max_price_move_bps:      u16,
+    pub price_move_window_secs:  i64,
+    /// Spot, TWAP, EMA or Conservative (non-Spot modes need an accumulator)
+    pub pricing_mode:            PricingMode,
+    /// Delay for later risk-parameter updates
+    pub config_timelock_secs:    i64,
+    /// Band around the last good price for emergency manual prices (0 = disabled)
+    pub manual_price_band_bps:   u16,
+    /// Minimum sss-core allowlist KYC tier for oracle issuance (0 = none)
+    pub min_kyc_tier:            u8,
+    /// Rolling 24h caps in `quote_denomination` units despite the `_usd`
+    /// suffix, all users then per requester (0 = none)
+    pub global_mint_cap_usd:     u64,
+    pub global_redeem_cap_usd:   u64,
+    pub user_mint_cap_usd:       u64,
+    pub user_redeem_cap_usd:     u64,
+    /// Peg-defense fee curve over the flat fees (no points = off)
+    pub peg_fee_curve:           PegFeeCurve,
 }
 
 #[derive(Accounts)]
@@ -27,10 +58,22 @@ pub struct InitializeOracle<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// Must be the mint's sss-core master authority
+    #[account(
+        constraint = authority.key() == core_config.master_authority @ OracleError::Unauthorized
+    )]
     pub authority: Signer<'info>,
 
-    /// CHECK: The SSS token mint address
-    pub mint: UncheckedAccount<'info>,
+    /// The SSS token mint; its decimals drive the quote math
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// sss-core stablecoin config for the mint
+    #[account(
+        seeds = [b"sss-config", mint.key().as_ref()],
+        seeds::program = sss_core::ID,
+        bump = core_config.bump,
+    )]
+    pub core_config: Box<Account<'info, StablecoinConfig>>,
 
     #[account(
         init,
@@ -42,10 +85,11 @@ pub struct InitializeOracle<'info> {
     pub oracle_config: Account<'info, OracleConfig>,
 
     #[account(
-        seeds = [b"sss-feed-registry"],
-        bump = registry.bump,
+        seeds = [b"sss-feed", params.feed_symbol.as_bytes()],
+        bump = feed.bump,
+        constraint = feed.active @ OracleError::FeedInactive,
     )]
-    pub registry: Account<'info, FeedRegistry>,
+    pub feed: Account<'info, FeedEntry>,
 
     pub system_program: Program<'info, System>,
 }
@@ -54,43 +98,87 @@ pub fn initialize_oracle(
     ctx: Context<InitializeOracle>,
     params: InitializeOracleParams,
 ) -> Result<()> {
-    // Verify feed exists in registry
+    require!(params.cpi_multiplier > 0, OracleError::InvalidCpiMultiplier);
     require!(
-        ctx.accounts.registry.find_feed(&params.feed_symbol).is_some(),
-        OracleError::FeedNotFound
+        ctx.accounts.mint.decimals <= MAX_AMOUNT_DECIMALS,
+        OracleError::UnsupportedDecimals
+    );
+    params.quote_denomination.validate()?;
+    require!(
+        params.quote_denomination.matches_feed(&ctx.accounts.feed),
+        OracleError::FeedCurrencyMismatch
     );
-    require!(params.cpi_multiplier > 0, OracleError::InvalidCpiMultiplier);
 
     let now = Clock::get()?.unix_timestamp;
     let oracle = &mut ctx.accounts.oracle_config;
 
-    oracle.version                 = 1;
+    oracle.version                 = ORACLE_CONFIG_VERSION;
     oracle.mint                    = ctx.accounts.mint.key();
-    oracle.authority               = ctx.accounts.authority.key();
-    oracle.pending_authority       = None;
+    oracle.token_decimals          = ctx.accounts.mint.decimals;
+    oracle.quote_denomination      = params.quote_denomination;
+    // Every role starts with the initializing authority; hand them out after
+    oracle.admin                   = ctx.accounts.authority.key();
+    oracle.pending_admin           = None;
+    oracle.pauser                  = ctx.accounts.authority.key();
+    oracle.pending_pauser          = None;
+    oracle.cpi_updater             = ctx.accounts.authority.key();
+    oracle.pending_cpi_updater     = None;
+    oracle.fee_manager             = ctx.accounts.authority.key();
+    oracle.pending_fee_manager     = None;
     oracle.feed_symbol             = params.feed_symbol.clone();
     oracle.description             = params.description;
     oracle.max_staleness_secs      = params.max_staleness_secs;
     oracle.mint_fee_bps            = params.mint_fee_bps;
     oracle.redeem_fee_bps          = params.redeem_fee_bps;
+    oracle.swap_fee_bps            = params.swap_fee_bps;
     oracle.max_confidence_bps      = params.max_confidence_bps;
     oracle.quote_validity_secs     = params.quote_validity_secs;
     oracle.cpi_multiplier          = params.cpi_multiplier;
+    oracle.cpi_target_multiplier   = params.cpi_multiplier;
+    oracle.cpi_accrual_start       = now;
+    oracle.cpi_accrual_end         = now;
+    oracle.cpi_max_change_bps      = params.cpi_max_change_bps;
     oracle.cpi_last_updated        = now;
     oracle.cpi_min_update_interval = params.cpi_min_update_interval;
     oracle.cpi_data_source         = params.cpi_data_source;
+    oracle.cpi_attesters           = params.cpi_attesters;
+    oracle.cpi_reference_month     = String::new();
     oracle.paused                  = false;
     oracle.pause_reason            = String::new();
+    oracle.pause_code              = PauseReason::None;
+    oracle.last_good_price         = 0;
+    oracle.last_good_price_at      = 0;
+    oracle.max_price_move_bps      = params.max_price_move_bps;
+    oracle.price_move_window_secs  = params.price_move_window_secs;
+    oracle.tripped_price           = 0;
+    oracle.manual_price_band_bps   = params.manual_price_band_bps;
+    oracle.manual_price            = 0;
+    oracle.manual_price_expires_at = 0;
+    oracle.manual_price_reason     = String::new();
+    oracle.pricing_mode            = params.pricing_mode;
+    oracle.config_timelock_secs    = params.config_timelock_secs;
+    oracle.pending_update          = None;
+    oracle.pending_update_eta      = 0;
+    oracle.fee_splits              = Vec::new();
+    oracle.min_kyc_tier            = params.min_kyc_tier;
+    oracle.global_mint_cap_usd     = params.global_mint_cap_usd;
+    oracle.global_redeem_cap_usd   = params.global_redeem_cap_usd;
+    oracle.user_mint_cap_usd       = params.user_mint_cap_usd;
+    oracle.user_redeem_cap_usd     = params.user_redeem_cap_usd;
+    oracle.global_mint_usage       = UsageWindow::default();
+    oracle.global_redeem_usage     = UsageWindow::default();
+    oracle.peg_fee_curve           = params.peg_fee_curve;
     oracle.total_minted_usd        = 0;
     oracle.total_redeemed_usd      = 0;
     oracle.total_fees_collected    = 0;
     oracle.created_at              = now;
     oracle.last_updated_at         = now;
     oracle.bump                    = ctx.bumps.oracle_config;
+    oracle.validate_risk_params()?;
 
     emit!(OracleInitialized {
         mint:           ctx.accounts.mint.key(),
-        authority:      ctx.accounts.authority.key(),
+        admin:          ctx.accounts.authority.key(),
         feed_symbol:    params.feed_symbol,
         mint_fee_bps:   oracle.mint_fee_bps,
         redeem_fee_bps: oracle.redeem_fee_bps,
@@ -102,14 +190,24 @@ pub fn initialize_oracle(
 
 // ═══════════════════════════════════════════════════════════════════════════
 // pause_oracle / unpause_oracle
+//
+// Unpausing must name the current `pause_code`, so an automatic circuit
+// breaker pause is never cleared by a blind unpause. Acknowledging a breaker
+// pause accepts the tripping price as the new reference price.
 // ═══════════════════════════════════════════════════════════════════════════
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UnpauseParams {
+    /// Must equal `oracle_config.pause_code`
+    pub acknowledge: PauseReason,
+}
+
 #[derive(Accounts)]
 pub struct PauseOracle<'info> {
     #[account(
-        constraint = authority.key() == oracle_config.authority @ OracleError::Unauthorized
+        constraint = pauser.key() == oracle_config.pauser @ OracleError::Unauthorized
     )]
-    pub authority: Signer<'info>,
+    pub pauser: Signer<'info>,
 
     #[account(
         mut,
@@ -126,31 +224,208 @@ pub fn pause_oracle(ctx: Context<PauseOracle>, reason: String) -> Result<()> {
     oracle.paused          = true;
     oracle.pause_reason    = reason.clone();
     oracle.last_updated_at = now;
+    // A manual pause on top of a tripped breaker still needs the breaker ack
+    if oracle.pause_code != PauseReason::CircuitBreaker {
+        oracle.pause_code = PauseReason::Manual;
+    }
 
     emit!(OraclePaused {
         mint:      oracle.mint,
         paused:    true,
+        code:      oracle.pause_code,
         reason,
-        by:        ctx.accounts.authority.key(),
+        by:        ctx.accounts.pauser.key(),
         timestamp: now,
     });
 
     Ok(())
 }
 
-pub fn unpause_oracle(ctx: Context<PauseOracle>) -> Result<()> {
+pub fn unpause_oracle(ctx: Context<PauseOracle>, params: UnpauseParams) -> Result<()> {
     let oracle = &mut ctx.accounts.oracle_config;
     let now    = Clock::get()?.unix_timestamp;
 
+    require!(
+        params.acknowledge == oracle.pause_code,
+        OracleError::PauseAcknowledgementMismatch
+    );
+
+    if oracle.pause_code == PauseReason::CircuitBreaker {
+        oracle.last_good_price    = oracle.tripped_price;
+        oracle.last_good_price_at = now;
+        oracle.tripped_price      = 0;
+    }
+
     oracle.paused          = false;
     oracle.pause_reason    = String::new();
+    oracle.pause_code      = PauseReason::None;
     oracle.last_updated_at = now;
 
     emit!(OraclePaused {
         mint:      oracle.mint,
         paused:    false,
+        code:      params.acknowledge,
         reason:    String::new(),
-        by:        ctx.accounts.authority.key(),
+        by:        ctx.accounts.pauser.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// queue / apply / cancel_config_update — Timelocked risk-parameter changes
+//
+// The admin queues an `OracleParamsUpdate`; it is bounds-checked against
+// the resulting config up front. Once `config_timelock_secs` have passed,
+// anyone can apply it. The admin can cancel it at any time before that.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct QueueConfigUpdate<'info> {
+    #[account(
+        constraint = admin.key() == oracle_config.admin @ OracleError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    /// The feed named by `feed_symbol`; required when the update changes it
+    pub feed: Option<Account<'info, FeedEntry>>,
+}
+
+/// Check that `feed` is the active feed registered as `symbol` and quotes in
+/// the oracle's denomination. Feed PDAs are keyed by symbol, so a
+/// program-owned `FeedEntry` carrying the symbol can only be that symbol's PDA.
+fn check_feed(
+    feed:         Option<&Account<FeedEntry>>,
+    symbol:       &str,
+    denomination: &QuoteDenomination,
+) -> Result<()> {
+    let feed = feed.ok_or(OracleError::FeedNotFound)?;
+    require!(feed.symbol == symbol, OracleError::FeedNotFound);
+    require!(feed.active, OracleError::FeedInactive);
+    require!(denomination.matches_feed(feed), OracleError::FeedCurrencyMismatch);
+    Ok(())
+}
+
+pub fn queue_config_update(
+    ctx: Context<QueueConfigUpdate>,
+    update: OracleParamsUpdate,
+) -> Result<()> {
+    let oracle = &mut ctx.accounts.oracle_config;
+    let now    = Clock::get()?.unix_timestamp;
+
+    require!(!update.is_empty(), OracleError::EmptyConfigUpdate);
+    require!(oracle.pending_update.is_none(), OracleError::ConfigUpdatePending);
+    if let Some(symbol) = &update.feed_symbol {
+        check_feed(ctx.accounts.feed.as_ref(), symbol, &oracle.quote_denomination)?;
+    }
+
+    // Validate the config as it would look after the update
+    let mut preview = (**oracle).clone();
+    preview.apply_update(&update);
+    preview.validate_risk_params()?;
+
+    let eta = now
+        .checked_add(oracle.config_timelock_secs)
+        .ok_or(OracleError::MathOverflow)?;
+    oracle.pending_update     = Some(update.clone());
+    oracle.pending_update_eta = eta;
+    oracle.last_updated_at    = now;
+
+    emit!(ConfigUpdateQueued {
+        mint:      oracle.mint,
+        update,
+        eta,
+        by:        ctx.accounts.admin.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApplyConfigUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    /// The feed named by `feed_symbol`; required when the update changes it
+    pub feed: Option<Account<'info, FeedEntry>>,
+}
+
+pub fn apply_config_update(ctx: Context<ApplyConfigUpdate>) -> Result<()> {
+    let oracle = &mut ctx.accounts.oracle_config;
+    let now    = Clock::get()?.unix_timestamp;
+
+    let update = oracle.pending_update.clone().ok_or(OracleError::NoPendingConfigUpdate)?;
+    require!(now >= oracle.pending_update_eta, OracleError::ConfigTimelockNotElapsed);
+
+    // The feed may have been deactivated while the update was queued
+    if let Some(symbol) = &update.feed_symbol {
+        check_feed(ctx.accounts.feed.as_ref(), symbol, &oracle.quote_denomination)?;
+    }
+
+    // Prices of another feed may use other decimals: restart the breaker
+    // reference and drop any manual price set against the old feed
+    if update.feed_symbol.as_ref().is_some_and(|s| *s != oracle.feed_symbol) {
+        oracle.last_good_price         = 0;
+        oracle.last_good_price_at      = 0;
+        oracle.manual_price            = 0;
+        oracle.manual_price_expires_at = 0;
+        oracle.manual_price_reason     = String::new();
+    }
+    oracle.apply_update(&update);
+    oracle.validate_risk_params()?;
+    oracle.pending_update     = None;
+    oracle.pending_update_eta = 0;
+    oracle.last_updated_at    = now;
+
+    emit!(ConfigUpdateApplied {
+        mint:      oracle.mint,
+        update,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelConfigUpdate<'info> {
+    #[account(
+        constraint = admin.key() == oracle_config.admin @ OracleError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+}
+
+pub fn cancel_config_update(ctx: Context<CancelConfigUpdate>) -> Result<()> {
+    let oracle = &mut ctx.accounts.oracle_config;
+    let now    = Clock::get()?.unix_timestamp;
+
+    let update = oracle.pending_update.take().ok_or(OracleError::NoPendingConfigUpdate)?;
+    oracle.pending_update_eta = 0;
+    oracle.last_updated_at    = now;
+
+    emit!(ConfigUpdateCancelled {
+        mint:      oracle.mint,
+        update,
+        by:        ctx.accounts.admin.key(),
         timestamp: now,
     });
 
@@ -158,13 +433,133 @@ pub fn unpause_oracle(ctx: Context<PauseOracle>) -> Result<()> {
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
-// Two-step authority transfer
+// set_manual_price / clear_manual_price — Emergency price override
+//
+// When the feed's sources are down, the admin can quote off a manual
+// price instead. It must sit within `manual_price_band_bps` of the last good
+// price (the band itself is timelocked) and lapses on its own at
+// `expires_at`. Quotes priced this way are flagged `manual_override`.
 // ═══════════════════════════════════════════════════════════════════════════
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ManualPriceParams {
+    /// Price in the feed's decimals
+    pub price:         u64,
+    /// How long the price applies (at most MAX_MANUAL_PRICE_DURATION_SECS)
+    pub duration_secs: i64,
+    pub reason:        String,
+}
+
 #[derive(Accounts)]
+pub struct SetManualPrice<'info> {
+    #[account(
+        constraint = admin.key() == oracle_config.admin @ OracleError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+}
+
+pub fn set_manual_price(ctx: Context<SetManualPrice>, params: ManualPriceParams) -> Result<()> {
+    let oracle = &mut ctx.accounts.oracle_config;
+    let now    = Clock::get()?.unix_timestamp;
+
+    require!(oracle.manual_price_band_bps > 0, OracleError::ManualPriceDisabled);
+    require!(oracle.last_good_price > 0, OracleError::NoReferencePrice);
+    require!(
+        params.price > 0
+            && params.duration_secs > 0
+            && params.duration_secs <= MAX_MANUAL_PRICE_DURATION_SECS
+            && !params.reason.is_empty()
+            && params.reason.len() <= MAX_MANUAL_PRICE_REASON_LEN,
+        OracleError::InvalidManualPrice
+    );
+
+    let deviation_bps = price_move_bps(oracle.last_good_price, params.price)?;
+    require!(
+        deviation_bps <= oracle.manual_price_band_bps as u64,
+        OracleError::ManualPriceOutOfBand
+    );
+
+    let expires_at = now
+        .checked_add(params.duration_secs)
+        .ok_or(OracleError::MathOverflow)?;
+    oracle.manual_price            = params.price;
+    oracle.manual_price_expires_at = expires_at;
+    oracle.manual_price_reason     = params.reason.clone();
+    oracle.last_updated_at         = now;
+
+    emit!(ManualPriceSet {
+        mint:            oracle.mint,
+        feed_symbol:     oracle.feed_symbol.clone(),
+        price:           params.price,
+        reference_price: oracle.last_good_price,
+        deviation_bps,
+        expires_at,
+        reason:          params.reason,
+        by:              ctx.accounts.admin.key(),
+        timestamp:       now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClearManualPrice<'info> {
+    #[account(
+        constraint = admin.key() == oracle_config.admin @ OracleError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+}
+
+pub fn clear_manual_price(ctx: Context<ClearManualPrice>) -> Result<()> {
+    let oracle = &mut ctx.accounts.oracle_config;
+    let now    = Clock::get()?.unix_timestamp;
+
+    require!(oracle.manual_price_active(now), OracleError::NoManualPrice);
+
+    let price = oracle.manual_price;
+    oracle.manual_price            = 0;
+    oracle.manual_price_expires_at = 0;
+    oracle.manual_price_reason     = String::new();
+    oracle.last_updated_at         = now;
+
+    emit!(ManualPriceCleared {
+        mint:      oracle.mint,
+        price,
+        by:        ctx.accounts.admin.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Two-step role transfer
+//
+// Each `OracleRole` is handed over on its own: the role's current holder or
+// the admin proposes a new key, which then accepts by signing. Proposing
+// again replaces the pending key.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+#[instruction(role: OracleRole)]
 pub struct ProposeAuthorityTransfer<'info> {
     #[account(
-        constraint = authority.key() == oracle_config.authority @ OracleError::Unauthorized
+        constraint = authority.key() == oracle_config.admin
+            || authority.key() == oracle_config.role_holder(role) @ OracleError::Unauthorized
     )]
     pub authority: Signer<'info>,
 
@@ -178,25 +573,30 @@ pub struct ProposeAuthorityTransfer<'info> {
 
 pub fn propose_authority_transfer(
     ctx: Context<ProposeAuthorityTransfer>,
+    role: OracleRole,
     new_authority: Pubkey,
 ) -> Result<()> {
     let oracle = &mut ctx.accounts.oracle_config;
     let now    = Clock::get()?.unix_timestamp;
 
-    oracle.pending_authority = Some(new_authority);
-    oracle.last_updated_at   = now;
+    let current = oracle.role_holder(role);
+    *oracle.role_slots(role).1 = Some(new_authority);
+    oracle.last_updated_at     = now;
 
     emit!(AuthorityTransferProposed {
-        mint:      oracle.mint,
-        current:   ctx.accounts.authority.key(),
-        proposed:  new_authority,
-        timestamp: now,
+        mint:        oracle.mint,
+        role,
+        current,
+        proposed:    new_authority,
+        proposed_by: ctx.accounts.authority.key(),
+        timestamp:   now,
     });
 
     Ok(())
 }
 
 #[derive(Accounts)]
+#[instruction(role: OracleRole)]
 pub struct AcceptAuthorityTransfer<'info> {
     pub new_authority: Signer<'info>,
 
@@ -204,25 +604,30 @@ pub struct AcceptAuthorityTransfer<'info> {
         mut,
         seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
         bump = oracle_config.bump,
-        constraint = oracle_config.pending_authority == Some(new_authority.key())
+        constraint = oracle_config.pending_role_holder(role) == Some(new_authority.key())
             @ OracleError::NoPendingTransfer,
     )]
     pub oracle_config: Account<'info, OracleConfig>,
 }
 
-pub fn accept_authority_transfer(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
+pub fn accept_authority_transfer(
+    ctx: Context<AcceptAuthorityTransfer>,
+    role: OracleRole,
+) -> Result<()> {
     let oracle = &mut ctx.accounts.oracle_config;
     let now    = Clock::get()?.unix_timestamp;
 
-    let old_authority        = oracle.authority;
-    oracle.authority         = ctx.accounts.new_authority.key();
-    oracle.pending_authority = None;
-    oracle.last_updated_at   = now;
+    let new_holder        = ctx.accounts.new_authority.key();
+    let (holder, pending) = oracle.role_slots(role);
+    let old_holder        = core::mem::replace(holder, new_holder);
+    *pending              = None;
+    oracle.last_updated_at = now;
 
     emit!(AuthorityTransferred {
         mint:      oracle.mint,
-        from:      old_authority,
-        to:        ctx.accounts.new_authority.key(),
+        role,
+        from:      old_holder,
+        to:        new_holder,
         timestamp: now,
     });
 
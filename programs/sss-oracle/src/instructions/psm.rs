@@ -0,0 +1,715 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use sss_core::program::SssCore;
+use sss_core::state::{MinterConfig, StablecoinConfig};
+use crate::state::*;
+use crate::errors::OracleError;
+use crate::events::*;
+use crate::compliance::{check_participant, check_recipient};
+use crate::limits::enforce_caps;
+use crate::math::{pro_rata, quote_to_collateral, Rounding, MAX_AMOUNT_DECIMALS};
+
+// ═══════════════════════════════════════════════════════════════════════════
+// register_psm_collateral — Accept a collateral in the mint's PSM
+//
+// Creates the `PsmCollateral` entry and its vault, a token account of the
+// collateral owned by the oracle config PDA. The collateral is valued at par
+// in USD, so only USD stablecoins belong here.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct RegisterPsmCollateral<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = admin.key() == oracle_config.admin @ OracleError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump  = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    pub collateral_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PsmCollateral::LEN,
+        seeds = [b"sss-psm", oracle_config.mint.as_ref(), collateral_mint.key().as_ref()],
+        bump,
+    )]
+    pub psm_collateral: Box<Account<'info, PsmCollateral>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"sss-psm-vault", oracle_config.mint.as_ref(), collateral_mint.key().as_ref()],
+        bump,
+        token::mint = collateral_mint,
+        token::authority = oracle_config,
+        token::token_program = collateral_token_program,
+    )]
+    pub collateral_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub collateral_token_program: Interface<'info, TokenInterface>,
+    pub system_program:           Program<'info, System>,
+}
+
+pub fn register_psm_collateral(
+    ctx: Context<RegisterPsmCollateral>,
+    debt_ceiling_usd: u64,
+) -> Result<()> {
+    let collateral_mint = &ctx.accounts.collateral_mint;
+    require!(
+        collateral_mint.key() != ctx.accounts.oracle_config.mint,
+        OracleError::MintMismatch
+    );
+    // Collateral is valued at USD par
+    require!(
+        ctx.accounts.oracle_config.quote_denomination.is_usd(),
+        OracleError::PsmRequiresUsdQuote
+    );
+    require!(
+        collateral_mint.decimals <= MAX_AMOUNT_DECIMALS,
+        OracleError::UnsupportedDecimals
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let psm = &mut ctx.accounts.psm_collateral;
+    psm.mint                = ctx.accounts.oracle_config.mint;
+    psm.collateral_mint     = collateral_mint.key();
+    psm.collateral_decimals = collateral_mint.decimals;
+    psm.vault               = ctx.accounts.collateral_vault.key();
+    psm.debt_ceiling_usd    = debt_ceiling_usd;
+    psm.debt_usd            = 0;
+    psm.total_deposited     = 0;
+    psm.total_released      = 0;
+    psm.active              = true;
+    psm.created_at          = now;
+    psm.updated_at          = now;
+    psm.bump                = ctx.bumps.psm_collateral;
+
+    emit!(PsmCollateralRegistered {
+        mint:             psm.mint,
+        collateral_mint:  psm.collateral_mint,
+        vault:            psm.vault,
+        debt_ceiling_usd,
+        by:               ctx.accounts.admin.key(),
+        timestamp:        now,
+    });
+
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// update_psm_collateral — Change the debt ceiling or stop new mints
+//
+// A ceiling below the current debt only blocks further mints. Deactivating
+// stops new mints; holders can always redeem against the collateral held.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdatePsmCollateralParams {
+    /// USD, in the oracle's quote decimals
+    pub debt_ceiling_usd: Option<u64>,
+    pub active:           Option<bool>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePsmCollateral<'info> {
+    #[account(
+        constraint = admin.key() == oracle_config.admin @ OracleError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump  = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-psm", oracle_config.mint.as_ref(), psm_collateral.collateral_mint.as_ref()],
+        bump  = psm_collateral.bump,
+    )]
+    pub psm_collateral: Box<Account<'info, PsmCollateral>>,
+}
+
+pub fn update_psm_collateral(
+    ctx: Context<UpdatePsmCollateral>,
+    params: UpdatePsmCollateralParams,
+) -> Result<()> {
+    let psm = &mut ctx.accounts.psm_collateral;
+    let now = Clock::get()?.unix_timestamp;
+
+    if let Some(v) = params.debt_ceiling_usd { psm.debt_ceiling_usd = v; }
+    if let Some(v) = params.active           { psm.active           = v; }
+    psm.updated_at = now;
+
+    emit!(PsmCollateralUpdated {
+        mint:             psm.mint,
+        collateral_mint:  psm.collateral_mint,
+        debt_ceiling_usd: psm.debt_ceiling_usd,
+        active:           psm.active,
+        by:               ctx.accounts.admin.key(),
+        timestamp:        now,
+    });
+
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// mint_with_psm — Deposit collateral, mint SSS tokens at the oracle rate
+//
+// Consumes a mint PendingQuote from `get_mint_quote`. Its `input_amount`
+// (quote units) is paid in collateral at par, rounded up, into the vault; the
+// quote's tokens and fee are then minted exactly as in `mint_with_oracle`,
+// through sss-core with the oracle config PDA as minter.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct MintWithPsm<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump  = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sss-quote",
+            oracle_config.mint.as_ref(),
+            requester.key().as_ref(),
+            &quote.nonce.to_le_bytes(),
+        ],
+        bump  = quote.bump,
+        constraint = quote.requester == requester.key() @ OracleError::Unauthorized,
+        constraint = !quote.used @ OracleError::QuoteAlreadyUsed,
+        constraint = quote.direction == QuoteDirection::Mint @ OracleError::Unauthorized,
+        close = requester,
+    )]
+    pub quote: Box<Account<'info, PendingQuote>>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-psm", oracle_config.mint.as_ref(), collateral_mint.key().as_ref()],
+        bump  = psm_collateral.bump,
+        constraint = psm_collateral.active @ OracleError::PsmCollateralInactive,
+    )]
+    pub psm_collateral: Box<Account<'info, PsmCollateral>>,
+
+    pub collateral_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Requester's collateral account the deposit is taken from
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = requester,
+        token::token_program = collateral_token_program,
+    )]
+    pub collateral_source: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = psm_collateral.vault,
+    )]
+    pub collateral_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// sss-core stablecoin config for the oracle's mint
+    #[account(
+        mut,
+        seeds = [b"sss-config", mint.key().as_ref()],
+        seeds::program = sss_core::ID,
+        bump = core_config.bump,
+        has_one = mint,
+    )]
+    pub core_config: Box<Account<'info, StablecoinConfig>>,
+
+    /// sss-core minter entry registered for the oracle config PDA
+    #[account(
+        mut,
+        seeds = [b"sss-minter", mint.key().as_ref(), oracle_config.key().as_ref()],
+        seeds::program = sss_core::ID,
+        bump = minter_config.bump,
+    )]
+    pub minter_config: Box<Account<'info, MinterConfig>>,
+
+    /// CHECK: Must match oracle_config.mint — validated by Token-2022 in the sss-core CPI
+    #[account(
+        mut,
+        constraint = mint.key() == oracle_config.mint @ OracleError::MintMismatch,
+    )]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Recipient token account — validated by Token-2022 in the sss-core CPI
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// CHECK: Oracle fee vault PDA (created by `initialize_fee_vault`) — receives the mint fee
+    #[account(
+        mut,
+        seeds = [b"sss-fee-vault", mint.key().as_ref()],
+        bump,
+    )]
+    pub fee_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Requester's sss-core allowlist entry — checked in `check_participant`.
+    /// Required when the mint has an active allowlist or the oracle a `min_kyc_tier`
+    pub requester_allowlist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Requester's sss-core blacklist entry PDA (may be empty) — checked in
+    /// `check_participant`. Required when the mint has a blacklister
+    pub requester_blacklist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Recipient's sss-core allowlist entry — checked in `check_recipient`
+    pub recipient_allowlist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Recipient's sss-core blacklist entry PDA (may be empty) — checked in `check_recipient`
+    pub recipient_blacklist: Option<UncheckedAccount<'info>>,
+
+    /// Requester's rolling USD usage — required when the oracle sets a per-user mint cap
+    #[account(
+        mut,
+        seeds = [b"sss-usage", oracle_config.mint.as_ref(), requester.key().as_ref()],
+        bump  = user_usage.bump,
+    )]
+    pub user_usage: Option<Box<Account<'info, UserUsage>>>,
+
+    pub sss_core_program:         Program<'info, SssCore>,
+    pub token_program:            Program<'info, Token2022>,
+    pub collateral_token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn mint_with_psm<'info>(ctx: Context<'_, '_, '_, 'info, MintWithPsm<'info>>) -> Result<()> {
+    let quote = &ctx.accounts.quote;
+
+    require!(!ctx.accounts.oracle_config.paused, OracleError::OraclePaused);
+
+    // Check quote expiry
+    let now = Clock::get()?.unix_timestamp;
+    require!(!quote.is_expired(now), OracleError::QuoteExpired);
+
+    // Slippage validation against the stored min_output
+    require!(
+        quote.output_amount >= quote.min_output,
+        OracleError::SlippageExceeded
+    );
+
+    // sss-core compliance: requester and recipient
+    let min_kyc_tier = ctx.accounts.oracle_config.min_kyc_tier;
+    check_participant(
+        &ctx.accounts.core_config,
+        min_kyc_tier,
+        &QuoteDirection::Mint,
+        &ctx.accounts.requester.key(),
+        ctx.accounts.requester_allowlist.as_deref(),
+        ctx.accounts.requester_blacklist.as_deref(),
+    )?;
+    check_recipient(
+        &ctx.accounts.core_config,
+        min_kyc_tier,
+        &ctx.accounts.destination,
+        ctx.accounts.recipient_allowlist.as_deref(),
+        ctx.accounts.recipient_blacklist.as_deref(),
+    )?;
+
+    // Rolling USD caps
+    enforce_caps(
+        &mut ctx.accounts.oracle_config,
+        ctx.accounts.user_usage.as_deref_mut().map(|u| &mut **u),
+        ctx.accounts.requester.key(),
+        QuoteDirection::Mint,
+        quote.input_amount,
+        now,
+    )?;
+
+    // ── 1. Debt ceiling ──
+    let psm = &mut ctx.accounts.psm_collateral;
+    let debt_usd = psm
+        .debt_usd
+        .checked_add(quote.input_amount)
+        .ok_or(OracleError::MathOverflow)?;
+    require!(debt_usd <= psm.debt_ceiling_usd, OracleError::DebtCeilingExceeded);
+
+    // ── 2. Take the collateral, rounded up ──
+    let collateral_amount = quote_to_collateral(
+        quote.input_amount,
+        ctx.accounts.oracle_config.quote_denomination.decimals,
+        psm.collateral_decimals,
+        Rounding::Up,
+    )?;
+    require!(collateral_amount > 0, OracleError::ZeroAmount);
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.collateral_token_program.to_account_info(),
+            TransferChecked {
+                from:      ctx.accounts.collateral_source.to_account_info(),
+                mint:      ctx.accounts.collateral_mint.to_account_info(),
+                to:        ctx.accounts.collateral_vault.to_account_info(),
+                authority: ctx.accounts.requester.to_account_info(),
+            },
+        ),
+        collateral_amount,
+        ctx.accounts.collateral_mint.decimals,
+    )?;
+
+    psm.debt_usd        = debt_usd;
+    psm.total_deposited = psm
+        .total_deposited
+        .checked_add(collateral_amount)
+        .ok_or(OracleError::MathOverflow)?;
+    psm.updated_at      = now;
+
+    // ── 3. Update oracle lifetime stats ──
+    let oracle = &mut ctx.accounts.oracle_config;
+    oracle.total_minted_usd = oracle
+        .total_minted_usd
+        .checked_add(quote.input_amount)
+        .ok_or(OracleError::MathOverflow)?;
+    oracle.total_fees_collected = oracle
+        .total_fees_collected
+        .checked_add(quote.fee_amount)
+        .ok_or(OracleError::MathOverflow)?;
+    oracle.last_updated_at = now;
+
+    // ── 4. CPI to sss-core::mint, signed by the oracle config PDA ──
+    let mint_key = oracle.mint;
+    let bump     = oracle.bump;
+    let seeds    = &[b"sss-oracle".as_ref(), mint_key.as_ref(), &[bump]];
+    let signer   = &[&seeds[..]];
+
+    let mint_to = |destination: AccountInfo<'info>, amount: u64| -> Result<()> {
+        let cpi_accounts = sss_core::cpi::accounts::MintTokens {
+            minter:        ctx.accounts.oracle_config.to_account_info(),
+            config:        ctx.accounts.core_config.to_account_info(),
+            minter_config: ctx.accounts.minter_config.to_account_info(),
+            mint:          ctx.accounts.mint.to_account_info(),
+            destination,
+            token_program: ctx.accounts.token_program.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.sss_core_program.to_account_info();
+        let cpi_ctx     = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+        sss_core::cpi::mint(cpi_ctx, amount)
+    };
+
+    mint_to(ctx.accounts.destination.to_account_info(), quote.output_amount)?;
+    if quote.fee_amount > 0 {
+        mint_to(ctx.accounts.fee_vault.to_account_info(), quote.fee_amount)?;
+    }
+
+    emit!(PsmMint {
+        mint:              mint_key,
+        collateral_mint:   ctx.accounts.collateral_mint.key(),
+        recipient:         ctx.accounts.destination.key(),
+        collateral_amount,
+        usd_amount:        quote.input_amount,
+        token_amount:      quote.output_amount,
+        fee_amount:        quote.fee_amount,
+        price_used:        quote.price_snapshot,
+        debt_usd,
+        timestamp:         now,
+    });
+
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// redeem_with_psm — Burn SSS tokens, release collateral at the oracle rate
+//
+// Consumes a redeem PendingQuote from `get_redeem_quote`. The tokens are
+// handled as in `redeem_with_oracle` — the fee's token share to the fee
+// vault, the rest escrowed and burned through sss-core — except the burn is
+// mandatory: the oracle config PDA must be the sss-core burner. The quote's
+// net `output_amount` (quote units) is paid out of the vault in collateral at
+// par, rounded down.
+//
+// Transfer-hook extra accounts for the SSS mint are passed through
+// `remaining_accounts`.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct RedeemWithPsm<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump  = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sss-quote",
+            oracle_config.mint.as_ref(),
+            requester.key().as_ref(),
+            &quote.nonce.to_le_bytes(),
+        ],
+        bump  = quote.bump,
+        constraint = quote.requester == requester.key() @ OracleError::Unauthorized,
+        constraint = !quote.used @ OracleError::QuoteAlreadyUsed,
+        constraint = quote.direction == QuoteDirection::Redeem @ OracleError::Unauthorized,
+        close = requester,
+    )]
+    pub quote: Box<Account<'info, PendingQuote>>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-psm", oracle_config.mint.as_ref(), collateral_mint.key().as_ref()],
+        bump  = psm_collateral.bump,
+    )]
+    pub psm_collateral: Box<Account<'info, PsmCollateral>>,
+
+    pub collateral_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        address = psm_collateral.vault,
+    )]
+    pub collateral_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Collateral account the released collateral is paid to
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::token_program = collateral_token_program,
+    )]
+    pub collateral_destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// sss-core stablecoin config for the oracle's mint
+    #[account(
+        mut,
+        seeds = [b"sss-config", mint.key().as_ref()],
+        seeds::program = sss_core::ID,
+        bump = core_config.bump,
+        has_one = mint,
+    )]
+    pub core_config: Box<Account<'info, StablecoinConfig>>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == oracle_config.mint @ OracleError::MintMismatch,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Requester's token account the redeemed tokens are taken from
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = requester,
+        token::token_program = token_program,
+    )]
+    pub source: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Oracle escrow — associated token account of the oracle config PDA
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = oracle_config,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Oracle fee vault — receives the token share of the redeem fee
+    #[account(
+        mut,
+        seeds = [b"sss-fee-vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Requester's sss-core allowlist entry — checked in `check_participant`.
+    /// Required when the mint has an active allowlist or the oracle a `min_kyc_tier`
+    pub requester_allowlist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Requester's sss-core blacklist entry PDA (may be empty) — checked in
+    /// `check_participant`. Required when the mint has a blacklister
+    pub requester_blacklist: Option<UncheckedAccount<'info>>,
+
+    /// Requester's rolling USD usage — required when the oracle sets a per-user redeem cap
+    #[account(
+        mut,
+        seeds = [b"sss-usage", oracle_config.mint.as_ref(), requester.key().as_ref()],
+        bump  = user_usage.bump,
+    )]
+    pub user_usage: Option<Box<Account<'info, UserUsage>>>,
+
+    pub sss_core_program:         Program<'info, SssCore>,
+    pub token_program:            Program<'info, Token2022>,
+    pub collateral_token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn redeem_with_psm<'info>(ctx: Context<'_, '_, '_, 'info, RedeemWithPsm<'info>>) -> Result<()> {
+    let quote = &ctx.accounts.quote;
+
+    require!(!ctx.accounts.oracle_config.paused, OracleError::OraclePaused);
+    require!(
+        ctx.accounts.core_config.burner == ctx.accounts.oracle_config.key(),
+        OracleError::PsmRequiresBurner
+    );
+
+    // Check quote expiry
+    let now = Clock::get()?.unix_timestamp;
+    require!(!quote.is_expired(now), OracleError::QuoteExpired);
+
+    // Slippage validation against the stored min_output
+    require!(
+        quote.output_amount >= quote.min_output,
+        OracleError::SlippageExceeded
+    );
+
+    // sss-core compliance: the requester sends the tokens
+    check_participant(
+        &ctx.accounts.core_config,
+        ctx.accounts.oracle_config.min_kyc_tier,
+        &QuoteDirection::Redeem,
+        &ctx.accounts.requester.key(),
+        ctx.accounts.requester_allowlist.as_deref(),
+        ctx.accounts.requester_blacklist.as_deref(),
+    )?;
+
+    let token_amount = quote.input_amount;
+    let gross_usd    = quote
+        .output_amount
+        .checked_add(quote.fee_amount)
+        .ok_or(OracleError::MathOverflow)?;
+
+    // Rolling USD caps
+    enforce_caps(
+        &mut ctx.accounts.oracle_config,
+        ctx.accounts.user_usage.as_deref_mut().map(|u| &mut **u),
+        ctx.accounts.requester.key(),
+        QuoteDirection::Redeem,
+        gross_usd,
+        now,
+    )?;
+
+    // Token share of the USD fee, rounded up like every fee
+    let fee_tokens  = pro_rata(token_amount, quote.fee_amount, gross_usd, Rounding::Up)?;
+    let burn_amount = token_amount.checked_sub(fee_tokens).ok_or(OracleError::MathOverflow)?;
+
+    // The fee's share stays outstanding, still backed by the vault
+    let psm      = &ctx.accounts.psm_collateral;
+    let debt_usd = psm
+        .debt_usd
+        .checked_sub(quote.output_amount)
+        .ok_or(OracleError::InsufficientPsmCollateral)?;
+    let collateral_amount = quote_to_collateral(
+        quote.output_amount,
+        ctx.accounts.oracle_config.quote_denomination.decimals,
+        psm.collateral_decimals,
+        Rounding::Down,
+    )?;
+
+    let mint_key = ctx.accounts.oracle_config.mint;
+    let bump     = ctx.accounts.oracle_config.bump;
+    let seeds    = &[b"sss-oracle".as_ref(), mint_key.as_ref(), &[bump]];
+    let signer   = &[&seeds[..]];
+
+    // ── 1. Escrow the requester's tokens, fee share to the fee vault ──
+    let transfer_in = |destination: AccountInfo<'info>, amount: u64| -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        invoke_transfer_checked(
+            &ctx.accounts.token_program.key(),
+            ctx.accounts.source.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            destination,
+            ctx.accounts.requester.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.mint.decimals,
+            &[],
+        )?;
+        Ok(())
+    };
+    transfer_in(ctx.accounts.escrow.to_account_info(), burn_amount)?;
+    transfer_in(ctx.accounts.fee_vault.to_account_info(), fee_tokens)?;
+
+    // ── 2. Burn from escrow through sss-core ──
+    if burn_amount > 0 {
+        let cpi_accounts = sss_core::cpi::accounts::BurnTokens {
+            burner:        ctx.accounts.oracle_config.to_account_info(),
+            config:        ctx.accounts.core_config.to_account_info(),
+            source:        ctx.accounts.escrow.to_account_info(),
+            mint:          ctx.accounts.mint.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.sss_core_program.to_account_info();
+        let cpi_ctx     = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+        sss_core::cpi::burn(cpi_ctx, burn_amount)?;
+    }
+
+    // ── 3. Release the collateral, signed by the oracle config PDA ──
+    if collateral_amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.collateral_token_program.to_account_info(),
+                TransferChecked {
+                    from:      ctx.accounts.collateral_vault.to_account_info(),
+                    mint:      ctx.accounts.collateral_mint.to_account_info(),
+                    to:        ctx.accounts.collateral_destination.to_account_info(),
+                    authority: ctx.accounts.oracle_config.to_account_info(),
+                },
+                signer,
+            ),
+            collateral_amount,
+            ctx.accounts.collateral_mint.decimals,
+        )?;
+    }
+
+    // ── 4. Update PSM accounting and oracle lifetime stats ──
+    let psm = &mut ctx.accounts.psm_collateral;
+    psm.debt_usd       = debt_usd;
+    psm.total_released = psm
+        .total_released
+        .checked_add(collateral_amount)
+        .ok_or(OracleError::MathOverflow)?;
+    psm.updated_at     = now;
+
+    let oracle = &mut ctx.accounts.oracle_config;
+    oracle.total_redeemed_usd = oracle
+        .total_redeemed_usd
+        .checked_add(gross_usd)
+        .ok_or(OracleError::MathOverflow)?;
+    oracle.total_fees_collected = oracle
+        .total_fees_collected
+        .checked_add(fee_tokens)
+        .ok_or(OracleError::MathOverflow)?;
+    oracle.last_updated_at = now;
+
+    emit!(PsmRedeem {
+        mint:              mint_key,
+        collateral_mint:   ctx.accounts.collateral_mint.key(),
+        redeemer:          ctx.accounts.requester.key(),
+        token_amount,
+        fee_tokens,
+        usd_amount:        quote.output_amount,
+        collateral_amount,
+        price_used:        quote.price_snapshot,
+        debt_usd,
+        timestamp:         now,
+    });
+
+    Ok(())
+}
@@ -3,15 +3,269 @@ use crate::state::*;
 use crate::errors::OracleError;
 use crate::events::*;
 use crate::math::*;
-use crate::switchboard::read_switchboard_price;
+use crate::aggregation::{aggregate_feed_price, feed_account_span};
+use crate::compliance::check_participant;
+use sss_core::state::StablecoinConfig;
 
 // ═══════════════════════════════════════════════════════════════════════════
-// get_mint_quote — USD → tokens
+// Circuit breaker — shared by both quote directions
+//
+// A reading outside the band pauses the oracle instead of quoting. The quote
+// instruction still succeeds (an error would roll the pause back): the quote
+// account is closed again and a `tripped` result is returned.
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// `(move_bps, allowed_bps)` when `price_scaled` is outside the breaker band,
+/// without changing anything.
+fn breaker_move(oracle: &OracleConfig, price_scaled: u64, now: i64) -> Result<Option<(u64, u64)>> {
+    if oracle.max_price_move_bps == 0 || oracle.last_good_price == 0 {
+        return Ok(None);
+    }
+    let move_bps    = price_move_bps(oracle.last_good_price, price_scaled)?;
+    let allowed_bps = allowed_move_bps(
+        oracle.max_price_move_bps,
+        oracle.price_move_window_secs,
+        now.saturating_sub(oracle.last_good_price_at),
+    )?;
+    Ok((move_bps > allowed_bps).then_some((move_bps, allowed_bps)))
+}
+
+/// Check `price_scaled` against the last good price. Returns `true` when the
+/// breaker tripped; otherwise records the price as the new last good price.
+fn check_circuit_breaker(oracle: &mut OracleConfig, price_scaled: u64, now: i64) -> Result<bool> {
+    if let Some((move_bps, allowed_bps)) = breaker_move(oracle, price_scaled, now)? {
+        oracle.paused          = true;
+        oracle.pause_code      = PauseReason::CircuitBreaker;
+        oracle.pause_reason    = format!("circuit breaker: {} bps move", move_bps);
+        oracle.tripped_price   = price_scaled;
+        oracle.last_updated_at = now;
+
+        emit!(CircuitBreakerTripped {
+            mint:            oracle.mint,
+            feed_symbol:     oracle.feed_symbol.clone(),
+            last_good_price: oracle.last_good_price,
+            last_good_at:    oracle.last_good_price_at,
+            new_price:       price_scaled,
+            move_bps,
+            allowed_bps,
+            timestamp:       now,
+        });
+        return Ok(true);
+    }
+
+    oracle.last_good_price    = price_scaled;
+    oracle.last_good_price_at = now;
+    Ok(false)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Pricing mode — spot median or the feed's accumulator
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Price a quote uses under `oracle.pricing_mode`. `spot` is the current
+/// median; accumulator prices must have been cranked within
+/// `max_staleness_secs`.
+fn resolve_price(
+    oracle:      &OracleConfig,
+    accumulator: Option<&PriceAccumulator>,
+    spot:        u64,
+    rounding:    Rounding,
+    now:         i64,
+) -> Result<u64> {
+    if oracle.pricing_mode == PricingMode::Spot {
+        return Ok(spot);
+    }
+
+    let acc = accumulator.ok_or(OracleError::AccumulatorRequired)?;
+    require!(acc.observation_count > 0, OracleError::AccumulatorNotReady);
+    require!(
+        now.saturating_sub(acc.last_update) <= oracle.max_staleness_secs,
+        OracleError::AccumulatorStale
+    );
+
+    let twap = || -> Result<u64> {
+        let start = acc.window_start(now).ok_or(OracleError::AccumulatorNotReady)?;
+        let cum   = acc.cumulative_at(now).ok_or(OracleError::MathOverflow)?;
+        twap_from_cumulative(cum, start.cumulative_price, now - start.timestamp, rounding)
+    };
+
+    let price = match oracle.pricing_mode {
+        PricingMode::Spot         => spot,
+        PricingMode::Twap         => twap()?,
+        PricingMode::Ema          => acc.ema_price,
+        PricingMode::Conservative => conservative_price(spot, twap()?, rounding),
+    };
+    require!(price > 0, OracleError::InvalidPrice);
+    Ok(price)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Quote price — manual override, or spot median + breaker + pricing mode
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Feed price for one quote, with what it was derived from.
+pub(crate) struct QuotePrice {
+    /// Median spot price (the manual price under an override)
+    pub spot:            u64,
+    /// Price the quote uses under the oracle's pricing mode
+    pub price:           u64,
+    pub sources_used:    Vec<Pubkey>,
+    pub reports:         Vec<SourceReport>,
+    pub manual_override: bool,
+}
+
+pub(crate) enum PriceOutcome {
+    Priced(QuotePrice),
+    /// The circuit breaker tripped on this spot price and paused the oracle
+    Tripped(u64),
+}
+
+/// Price `feed` for a quote against `oracle`.
+///
+/// While a manual override is active the sources are not read at all, since
+/// it exists for when they are down. It was bounded against the last good
+/// price when set, so neither the breaker nor the pricing mode applies.
+/// Otherwise the median of the fresh sources, rounded with `rounding`, goes
+/// through the circuit breaker and then the pricing mode.
+pub(crate) fn quote_price(
+    oracle:          &mut OracleConfig,
+    feed:            &FeedEntry,
+    source_accounts: &[AccountInfo],
+    accumulator:     Option<&PriceAccumulator>,
+    rounding:        Rounding,
+    now:             i64,
+) -> Result<PriceOutcome> {
+    if oracle.manual_price_active(now) {
+        return Ok(PriceOutcome::Priced(QuotePrice {
+            spot:            oracle.manual_price,
+            price:           oracle.manual_price,
+            sources_used:    Vec::new(),
+            reports:         Vec::new(),
+            manual_override: true,
+        }));
+    }
+
+    // Staleness and confidence are checked per source
+    let agg = aggregate_feed_price(
+        feed,
+        source_accounts,
+        rounding,
+        oracle.max_staleness_secs,
+        oracle.max_confidence_bps,
+    )?;
+    let spot = agg.price_scaled;
+
+    // The circuit breaker always watches the spot median
+    if check_circuit_breaker(oracle, spot, now)? {
+        return Ok(PriceOutcome::Tripped(spot));
+    }
+
+    let price = resolve_price(oracle, accumulator, spot, rounding, now)?;
+    Ok(PriceOutcome::Priced(QuotePrice {
+        spot,
+        price,
+        sources_used:    agg.sources_used,
+        reports:         agg.reports,
+        manual_override: false,
+    }))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Peg fee curve — mint/redeem fee from the token's market deviation
+//
+// With `peg_fee_curve` set, the fee is read off the curve at how far the
+// curve's market feed (quote currency per whole token on the secondary
+// market) sits from the oracle's fair value at the quote's price. The market
+// feed is a named account; its sources follow the oracle feed's in
+// `remaining_accounts`, each laid out as for a single quote.
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Split `accounts` into the oracle feed's and the market feed's.
+pub(crate) fn split_market_accounts<'a, 'info>(
+    oracle:   &OracleConfig,
+    feed:     &FeedEntry,
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<(&'a [AccountInfo<'info>], &'a [AccountInfo<'info>])> {
+    if !oracle.peg_fee_curve.enabled() {
+        return Ok((accounts, &[]));
+    }
+    let span = feed_account_span(feed, accounts)?;
+    require!(accounts.len() >= span, OracleError::FeedMismatch);
+    Ok(accounts.split_at(span))
+}
+
+/// Fee in bps a quote in `direction` charges, with the market deviation it
+/// was read at (`None` on the flat fee). `fair` is the token's value at the
+/// quote's price.
+///
+/// # Errors
+/// Returns `OracleError::MarketFeedRequired` – curve set but no market feed passed
+/// Returns `OracleError::FeedMismatch`       – not the curve's market feed PDA
+/// Returns `OracleError::FeedInactive`       – the market feed was deactivated
+/// Plus any error from pricing the market feed's sources.
+pub(crate) fn quote_fee_bps(
+    oracle:          &OracleConfig,
+    direction:       &QuoteDirection,
+    fair:            &SwapLeg,
+    market_feed:     Option<&Account<FeedEntry>>,
+    market_accounts: &[AccountInfo],
+) -> Result<(u16, Option<i64>)> {
+    let curve = &oracle.peg_fee_curve;
+    if !curve.enabled() {
+        let flat = match direction {
+            QuoteDirection::Mint   => oracle.mint_fee_bps,
+            QuoteDirection::Redeem => oracle.redeem_fee_bps,
+        };
+        return Ok((flat, None));
+    }
+
+    let market_feed = market_feed.ok_or(OracleError::MarketFeedRequired)?;
+    let expected = Pubkey::create_program_address(
+        &[b"sss-feed", curve.market_feed_symbol.as_bytes(), &[market_feed.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| error!(OracleError::FeedMismatch))?;
+    require!(
+        market_feed.symbol == curve.market_feed_symbol && market_feed.key() == expected,
+        OracleError::FeedMismatch
+    );
+    require!(market_feed.active, OracleError::FeedInactive);
+
+    let market = aggregate_feed_price(
+        market_feed,
+        market_accounts,
+        Rounding::Down,
+        oracle.max_staleness_secs,
+        oracle.max_confidence_bps,
+    )?;
+    let deviation_bps = peg_deviation_bps(market.price_scaled, market_feed.decimals, fair)?;
+    Ok((curve.fee_bps_at(direction, deviation_bps)?, Some(deviation_bps)))
+}
+
+pub(crate) fn tripped_result(price_scaled: u64, now: i64) -> QuoteResult {
+    QuoteResult {
+        output_amount: 0,
+        fee_amount:    0,
+        price_used:    price_scaled,
+        valid_until:   now,
+        quote_account: Pubkey::default(),
+        tripped:       true,
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// get_mint_quote — quote units → tokens
+//
+// The feed's source accounts are passed as `remaining_accounts`, exactly
+// matching `FeedEntry.sources` in order. The quote prices off their median.
+// For a composite feed, pass each leg's feed PDA followed by that leg's
+// sources, in leg order.
 // ═══════════════════════════════════════════════════════════════════════════
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct GetQuoteParams {
-    /// Input amount: USD cents for mint, token base units for redeem
+    /// Input amount: quote units (`OracleConfig.quote_denomination`) for mint,
+    /// token base units for redeem
     pub input_amount: u64,
     /// Minimum acceptable output (slippage floor)
     pub min_output:   u64,
@@ -26,19 +280,28 @@ pub struct GetMintQuote<'info> {
     pub requester: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
         bump  = oracle_config.bump,
     )]
     pub oracle_config: Account<'info, OracleConfig>,
 
     #[account(
-        seeds = [b"sss-feed-registry"],
-        bump  = registry.bump,
+        seeds = [b"sss-feed", oracle_config.feed_symbol.as_bytes()],
+        bump  = feed.bump,
+        constraint = feed.active @ OracleError::FeedInactive,
+    )]
+    pub feed: Box<Account<'info, FeedEntry>>,
+
+    /// Required unless `oracle_config.pricing_mode` is `Spot`
+    #[account(
+        seeds = [b"sss-accumulator", oracle_config.feed_symbol.as_bytes()],
+        bump  = accumulator.bump,
     )]
-    pub registry: Account<'info, FeedRegistry>,
+    pub accumulator: Option<Box<Account<'info, PriceAccumulator>>>,
 
-    /// CHECK: The Switchboard aggregator for this feed — validated inside instruction
-    pub switchboard_feed: UncheckedAccount<'info>,
+    /// The peg fee curve's market feed — required when `oracle_config.peg_fee_curve` is set
+    pub market_feed: Option<Box<Account<'info, FeedEntry>>>,
 
     #[account(
         init,
@@ -54,87 +317,140 @@ pub struct GetMintQuote<'info> {
     )]
     pub quote: Account<'info, PendingQuote>,
 
+    /// sss-core stablecoin config for the oracle's mint
+    #[account(
+        seeds = [b"sss-config", oracle_config.mint.as_ref()],
+        seeds::program = sss_core::ID,
+        bump = core_config.bump,
+    )]
+    pub core_config: Box<Account<'info, StablecoinConfig>>,
+
+    /// CHECK: Requester's sss-core allowlist entry — checked in `check_participant`.
+    /// Required when the mint has an active allowlist or the oracle a `min_kyc_tier`
+    pub requester_allowlist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Requester's sss-core blacklist entry PDA (may be empty) — checked in
+    /// `check_participant`. Required when the mint has a blacklister
+    pub requester_blacklist: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn get_mint_quote(ctx: Context<GetMintQuote>, params: GetQuoteParams) -> Result<QuoteResult> {
-    let oracle   = &ctx.accounts.oracle_config;
-    let registry = &ctx.accounts.registry;
+    let oracle = &ctx.accounts.oracle_config;
+    let feed   = &ctx.accounts.feed;
 
     require!(!oracle.paused, OracleError::OraclePaused);
     require!(params.input_amount > 0, OracleError::ZeroAmount);
 
-    // Get feed entry from registry
-    let feed = registry
-        .find_feed(&oracle.feed_symbol)
-        .ok_or(OracleError::FeedNotFound)?;
-
-    // Verify caller passed the correct switchboard_feed account
-    require!(
-        ctx.accounts.switchboard_feed.key() == feed.switchboard_feed,
-        OracleError::FeedMismatch
-    );
-
-    // Read Switchboard price via raw deserialization
-    let feed_data = ctx.accounts.switchboard_feed.try_borrow_data()?;
-    let sb_price  = read_switchboard_price(&feed_data)?;
-
-    // Validate
-    require!(sb_price.value > 0.0, OracleError::InvalidPrice);
-    validate_staleness(sb_price.round_open_timestamp, oracle.max_staleness_secs)?;
+    // sss-core compliance: the requester (the recipient is checked on execution)
+    check_participant(
+        &ctx.accounts.core_config,
+        oracle.min_kyc_tier,
+        &QuoteDirection::Mint,
+        &ctx.accounts.requester.key(),
+        ctx.accounts.requester_allowlist.as_deref(),
+        ctx.accounts.requester_blacklist.as_deref(),
+    )?;
 
-    let price_scaled = (sb_price.value * PRICE_SCALE as f64) as u64;
-    let conf_scaled  = (sb_price.std_deviation.abs() * PRICE_SCALE as f64) as u64;
+    let rounding = price_rounding(&feed.feed_type, &QuoteDirection::Mint);
+    let now      = Clock::get()?.unix_timestamp;
+    let (feed_accounts, market_accounts) =
+        split_market_accounts(&ctx.accounts.oracle_config, feed, ctx.remaining_accounts)?;
+    let quoted = quote_price(
+        &mut ctx.accounts.oracle_config,
+        feed,
+        feed_accounts,
+        ctx.accounts.accumulator.as_deref().map(|a| &**a),
+        rounding,
+        now,
+    )?;
+    let QuotePrice { spot: spot_price, price: price_scaled, sources_used, reports, manual_override } =
+        match quoted {
+            PriceOutcome::Priced(p)     => p,
+            PriceOutcome::Tripped(spot) => {
+                ctx.accounts.quote.close(ctx.accounts.requester.to_account_info())?;
+                return Ok(tripped_result(spot, now));
+            }
+        };
+    let oracle = &ctx.accounts.oracle_config;
 
-    validate_confidence(price_scaled, conf_scaled, oracle.max_confidence_bps)?;
+    // A higher multiplier mints fewer tokens
+    let fair = SwapLeg {
+        price_scaled,
+        feed_type:      &feed.feed_type,
+        cpi_multiplier: oracle.cpi_multiplier_at(now, Rounding::Up)?,
+        token_decimals: oracle.token_decimals,
+        price_decimals: feed.decimals,
+    };
 
     // Calculate gross token amount
-    let gross_tokens = calc_token_amount_for_usd(
+    let gross_tokens = calc_token_amount_for_quote(
         params.input_amount,
         price_scaled,
         &feed.feed_type,
-        oracle.cpi_multiplier,
+        fair.cpi_multiplier,
+        oracle.token_decimals,
+        feed.decimals,
+        oracle.quote_denomination.decimals,
     )?;
 
     require!(gross_tokens > 0, OracleError::ZeroOutput);
 
-    // Deduct mint fee
-    let (net_tokens, fee_tokens) = apply_fee(gross_tokens, oracle.mint_fee_bps)?;
+    // Deduct mint fee, off the peg fee curve when set
+    let (fee_bps, deviation_bps) = quote_fee_bps(
+        oracle,
+        &QuoteDirection::Mint,
+        &fair,
+        ctx.accounts.market_feed.as_deref(),
+        market_accounts,
+    )?;
+    let (net_tokens, fee_tokens) = apply_fee(gross_tokens, fee_bps)?;
 
     // Slippage check
     check_slippage(net_tokens, params.min_output)?;
 
-    let now         = Clock::get()?.unix_timestamp;
     let valid_until = now + oracle.quote_validity_secs;
 
     // Store the quote on-chain
     let quote = &mut ctx.accounts.quote;
-    quote.mint           = oracle.mint;
-    quote.requester      = ctx.accounts.requester.key();
-    quote.direction      = QuoteDirection::Mint;
-    quote.feed_symbol    = oracle.feed_symbol.clone();
-    quote.input_amount   = params.input_amount;
-    quote.output_amount  = net_tokens;
-    quote.fee_amount     = fee_tokens;
-    quote.price_snapshot = price_scaled;
-    quote.valid_until    = valid_until;
-    quote.min_output     = params.min_output;
-    quote.used           = false;
-    quote.created_at     = now;
-    quote.nonce          = params.nonce;
-    quote.bump           = ctx.bumps.quote;
+    quote.mint            = oracle.mint;
+    quote.requester       = ctx.accounts.requester.key();
+    quote.direction       = QuoteDirection::Mint;
+    quote.feed_symbol     = oracle.feed_symbol.clone();
+    quote.input_amount    = params.input_amount;
+    quote.output_amount   = net_tokens;
+    quote.fee_amount      = fee_tokens;
+    quote.price_snapshot  = price_scaled;
+    quote.price_decimals  = feed.decimals;
+    quote.pricing_mode    = oracle.pricing_mode;
+    quote.sources_used    = sources_used;
+    quote.manual_override = manual_override;
+    quote.valid_until     = valid_until;
+    quote.min_output      = params.min_output;
+    quote.used            = false;
+    quote.created_at      = now;
+    quote.nonce           = params.nonce;
+    quote.bump            = ctx.bumps.quote;
 
     emit!(QuoteGenerated {
-        quote_id:      ctx.accounts.quote.key(),
-        mint:          oracle.mint,
-        feed_symbol:   oracle.feed_symbol.clone(),
-        direction:     "Mint".to_string(),
-        input_amount:  params.input_amount,
-        output_amount: net_tokens,
-        fee_amount:    fee_tokens,
-        price_used:    price_scaled,
+        quote_id:       ctx.accounts.quote.key(),
+        mint:           oracle.mint,
+        feed_symbol:    oracle.feed_symbol.clone(),
+        direction:      "Mint".to_string(),
+        input_amount:   params.input_amount,
+        output_amount:  net_tokens,
+        fee_amount:     fee_tokens,
+        fee_bps,
+        deviation_bps,
+        price_used:     price_scaled,
+        price_decimals: feed.decimals,
+        pricing_mode:   oracle.pricing_mode,
+        spot_price,
+        sources:        reports,
+        manual_override,
         valid_until,
-        timestamp:     now,
+        timestamp:      now,
     });
 
     Ok(QuoteResult {
@@ -143,11 +459,12 @@ pub fn get_mint_quote(ctx: Context<GetMintQuote>, params: GetQuoteParams) -> Res
         price_used:    price_scaled,
         valid_until,
         quote_account: ctx.accounts.quote.key(),
+        tripped:       false,
     })
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
-// get_redeem_quote — tokens → USD
+// get_redeem_quote — tokens → quote units
 // ═══════════════════════════════════════════════════════════════════════════
 
 #[derive(Accounts)]
@@ -157,19 +474,28 @@ pub struct GetRedeemQuote<'info> {
     pub requester: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
         bump  = oracle_config.bump,
     )]
     pub oracle_config: Account<'info, OracleConfig>,
 
     #[account(
-        seeds = [b"sss-feed-registry"],
-        bump  = registry.bump,
+        seeds = [b"sss-feed", oracle_config.feed_symbol.as_bytes()],
+        bump  = feed.bump,
+        constraint = feed.active @ OracleError::FeedInactive,
     )]
-    pub registry: Account<'info, FeedRegistry>,
+    pub feed: Box<Account<'info, FeedEntry>>,
 
-    /// CHECK: Switchboard aggregator — validated inside instruction
-    pub switchboard_feed: UncheckedAccount<'info>,
+    /// Required unless `oracle_config.pricing_mode` is `Spot`
+    #[account(
+        seeds = [b"sss-accumulator", oracle_config.feed_symbol.as_bytes()],
+        bump  = accumulator.bump,
+    )]
+    pub accumulator: Option<Box<Account<'info, PriceAccumulator>>>,
+
+    /// The peg fee curve's market feed — required when `oracle_config.peg_fee_curve` is set
+    pub market_feed: Option<Box<Account<'info, FeedEntry>>>,
 
     #[account(
         init,
@@ -185,6 +511,22 @@ pub struct GetRedeemQuote<'info> {
     )]
     pub quote: Account<'info, PendingQuote>,
 
+    /// sss-core stablecoin config for the oracle's mint
+    #[account(
+        seeds = [b"sss-config", oracle_config.mint.as_ref()],
+        seeds::program = sss_core::ID,
+        bump = core_config.bump,
+    )]
+    pub core_config: Box<Account<'info, StablecoinConfig>>,
+
+    /// CHECK: Requester's sss-core allowlist entry — checked in `check_participant`.
+    /// Required when the mint has an active allowlist or the oracle a `min_kyc_tier`
+    pub requester_allowlist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Requester's sss-core blacklist entry PDA (may be empty) — checked in
+    /// `check_participant`. Required when the mint has a blacklister
+    pub requester_blacklist: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -192,84 +534,547 @@ pub fn get_redeem_quote(
     ctx: Context<GetRedeemQuote>,
     params: GetQuoteParams,
 ) -> Result<QuoteResult> {
-    let oracle   = &ctx.accounts.oracle_config;
-    let registry = &ctx.accounts.registry;
+    let oracle = &ctx.accounts.oracle_config;
+    let feed   = &ctx.accounts.feed;
 
     require!(!oracle.paused, OracleError::OraclePaused);
     require!(params.input_amount > 0, OracleError::ZeroAmount);
 
-    let feed = registry
-        .find_feed(&oracle.feed_symbol)
-        .ok_or(OracleError::FeedNotFound)?;
-
-    require!(
-        ctx.accounts.switchboard_feed.key() == feed.switchboard_feed,
-        OracleError::FeedMismatch
-    );
-
-    // Read price
-    let feed_data = ctx.accounts.switchboard_feed.try_borrow_data()?;
-    let sb_price  = read_switchboard_price(&feed_data)?;
-
-    require!(sb_price.value > 0.0, OracleError::InvalidPrice);
-    validate_staleness(sb_price.round_open_timestamp, oracle.max_staleness_secs)?;
+    // sss-core compliance: the requester sends the tokens
+    check_participant(
+        &ctx.accounts.core_config,
+        oracle.min_kyc_tier,
+        &QuoteDirection::Redeem,
+        &ctx.accounts.requester.key(),
+        ctx.accounts.requester_allowlist.as_deref(),
+        ctx.accounts.requester_blacklist.as_deref(),
+    )?;
 
-    let price_scaled = (sb_price.value * PRICE_SCALE as f64) as u64;
-    let conf_scaled  = (sb_price.std_deviation.abs() * PRICE_SCALE as f64) as u64;
+    let rounding = price_rounding(&feed.feed_type, &QuoteDirection::Redeem);
+    let now      = Clock::get()?.unix_timestamp;
+    let (feed_accounts, market_accounts) =
+        split_market_accounts(&ctx.accounts.oracle_config, feed, ctx.remaining_accounts)?;
+    let quoted = quote_price(
+        &mut ctx.accounts.oracle_config,
+        feed,
+        feed_accounts,
+        ctx.accounts.accumulator.as_deref().map(|a| &**a),
+        rounding,
+        now,
+    )?;
+    let QuotePrice { spot: spot_price, price: price_scaled, sources_used, reports, manual_override } =
+        match quoted {
+            PriceOutcome::Priced(p)     => p,
+            PriceOutcome::Tripped(spot) => {
+                ctx.accounts.quote.close(ctx.accounts.requester.to_account_info())?;
+                return Ok(tripped_result(spot, now));
+            }
+        };
+    let oracle = &ctx.accounts.oracle_config;
 
-    validate_confidence(price_scaled, conf_scaled, oracle.max_confidence_bps)?;
+    // A lower multiplier pays out less
+    let fair = SwapLeg {
+        price_scaled,
+        feed_type:      &feed.feed_type,
+        cpi_multiplier: oracle.cpi_multiplier_at(now, Rounding::Down)?,
+        token_decimals: oracle.token_decimals,
+        price_decimals: feed.decimals,
+    };
 
-    // Calculate gross USD output from token input
-    let gross_usd = calc_usd_for_token_amount(
+    // Calculate gross quote-unit output from token input
+    let gross_quote = calc_quote_for_token_amount(
         params.input_amount,
         price_scaled,
         &feed.feed_type,
-        oracle.cpi_multiplier,
+        fair.cpi_multiplier,
+        oracle.token_decimals,
+        feed.decimals,
+        oracle.quote_denomination.decimals,
     )?;
 
-    require!(gross_usd > 0, OracleError::ZeroOutput);
+    require!(gross_quote > 0, OracleError::ZeroOutput);
 
-    let (net_usd, fee_usd) = apply_fee(gross_usd, oracle.redeem_fee_bps)?;
+    // Deduct redeem fee, off the peg fee curve when set
+    let (fee_bps, deviation_bps) = quote_fee_bps(
+        oracle,
+        &QuoteDirection::Redeem,
+        &fair,
+        ctx.accounts.market_feed.as_deref(),
+        market_accounts,
+    )?;
+    let (net_quote, fee_quote) = apply_fee(gross_quote, fee_bps)?;
 
-    check_slippage(net_usd, params.min_output)?;
+    check_slippage(net_quote, params.min_output)?;
 
-    let now         = Clock::get()?.unix_timestamp;
     let valid_until = now + oracle.quote_validity_secs;
 
     let quote = &mut ctx.accounts.quote;
-    quote.mint           = oracle.mint;
-    quote.requester      = ctx.accounts.requester.key();
-    quote.direction      = QuoteDirection::Redeem;
-    quote.feed_symbol    = oracle.feed_symbol.clone();
-    quote.input_amount   = params.input_amount;
-    quote.output_amount  = net_usd;
-    quote.fee_amount     = fee_usd;
-    quote.price_snapshot = price_scaled;
-    quote.valid_until    = valid_until;
-    quote.min_output     = params.min_output;
-    quote.used           = false;
-    quote.created_at     = now;
-    quote.nonce          = params.nonce;
-    quote.bump           = ctx.bumps.quote;
+    quote.mint            = oracle.mint;
+    quote.requester       = ctx.accounts.requester.key();
+    quote.direction       = QuoteDirection::Redeem;
+    quote.feed_symbol     = oracle.feed_symbol.clone();
+    quote.input_amount    = params.input_amount;
+    quote.output_amount   = net_quote;
+    quote.fee_amount      = fee_quote;
+    quote.price_snapshot  = price_scaled;
+    quote.price_decimals  = feed.decimals;
+    quote.pricing_mode    = oracle.pricing_mode;
+    quote.sources_used    = sources_used;
+    quote.manual_override = manual_override;
+    quote.valid_until     = valid_until;
+    quote.min_output      = params.min_output;
+    quote.used            = false;
+    quote.created_at      = now;
+    quote.nonce           = params.nonce;
+    quote.bump            = ctx.bumps.quote;
 
     emit!(QuoteGenerated {
-        quote_id:      ctx.accounts.quote.key(),
-        mint:          oracle.mint,
-        feed_symbol:   oracle.feed_symbol.clone(),
-        direction:     "Redeem".to_string(),
-        input_amount:  params.input_amount,
-        output_amount: net_usd,
-        fee_amount:    fee_usd,
-        price_used:    price_scaled,
+        quote_id:       ctx.accounts.quote.key(),
+        mint:           oracle.mint,
+        feed_symbol:    oracle.feed_symbol.clone(),
+        direction:      "Redeem".to_string(),
+        input_amount:   params.input_amount,
+        output_amount:  net_quote,
+        fee_amount:     fee_quote,
+        fee_bps,
+        deviation_bps,
+        price_used:     price_scaled,
+        price_decimals: feed.decimals,
+        pricing_mode:   oracle.pricing_mode,
+        spot_price,
+        sources:        reports,
+        manual_override,
         valid_until,
-        timestamp:     now,
+        timestamp:      now,
     });
 
     Ok(QuoteResult {
-        output_amount: net_usd,
-        fee_amount:    fee_usd,
+        output_amount: net_quote,
+        fee_amount:    fee_quote,
         price_used:    price_scaled,
         valid_until,
         quote_account: ctx.accounts.quote.key(),
+        tripped:       false,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// close_expired_quote — Permissionless crank for abandoned quotes
+//
+// A PendingQuote is normally closed when its requester executes it. Once
+// `valid_until` has passed it can never be executed, so anyone may close it;
+// the rent always goes back to the requester, never to the caller.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct CloseExpiredQuote<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sss-quote",
+            quote.mint.as_ref(),
+            quote.requester.as_ref(),
+            &quote.nonce.to_le_bytes(),
+        ],
+        bump  = quote.bump,
+        close = requester,
+    )]
+    pub quote: Account<'info, PendingQuote>,
+
+    /// CHECK: Rent refund destination — must be the quote's requester
+    #[account(
+        mut,
+        address = quote.requester @ OracleError::Unauthorized,
+    )]
+    pub requester: UncheckedAccount<'info>,
+}
+
+pub fn close_expired_quote(ctx: Context<CloseExpiredQuote>) -> Result<()> {
+    let quote = &ctx.accounts.quote;
+    let now   = Clock::get()?.unix_timestamp;
+    require!(quote.is_expired(now), OracleError::QuoteNotExpired);
+
+    emit!(QuoteClosed {
+        quote_id:      quote.key(),
+        mint:          quote.mint,
+        requester:     quote.requester,
+        valid_until:   quote.valid_until,
+        rent_refunded: quote.to_account_info().lamports(),
+        closed_by:     ctx.accounts.caller.key(),
+        timestamp:     now,
+    });
+
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// close_expired_swap_quote — close_expired_quote for PendingSwapQuote
+//
+// Same rules: once expired, anyone may close it and the rent goes back to
+// the requester. `QuoteClosed.mint` is the swap's input mint.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct CloseExpiredSwapQuote<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sss-swap-quote",
+            quote.mint_in.as_ref(),
+            quote.requester.as_ref(),
+            &quote.nonce.to_le_bytes(),
+        ],
+        bump  = quote.bump,
+        close = requester,
+    )]
+    pub quote: Account<'info, PendingSwapQuote>,
+
+    /// CHECK: Rent refund destination — must be the quote's requester
+    #[account(
+        mut,
+        address = quote.requester @ OracleError::Unauthorized,
+    )]
+    pub requester: UncheckedAccount<'info>,
+}
+
+pub fn close_expired_swap_quote(ctx: Context<CloseExpiredSwapQuote>) -> Result<()> {
+    let quote = &ctx.accounts.quote;
+    let now   = Clock::get()?.unix_timestamp;
+    require!(quote.is_expired(now), OracleError::QuoteNotExpired);
+
+    emit!(QuoteClosed {
+        quote_id:      quote.key(),
+        mint:          quote.mint_in,
+        requester:     quote.requester,
+        valid_until:   quote.valid_until,
+        rent_refunded: quote.to_account_info().lamports(),
+        closed_by:     ctx.accounts.caller.key(),
+        timestamp:     now,
+    });
+
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// close_expired_quotes — Batch variant of close_expired_quote
+//
+// `remaining_accounts` holds (quote, requester) pairs; a quote may be a
+// PendingQuote or a PendingSwapQuote. Quotes that are already closed
+// (executed or cranked since the transaction was built) or not yet expired
+// are skipped, so a stale crank batch does not fail as a whole. Anything
+// that is not a quote PDA of this program, or a requester that does not
+// match, fails the instruction.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct CloseExpiredQuotes<'info> {
+    pub caller: Signer<'info>,
+}
+
+pub fn close_expired_quotes<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseExpiredQuotes<'info>>,
+) -> Result<()> {
+    let accounts = ctx.remaining_accounts;
+    require!(
+        !accounts.is_empty() && accounts.len() % 2 == 0,
+        OracleError::InvalidQuoteAccounts
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    for pair in accounts.chunks(2) {
+        let (quote_info, requester_info) = (&pair[0], &pair[1]);
+        if quote_info.data_is_empty() {
+            continue;
+        }
+
+        // Account::try_from checks owner and discriminator
+        let is_swap = quote_info.try_borrow_data()?.starts_with(PendingSwapQuote::DISCRIMINATOR);
+        if is_swap {
+            let quote = Account::<PendingSwapQuote>::try_from(quote_info)?;
+            let seeds = QuoteSeeds {
+                prefix:    b"sss-swap-quote",
+                mint:      quote.mint_in,
+                requester: quote.requester,
+                nonce:     quote.nonce,
+                bump:      quote.bump,
+            };
+            if check_closable(&seeds, quote.valid_until, quote_info, requester_info, &ctx.accounts.caller, now)? {
+                quote.close(requester_info.clone())?;
+            }
+        } else {
+            let quote = Account::<PendingQuote>::try_from(quote_info)?;
+            let seeds = QuoteSeeds {
+                prefix:    b"sss-quote",
+                mint:      quote.mint,
+                requester: quote.requester,
+                nonce:     quote.nonce,
+                bump:      quote.bump,
+            };
+            if check_closable(&seeds, quote.valid_until, quote_info, requester_info, &ctx.accounts.caller, now)? {
+                quote.close(requester_info.clone())?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// PDA seeds shared by both quote kinds: `[prefix, mint, requester, nonce]`
+struct QuoteSeeds {
+    prefix:    &'static [u8],
+    mint:      Pubkey,
+    requester: Pubkey,
+    nonce:     u64,
+    bump:      u8,
+}
+
+/// Check a quote passed to the batch crank and, once it has expired, emit
+/// its `QuoteClosed`. Returns whether the caller should close it.
+///
+/// # Errors
+/// Returns `OracleError::InvalidQuoteAccounts` – not the PDA its own fields derive
+/// Returns `OracleError::Unauthorized`         – requester account does not match
+fn check_closable(
+    seeds:          &QuoteSeeds,
+    valid_until:    i64,
+    quote_info:     &AccountInfo,
+    requester_info: &AccountInfo,
+    caller:         &Signer,
+    now:            i64,
+) -> Result<bool> {
+    let expected = Pubkey::create_program_address(
+        &[
+            seeds.prefix,
+            seeds.mint.as_ref(),
+            seeds.requester.as_ref(),
+            &seeds.nonce.to_le_bytes(),
+            &[seeds.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| error!(OracleError::InvalidQuoteAccounts))?;
+    require!(quote_info.key() == expected, OracleError::InvalidQuoteAccounts);
+    require!(requester_info.key() == seeds.requester, OracleError::Unauthorized);
+
+    // Same rule as `PendingQuote::is_expired`
+    if now <= valid_until {
+        return Ok(false);
+    }
+
+    emit!(QuoteClosed {
+        quote_id:      expected,
+        mint:          seeds.mint,
+        requester:     seeds.requester,
+        valid_until,
+        rent_refunded: quote_info.lamports(),
+        closed_by:     caller.key(),
+        timestamp:     now,
+    });
+    Ok(true)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// get_price_diagnostics — Read-only "what would I get?" view
+//
+// Writes nothing: no PendingQuote, no circuit-breaker state, no stats. The
+// result is returned as return data, so it is meant to be read with
+// `simulateTransaction`. It reports the raw reading of the feed, what a
+// quote for `amount` in `direction` would output right now, and which
+// validation — pause, staleness, confidence, circuit breaker — would stop
+// it, along with the exact error code.
+//
+// `remaining_accounts` are the feed's sources, as for `get_mint_quote`.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DiagnosticsParams {
+    pub direction: QuoteDirection,
+    /// Quote units for mint, token base units for redeem
+    pub amount:    u64,
+}
+
+#[derive(Accounts)]
+pub struct GetPriceDiagnostics<'info> {
+    #[account(
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump  = oracle_config.bump,
+    )]
+    pub oracle_config: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        seeds = [b"sss-feed", oracle_config.feed_symbol.as_bytes()],
+        bump  = feed.bump,
+    )]
+    pub feed: Box<Account<'info, FeedEntry>>,
+
+    /// Required unless `oracle_config.pricing_mode` is `Spot`
+    #[account(
+        seeds = [b"sss-accumulator", oracle_config.feed_symbol.as_bytes()],
+        bump  = accumulator.bump,
+    )]
+    pub accumulator: Option<Box<Account<'info, PriceAccumulator>>>,
+
+    /// The peg fee curve's market feed — required when `oracle_config.peg_fee_curve` is set
+    pub market_feed: Option<Box<Account<'info, FeedEntry>>>,
+}
+
+/// Anchor error code of `err`, or u32::MAX for a plain runtime error.
+fn error_code_of(err: &Error) -> u32 {
+    match err {
+        Error::AnchorError(e)  => e.error_code_number,
+        Error::ProgramError(_) => u32::MAX,
+    }
+}
+
+pub fn get_price_diagnostics(
+    ctx: Context<GetPriceDiagnostics>,
+    params: DiagnosticsParams,
+) -> Result<PriceDiagnostics> {
+    let oracle   = &ctx.accounts.oracle_config;
+    let feed     = &ctx.accounts.feed;
+    let now      = Clock::get()?.unix_timestamp;
+    let rounding = price_rounding(&feed.feed_type, &params.direction);
+    let manual   = oracle.manual_price_active(now);
+    let (accounts, market_accounts) = split_market_accounts(oracle, feed, ctx.remaining_accounts)
+        .unwrap_or((ctx.remaining_accounts, &[]));
+
+    // ── 1. Raw reading: every readable source, no staleness or confidence limit ──
+    let (raw_price, confidence, age_secs, sources) =
+        match aggregate_feed_price(feed, accounts, rounding, i64::MAX, 0) {
+            Ok(agg) => (
+                agg.price_scaled,
+                confidence_bps(agg.price_scaled, agg.conf_scaled).unwrap_or(u64::MAX),
+                now.saturating_sub(agg.oldest_timestamp),
+                agg.reports,
+            ),
+            Err(_) => (0, 0, -1, Vec::new()),
+        };
+
+    // ── 2. The reading a quote would take, under the oracle's limits ──
+    let spot: core::result::Result<u64, u32> = if manual {
+        Ok(oracle.manual_price)
+    } else {
+        aggregate_feed_price(
+            feed,
+            accounts,
+            rounding,
+            oracle.max_staleness_secs,
+            oracle.max_confidence_bps,
+        )
+        .map(|agg| agg.price_scaled)
+        .map_err(|err| error_code_of(&err))
+    };
+
+    // Multi-source feeds fail as a whole; attribute that to the sources' own checks
+    let failed_on = |code: OracleError, source_check: &dyn Fn(&SourceReport) -> bool| match spot {
+        Ok(_)    => false,
+        Err(err) => {
+            err == u32::from(code)
+                || (err == u32::from(OracleError::InsufficientSources)
+                    && sources.iter().any(source_check))
+        }
+    };
+    let stale = failed_on(OracleError::PriceTooStale, &|r| {
+        r.age_secs > oracle.max_staleness_secs
+    });
+    let confidence_too_wide = failed_on(OracleError::ConfidenceTooWide, &|r| {
+        oracle.max_confidence_bps > 0 && r.confidence_bps > oracle.max_confidence_bps as u64
+    });
+    let breaker_would_trip = match spot {
+        Ok(price) if !manual => matches!(breaker_move(oracle, price, now), Ok(Some(_))),
+        _                    => false,
+    };
+
+    // ── 3. What the quote would output, or the error it would fail with ──
+    let flat_fee_bps = match params.direction {
+        QuoteDirection::Mint   => oracle.mint_fee_bps,
+        QuoteDirection::Redeem => oracle.redeem_fee_bps,
+    };
+    let output = |spot: u64| -> Result<(u64, u16, u64, u64, u64)> {
+        let price = if manual {
+            spot
+        } else {
+            let accumulator = ctx.accounts.accumulator.as_deref().map(|a| &**a);
+            resolve_price(oracle, accumulator, spot, rounding, now)?
+        };
+
+        let cpi_rounding = match params.direction {
+            QuoteDirection::Mint   => Rounding::Up,
+            QuoteDirection::Redeem => Rounding::Down,
+        };
+        let fair = SwapLeg {
+            price_scaled:   price,
+            feed_type:      &feed.feed_type,
+            cpi_multiplier: oracle.cpi_multiplier_at(now, cpi_rounding)?,
+            token_decimals: oracle.token_decimals,
+            price_decimals: feed.decimals,
+        };
+        let convert = match params.direction {
+            QuoteDirection::Mint   => calc_token_amount_for_quote,
+            QuoteDirection::Redeem => calc_quote_for_token_amount,
+        };
+        let gross = convert(
+            params.amount,
+            price,
+            &feed.feed_type,
+            fair.cpi_multiplier,
+            oracle.token_decimals,
+            feed.decimals,
+            oracle.quote_denomination.decimals,
+        )?;
+        require!(gross > 0, OracleError::ZeroOutput);
+
+        let (fee_bps, _) = quote_fee_bps(
+            oracle,
+            &params.direction,
+            &fair,
+            ctx.accounts.market_feed.as_deref(),
+            market_accounts,
+        )?;
+        let (net, fee) = apply_fee(gross, fee_bps)?;
+        Ok((price, fee_bps, gross, fee, net))
+    };
+
+    // Same order of checks as the quote instructions
+    let quoted = if !feed.active {
+        Err(u32::from(OracleError::FeedInactive))
+    } else if oracle.paused {
+        Err(u32::from(OracleError::OraclePaused))
+    } else if params.amount == 0 {
+        Err(u32::from(OracleError::ZeroAmount))
+    } else if breaker_would_trip {
+        // The reading would pause the oracle and quote nothing
+        Ok((0, flat_fee_bps, 0, 0, 0))
+    } else {
+        spot.and_then(|spot| output(spot).map_err(|err| error_code_of(&err)))
+    };
+    let ((price_used, fee_bps, gross_output, fee_amount, net_output), error_code) = match quoted {
+        Ok(out)   => (out, 0),
+        Err(code) => ((0, flat_fee_bps, 0, 0, 0), code),
+    };
+
+    Ok(PriceDiagnostics {
+        raw_price,
+        price_decimals:      feed.decimals,
+        confidence_bps:      confidence,
+        age_secs,
+        feed_type:           feed.feed_type.clone(),
+        pricing_mode:        oracle.pricing_mode,
+        manual_override:     manual,
+        price_used,
+        fee_bps,
+        gross_output,
+        fee_amount,
+        net_output,
+        sources,
+        paused:              oracle.paused,
+        stale,
+        confidence_too_wide,
+        breaker_would_trip,
+        error_code,
     })
 }
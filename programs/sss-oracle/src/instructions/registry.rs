@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::OracleError;
 use crate::events::*;
+use crate::math::MAX_AMOUNT_DECIMALS;
+use crate::aggregation::load_leg_feed;
+use crate::pyth::read_pyth_price;
 use crate::switchboard::read_switchboard_price;
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -29,20 +32,34 @@ pub struct InitializeRegistry<'info> {
 
 pub fn initialize_registry(ctx: Context<InitializeRegistry>) -> Result<()> {
     let registry = &mut ctx.accounts.registry;
-    registry.authority  = ctx.accounts.authority.key();
-    registry.feed_count = 0;
-    registry.feeds      = Vec::with_capacity(MAX_FEEDS);
-    registry.bump       = ctx.bumps.registry;
+    registry.authority         = ctx.accounts.authority.key();
+    registry.pending_authority = None;
+    registry.feed_count        = 0;
+    registry.bump              = ctx.bumps.registry;
     Ok(())
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
-// register_feed — Add a new price feed to the global registry
+// register_feed — Create a new price feed PDA under the global registry
+//
+// The feed's source accounts (1..=MAX_FEED_SOURCES, Switchboard or Pyth) are
+// passed as `remaining_accounts`; their order is the order quotes must pass
+// them in. `source_kinds[i]` names the provider of `remaining_accounts[i]`.
+//
+// A `Composite` feed has no sources of its own: `remaining_accounts` are its
+// legs' feed PDAs instead, in leg order, with no source kinds and
+// `min_sources = 0`.
 // ═══════════════════════════════════════════════════════════════════════════
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct RegisterFeedParams {
     pub symbol:         String,
+    /// Minimum number of fresh sources required to quote
+    pub min_sources:    u8,
+    /// Max spread between sources as bps of the median (0 = no check)
+    pub max_spread_bps: u16,
+    /// Provider of each source account, in `remaining_accounts` order
+    pub source_kinds:   Vec<SourceKind>,
     pub feed_type:      FeedType,
     pub base_currency:  String,
     pub quote_currency: String,
@@ -67,49 +84,67 @@ pub struct RegisterFeed<'info> {
     )]
     pub registry: Account<'info, FeedRegistry>,
 
-    /// CHECK: Switchboard aggregator account — validated by reading its data
-    pub switchboard_feed: UncheckedAccount<'info>,
+    /// Symbols are unique because the PDA can only be created once
+    #[account(
+        init,
+        payer = payer,
+        space = FeedEntry::LEN,
+        seeds = [b"sss-feed", params.symbol.as_bytes()],
+        bump,
+    )]
+    pub feed: Account<'info, FeedEntry>,
 
     pub system_program: Program<'info, System>,
 }
 
 pub fn register_feed(ctx: Context<RegisterFeed>, params: RegisterFeedParams) -> Result<()> {
-    let registry = &mut ctx.accounts.registry;
-
     // Validation
-    require!(params.symbol.len() <= 12, OracleError::SymbolTooLong);
-    require!(
-        registry.find_feed(&params.symbol).is_none(),
-        OracleError::FeedAlreadyRegistered
-    );
-    require!(
-        registry.feed_count < MAX_FEEDS as u8,
-        OracleError::RegistryFull
-    );
+    require!(params.symbol.len() <= MAX_SYMBOL_LEN, OracleError::SymbolTooLong);
+    require!(params.decimals <= MAX_AMOUNT_DECIMALS, OracleError::UnsupportedDecimals);
 
-    // Verify the Switchboard feed account is readable
-    let feed_data = ctx.accounts.switchboard_feed.try_borrow_data()?;
-    let _price = read_switchboard_price(&feed_data)
-        .map_err(|_| error!(OracleError::FeedNotReady))?;
+    let sources = if let FeedType::Composite { legs, .. } = &params.feed_type {
+        require!(
+            params.source_kinds.is_empty() && params.min_sources == 0,
+            OracleError::InvalidSourceCount
+        );
+        validate_composite_legs(&params.symbol, legs, ctx.remaining_accounts)?;
+        Vec::new()
+    } else {
+        validate_sources(
+            ctx.remaining_accounts,
+            &params.source_kinds,
+            params.min_sources,
+        )?
+    };
 
-    let now = Clock::get()?.unix_timestamp;
-
-    registry.feeds.push(FeedEntry {
-        symbol:           params.symbol.clone(),
-        switchboard_feed: ctx.accounts.switchboard_feed.key(),
-        feed_type:        params.feed_type.clone(),
-        base_currency:    params.base_currency.clone(),
-        quote_currency:   params.quote_currency.clone(),
-        decimals:         params.decimals,
-        active:           true,
-        registered_at:    now,
-        registered_by:    ctx.accounts.authority.key(),
-    });
-    registry.feed_count += 1;
+    let now  = Clock::get()?.unix_timestamp;
+    let feed = &mut ctx.accounts.feed;
+
+    feed.symbol           = params.symbol.clone();
+    feed.sources          = sources.clone();
+    feed.min_sources      = params.min_sources;
+    feed.max_spread_bps   = params.max_spread_bps;
+    feed.feed_type        = params.feed_type.clone();
+    feed.base_currency    = params.base_currency.clone();
+    feed.quote_currency   = params.quote_currency.clone();
+    feed.decimals         = params.decimals;
+    feed.active           = true;
+    feed.registered_at    = now;
+    feed.registered_by    = ctx.accounts.authority.key();
+    feed.updated_at       = now;
+    feed.bump             = ctx.bumps.feed;
+
+    let registry = &mut ctx.accounts.registry;
+    registry.feed_count = registry
+        .feed_count
+        .checked_add(1)
+        .ok_or(OracleError::MathOverflow)?;
 
     emit!(FeedRegistered {
         symbol:           params.symbol,
-        switchboard_feed: ctx.accounts.switchboard_feed.key(),
+        sources,
+        min_sources:      params.min_sources,
+        max_spread_bps:   params.max_spread_bps,
         feed_type:        format!("{:?}", params.feed_type),
         base_currency:    params.base_currency,
         quote_currency:   params.quote_currency,
@@ -119,3 +154,257 @@ pub fn register_feed(ctx: Context<RegisterFeed>, params: RegisterFeedParams) ->
 
     Ok(())
 }
+
+/// Validate a feed's source set and build its `PriceSource` list.
+///
+/// `accounts` are the source accounts in quote order and `kinds[i]` names the
+/// provider of `accounts[i]`. Every account must parse with its provider.
+fn validate_sources(
+    accounts:    &[AccountInfo],
+    kinds:       &[SourceKind],
+    min_sources: u8,
+) -> Result<Vec<PriceSource>> {
+    let keys: Vec<Pubkey> = accounts.iter().map(|a| a.key()).collect();
+    require!(
+        !keys.is_empty() && keys.len() <= MAX_FEED_SOURCES,
+        OracleError::InvalidSourceCount
+    );
+    require!(kinds.len() == keys.len(), OracleError::SourceKindMismatch);
+    require!(
+        min_sources >= 1 && min_sources as usize <= keys.len(),
+        OracleError::InvalidSourceCount
+    );
+    for (i, key) in keys.iter().enumerate() {
+        require!(!keys[..i].contains(key), OracleError::DuplicateSource);
+    }
+
+    // Verify every source account is readable with its declared provider
+    for (account, kind) in accounts.iter().zip(kinds.iter()) {
+        let feed_data = account.try_borrow_data()?;
+        match kind {
+            SourceKind::Switchboard => read_switchboard_price(&feed_data).map(|_| ()),
+            SourceKind::Pyth        => read_pyth_price(&feed_data).map(|_| ()),
+        }
+        .map_err(|_| error!(OracleError::FeedNotReady))?;
+    }
+
+    Ok(keys
+        .into_iter()
+        .zip(kinds.iter())
+        .map(|(account, kind)| PriceSource { kind: *kind, account })
+        .collect())
+}
+
+/// Validate a composite feed's legs against their feed PDAs, passed in leg
+/// order as `accounts`.
+fn validate_composite_legs(
+    symbol:   &str,
+    legs:     &[CompositeLeg],
+    accounts: &[AccountInfo],
+) -> Result<()> {
+    require!(
+        (2..=MAX_COMPOSITE_LEGS).contains(&legs.len()),
+        OracleError::InvalidCompositeLegs
+    );
+    require!(accounts.len() == legs.len(), OracleError::FeedMismatch);
+    for (i, leg) in legs.iter().enumerate() {
+        require!(
+            leg.symbol.len() <= MAX_SYMBOL_LEN
+                && leg.symbol != symbol
+                && !legs[..i].iter().any(|l| l.symbol == leg.symbol),
+            OracleError::InvalidCompositeLegs
+        );
+        load_leg_feed(&accounts[i], leg)?;
+    }
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Feed lifecycle — deactivate, rotate sources, change feed type
+//
+// All gated by the registry authority. A deactivated feed can no longer be
+// quoted, cranked or attached to an oracle; it is not reactivated, since its
+// symbol PDA keeps the audit trail of what it pointed at.
+//
+// A feed never switches between composite and source-backed, so a feed used
+// as a composite leg can never become composite itself.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct ManageFeed<'info> {
+    #[account(
+        constraint = authority.key() == registry.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"sss-feed-registry"],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, FeedRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-feed", feed.symbol.as_bytes()],
+        bump = feed.bump,
+        constraint = feed.active @ OracleError::FeedInactive,
+    )]
+    pub feed: Account<'info, FeedEntry>,
+}
+
+pub fn deactivate_feed(ctx: Context<ManageFeed>, reason: String) -> Result<()> {
+    let feed = &mut ctx.accounts.feed;
+    let now  = Clock::get()?.unix_timestamp;
+
+    feed.active     = false;
+    feed.updated_at = now;
+
+    emit!(FeedDeactivated {
+        symbol:    feed.symbol.clone(),
+        reason,
+        by:        ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Replacement source set for `rotate_feed_source`. The new source accounts
+/// are passed as `remaining_accounts`, as in `register_feed`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RotateFeedSourceParams {
+    /// Minimum number of fresh sources required to quote
+    pub min_sources:    u8,
+    /// Max spread between sources as bps of the median (0 = no check)
+    pub max_spread_bps: u16,
+    /// Provider of each source account, in `remaining_accounts` order
+    pub source_kinds:   Vec<SourceKind>,
+}
+
+pub fn rotate_feed_source(
+    ctx: Context<ManageFeed>,
+    params: RotateFeedSourceParams,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.feed.feed_type.is_composite(),
+        OracleError::CompositeFeedChange
+    );
+    let sources = validate_sources(
+        ctx.remaining_accounts,
+        &params.source_kinds,
+        params.min_sources,
+    )?;
+
+    let feed = &mut ctx.accounts.feed;
+    let now  = Clock::get()?.unix_timestamp;
+
+    let old_sources = std::mem::replace(&mut feed.sources, sources.clone());
+    feed.min_sources    = params.min_sources;
+    feed.max_spread_bps = params.max_spread_bps;
+    feed.updated_at     = now;
+
+    emit!(FeedSourceRotated {
+        symbol:         feed.symbol.clone(),
+        old_sources,
+        new_sources:    sources,
+        min_sources:    params.min_sources,
+        max_spread_bps: params.max_spread_bps,
+        by:             ctx.accounts.authority.key(),
+        timestamp:      now,
+    });
+
+    Ok(())
+}
+
+/// A composite feed's new legs are checked against their feed PDAs, passed
+/// as `remaining_accounts` like in `register_feed`.
+pub fn update_feed_type(ctx: Context<ManageFeed>, feed_type: FeedType) -> Result<()> {
+    require!(
+        ctx.accounts.feed.feed_type.is_composite() == feed_type.is_composite(),
+        OracleError::CompositeFeedChange
+    );
+    if let FeedType::Composite { legs, .. } = &feed_type {
+        validate_composite_legs(&ctx.accounts.feed.symbol, legs, ctx.remaining_accounts)?;
+    }
+
+    let feed = &mut ctx.accounts.feed;
+    let now  = Clock::get()?.unix_timestamp;
+
+    let old_type = std::mem::replace(&mut feed.feed_type, feed_type.clone());
+    feed.updated_at = now;
+
+    emit!(FeedTypeUpdated {
+        symbol:    feed.symbol.clone(),
+        old_type:  format!("{:?}", old_type),
+        new_type:  format!("{:?}", feed_type),
+        by:        ctx.accounts.authority.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Two-step registry authority transfer
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct ProposeRegistryAuthority<'info> {
+    #[account(
+        constraint = authority.key() == registry.authority @ OracleError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-feed-registry"],
+        bump = registry.bump,
+    )]
+    pub registry: Account<'info, FeedRegistry>,
+}
+
+pub fn propose_registry_authority(
+    ctx: Context<ProposeRegistryAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    registry.pending_authority = Some(new_authority);
+
+    emit!(RegistryAuthorityProposed {
+        current:   ctx.accounts.authority.key(),
+        proposed:  new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptRegistryAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-feed-registry"],
+        bump = registry.bump,
+        constraint = registry.pending_authority == Some(new_authority.key())
+            @ OracleError::NoPendingTransfer,
+    )]
+    pub registry: Account<'info, FeedRegistry>,
+}
+
+pub fn accept_registry_authority(ctx: Context<AcceptRegistryAuthority>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let old      = registry.authority;
+
+    registry.authority         = ctx.accounts.new_authority.key();
+    registry.pending_authority = None;
+
+    emit!(RegistryAuthorityTransferred {
+        from:      old,
+        to:        registry.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
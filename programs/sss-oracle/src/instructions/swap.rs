@@ -0,0 +1,594 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::onchain::invoke_transfer_checked;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use sss_core::program::SssCore;
+use sss_core::state::{MinterConfig, StablecoinConfig};
+use crate::state::*;
+use crate::errors::OracleError;
+use crate::events::*;
+use crate::math::*;
+use crate::aggregation::feed_account_span;
+use crate::compliance::{check_participant, check_recipient};
+use crate::limits::enforce_caps;
+use super::quotes::{quote_price, GetQuoteParams, PriceOutcome, QuotePrice};
+
+fn tripped_swap_result(spot: u64, now: i64) -> SwapQuoteResult {
+    SwapQuoteResult {
+        output_amount: 0,
+        fee_amount:    0,
+        price_in:      spot,
+        price_out:     0,
+        valid_until:   now,
+        quote_account: Pubkey::default(),
+        tripped:       true,
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// get_swap_quote — token A → token B at the cross rate of their feeds
+//
+// Each side is priced exactly like a quote on its own oracle: manual
+// override, circuit breaker and pricing mode all apply per leg. The input is
+// valued like a redeem and the output like a mint, so both legs round in the
+// protocol's favour. The swap fee is the output oracle's `swap_fee_bps`,
+// taken from the output and minted to its fee vault on execution.
+//
+// `remaining_accounts` holds the input feed's accounts followed by the
+// output feed's, each laid out as for a single quote: the feed's sources in
+// order, or for a composite feed each leg's feed PDA and its sources.
+// `params.input_amount` is in base units of token A, `params.min_output` in
+// base units of token B.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+#[instruction(params: GetQuoteParams)]
+pub struct GetSwapQuote<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-oracle", oracle_in.mint.as_ref()],
+        bump  = oracle_in.bump,
+    )]
+    pub oracle_in: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-oracle", oracle_out.mint.as_ref()],
+        bump  = oracle_out.bump,
+        constraint = oracle_out.mint != oracle_in.mint @ OracleError::SwapSameMint,
+    )]
+    pub oracle_out: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        seeds = [b"sss-feed", oracle_in.feed_symbol.as_bytes()],
+        bump  = feed_in.bump,
+        constraint = feed_in.active @ OracleError::FeedInactive,
+    )]
+    pub feed_in: Box<Account<'info, FeedEntry>>,
+
+    #[account(
+        seeds = [b"sss-feed", oracle_out.feed_symbol.as_bytes()],
+        bump  = feed_out.bump,
+        constraint = feed_out.active @ OracleError::FeedInactive,
+    )]
+    pub feed_out: Box<Account<'info, FeedEntry>>,
+
+    /// Required unless `oracle_in.pricing_mode` is `Spot`
+    #[account(
+        seeds = [b"sss-accumulator", oracle_in.feed_symbol.as_bytes()],
+        bump  = accumulator_in.bump,
+    )]
+    pub accumulator_in: Option<Box<Account<'info, PriceAccumulator>>>,
+
+    /// Required unless `oracle_out.pricing_mode` is `Spot`
+    #[account(
+        seeds = [b"sss-accumulator", oracle_out.feed_symbol.as_bytes()],
+        bump  = accumulator_out.bump,
+    )]
+    pub accumulator_out: Option<Box<Account<'info, PriceAccumulator>>>,
+
+    #[account(
+        init,
+        payer  = requester,
+        space  = PendingSwapQuote::LEN,
+        seeds  = [
+            b"sss-swap-quote",
+            oracle_in.mint.as_ref(),
+            requester.key().as_ref(),
+            &params.nonce.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub quote: Box<Account<'info, PendingSwapQuote>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn get_swap_quote(ctx: Context<GetSwapQuote>, params: GetQuoteParams) -> Result<SwapQuoteResult> {
+    require!(
+        !ctx.accounts.oracle_in.paused && !ctx.accounts.oracle_out.paused,
+        OracleError::OraclePaused
+    );
+    require!(params.input_amount > 0, OracleError::ZeroAmount);
+    // One quote-unit value feeds both oracles' caps and stats
+    require!(
+        ctx.accounts.oracle_in.quote_denomination == ctx.accounts.oracle_out.quote_denomination,
+        OracleError::QuoteDenominationMismatch
+    );
+
+    let feed_in  = &ctx.accounts.feed_in;
+    let feed_out = &ctx.accounts.feed_out;
+    let split    = feed_account_span(feed_in, ctx.remaining_accounts)?;
+    require!(
+        ctx.remaining_accounts.len() >= split,
+        OracleError::FeedMismatch
+    );
+    let (sources_in, sources_out) = ctx.remaining_accounts.split_at(split);
+    let now = Clock::get()?.unix_timestamp;
+
+    // ── 1. Price both legs; a tripped breaker pauses that oracle ──
+    let leg_in = quote_price(
+        &mut ctx.accounts.oracle_in,
+        feed_in,
+        sources_in,
+        ctx.accounts.accumulator_in.as_deref().map(|a| &**a),
+        price_rounding(&feed_in.feed_type, &QuoteDirection::Redeem),
+        now,
+    )?;
+    let QuotePrice { price: price_in, reports: reports_in, manual_override: manual_in, .. } =
+        match leg_in {
+            PriceOutcome::Priced(p)     => p,
+            PriceOutcome::Tripped(spot) => {
+                ctx.accounts.quote.close(ctx.accounts.requester.to_account_info())?;
+                return Ok(tripped_swap_result(spot, now));
+            }
+        };
+
+    let leg_out = quote_price(
+        &mut ctx.accounts.oracle_out,
+        feed_out,
+        sources_out,
+        ctx.accounts.accumulator_out.as_deref().map(|a| &**a),
+        price_rounding(&feed_out.feed_type, &QuoteDirection::Mint),
+        now,
+    )?;
+    let QuotePrice { price: price_out, reports: reports_out, manual_override: manual_out, .. } =
+        match leg_out {
+            PriceOutcome::Priced(p)     => p,
+            PriceOutcome::Tripped(spot) => {
+                ctx.accounts.quote.close(ctx.accounts.requester.to_account_info())?;
+                return Ok(SwapQuoteResult { price_in, price_out: spot, ..tripped_swap_result(0, now) });
+            }
+        };
+
+    let oracle_in  = &ctx.accounts.oracle_in;
+    let oracle_out = &ctx.accounts.oracle_out;
+
+    // ── 2. Cross rate: A valued like a redeem, B priced like a mint ──
+    let cpi_in  = oracle_in.cpi_multiplier_at(now, Rounding::Down)?;
+    let cpi_out = oracle_out.cpi_multiplier_at(now, Rounding::Up)?;
+    let from = SwapLeg {
+        price_scaled:   price_in,
+        feed_type:      &feed_in.feed_type,
+        cpi_multiplier: cpi_in,
+        token_decimals: oracle_in.token_decimals,
+        price_decimals: feed_in.decimals,
+    };
+    let to = SwapLeg {
+        price_scaled:   price_out,
+        feed_type:      &feed_out.feed_type,
+        cpi_multiplier: cpi_out,
+        token_decimals: oracle_out.token_decimals,
+        price_decimals: feed_out.decimals,
+    };
+    let gross_out = calc_swap_amount(params.input_amount, &from, &to)?;
+    require!(gross_out > 0, OracleError::ZeroOutput);
+
+    let (net_out, fee_out) = apply_fee(gross_out, oracle_out.swap_fee_bps)?;
+    check_slippage(net_out, params.min_output)?;
+
+    // Quote-unit value of the input, for both oracles' caps and lifetime stats
+    let usd_value = calc_quote_for_token_amount(
+        params.input_amount,
+        price_in,
+        &feed_in.feed_type,
+        cpi_in,
+        oracle_in.token_decimals,
+        feed_in.decimals,
+        oracle_in.quote_denomination.decimals,
+    )?;
+
+    // The shorter of the two oracles' validity windows applies
+    let valid_until = now + oracle_in.quote_validity_secs.min(oracle_out.quote_validity_secs);
+    let manual_override = manual_in || manual_out;
+
+    // ── 3. Store the quote ──
+    let quote = &mut ctx.accounts.quote;
+    quote.mint_in            = oracle_in.mint;
+    quote.mint_out           = oracle_out.mint;
+    quote.requester          = ctx.accounts.requester.key();
+    quote.feed_symbol_in     = oracle_in.feed_symbol.clone();
+    quote.feed_symbol_out    = oracle_out.feed_symbol.clone();
+    quote.input_amount       = params.input_amount;
+    quote.output_amount      = net_out;
+    quote.fee_amount         = fee_out;
+    quote.usd_value          = usd_value;
+    quote.price_in           = price_in;
+    quote.price_decimals_in  = feed_in.decimals;
+    quote.price_out          = price_out;
+    quote.price_decimals_out = feed_out.decimals;
+    quote.manual_override    = manual_override;
+    quote.valid_until        = valid_until;
+    quote.min_output         = params.min_output;
+    quote.created_at         = now;
+    quote.nonce              = params.nonce;
+    quote.bump               = ctx.bumps.quote;
+
+    emit!(SwapQuoteGenerated {
+        quote_id:        quote.key(),
+        mint_in:         oracle_in.mint,
+        mint_out:        oracle_out.mint,
+        feed_symbol_in:  oracle_in.feed_symbol.clone(),
+        feed_symbol_out: oracle_out.feed_symbol.clone(),
+        input_amount:    params.input_amount,
+        output_amount:   net_out,
+        fee_amount:      fee_out,
+        usd_value,
+        price_in,
+        price_out,
+        sources_in:      reports_in,
+        sources_out:     reports_out,
+        manual_override,
+        valid_until,
+        timestamp:       now,
+    });
+
+    Ok(SwapQuoteResult {
+        output_amount: net_out,
+        fee_amount:    fee_out,
+        price_in,
+        price_out,
+        valid_until,
+        quote_account: quote.key(),
+        tripped:       false,
+    })
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// swap_with_oracle — Atomic swap quote consumption: burn A, mint B
+//
+// Moves `quote.input_amount` of token A from the requester into the input
+// oracle's escrow and burns it through sss-core, then mints
+// `quote.output_amount` of token B to the destination and `quote.fee_amount`
+// to the output oracle's fee vault.
+//
+// The input oracle config PDA must hold the sss-core burner role of token A
+// and the output oracle config PDA must be an sss-core minter of token B.
+// Transfer-hook extra accounts for token A are passed through
+// `remaining_accounts`.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct SwapWithOracle<'info> {
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-oracle", oracle_in.mint.as_ref()],
+        bump  = oracle_in.bump,
+    )]
+    pub oracle_in: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"sss-oracle", oracle_out.mint.as_ref()],
+        bump  = oracle_out.bump,
+    )]
+    pub oracle_out: Box<Account<'info, OracleConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sss-swap-quote",
+            oracle_in.mint.as_ref(),
+            requester.key().as_ref(),
+            &quote.nonce.to_le_bytes(),
+        ],
+        bump  = quote.bump,
+        constraint = quote.requester == requester.key() @ OracleError::Unauthorized,
+        constraint = quote.mint_out == oracle_out.mint @ OracleError::MintMismatch,
+        close = requester,
+    )]
+    pub quote: Box<Account<'info, PendingSwapQuote>>,
+
+    // ── Token A (burned) ──
+
+    /// sss-core stablecoin config for token A
+    #[account(
+        mut,
+        seeds = [b"sss-config", mint_in.key().as_ref()],
+        seeds::program = sss_core::ID,
+        bump = core_config_in.bump,
+        constraint = core_config_in.mint == mint_in.key() @ OracleError::MintMismatch,
+    )]
+    pub core_config_in: Box<Account<'info, StablecoinConfig>>,
+
+    #[account(
+        mut,
+        constraint = mint_in.key() == oracle_in.mint @ OracleError::MintMismatch,
+    )]
+    pub mint_in: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Requester's token A account the swapped tokens are taken from
+    #[account(
+        mut,
+        token::mint = mint_in,
+        token::authority = requester,
+        token::token_program = token_program,
+    )]
+    pub source: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Input oracle escrow — associated token account of `oracle_in`
+    #[account(
+        mut,
+        associated_token::mint = mint_in,
+        associated_token::authority = oracle_in,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_in: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Requester's sss-core allowlist entry for token A — checked in `check_participant`.
+    /// Required when token A has an active allowlist or its oracle a `min_kyc_tier`
+    pub requester_allowlist_in: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Requester's sss-core blacklist entry PDA for token A (may be empty) — checked
+    /// in `check_participant`. Required when token A has a blacklister
+    pub requester_blacklist_in: Option<UncheckedAccount<'info>>,
+
+    // ── Token B (minted) ──
+
+    /// sss-core stablecoin config for token B
+    #[account(
+        mut,
+        seeds = [b"sss-config", mint_out.key().as_ref()],
+        seeds::program = sss_core::ID,
+        bump = core_config_out.bump,
+    )]
+    pub core_config_out: Box<Account<'info, StablecoinConfig>>,
+
+    /// sss-core minter entry registered for the output oracle config PDA
+    #[account(
+        mut,
+        seeds = [b"sss-minter", mint_out.key().as_ref(), oracle_out.key().as_ref()],
+        seeds::program = sss_core::ID,
+        bump = minter_config_out.bump,
+    )]
+    pub minter_config_out: Box<Account<'info, MinterConfig>>,
+
+    /// CHECK: Must match oracle_out.mint — validated by Token-2022 in the sss-core CPI
+    #[account(
+        mut,
+        constraint = mint_out.key() == oracle_out.mint @ OracleError::MintMismatch,
+    )]
+    pub mint_out: UncheckedAccount<'info>,
+
+    /// CHECK: Recipient token B account — validated by Token-2022 in the sss-core CPI
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// CHECK: Output oracle fee vault PDA (created by `initialize_fee_vault`) — receives the swap fee
+    #[account(
+        mut,
+        seeds = [b"sss-fee-vault", mint_out.key().as_ref()],
+        bump,
+    )]
+    pub fee_vault_out: UncheckedAccount<'info>,
+
+    /// CHECK: Requester's sss-core allowlist entry for token B — checked in `check_participant`.
+    /// Required when token B has an active allowlist or its oracle a `min_kyc_tier`
+    pub requester_allowlist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Requester's sss-core blacklist entry PDA for token B (may be empty) — checked
+    /// in `check_participant`. Required when token B has a blacklister
+    pub requester_blacklist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Recipient's sss-core allowlist entry — checked in `check_recipient`
+    pub recipient_allowlist: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Recipient's sss-core blacklist entry PDA (may be empty) — checked in `check_recipient`
+    pub recipient_blacklist: Option<UncheckedAccount<'info>>,
+
+    // ── Rolling USD usage ──
+
+    /// Requester's token A usage — required when `oracle_in` sets a per-user redeem cap
+    #[account(
+        mut,
+        seeds = [b"sss-usage", oracle_in.mint.as_ref(), requester.key().as_ref()],
+        bump  = user_usage_in.bump,
+    )]
+    pub user_usage_in: Option<Box<Account<'info, UserUsage>>>,
+
+    /// Requester's token B usage — required when `oracle_out` sets a per-user mint cap
+    #[account(
+        mut,
+        seeds = [b"sss-usage", oracle_out.mint.as_ref(), requester.key().as_ref()],
+        bump  = user_usage_out.bump,
+    )]
+    pub user_usage_out: Option<Box<Account<'info, UserUsage>>>,
+
+    pub sss_core_program: Program<'info, SssCore>,
+    pub token_program:    Program<'info, Token2022>,
+}
+
+pub fn swap_with_oracle<'info>(ctx: Context<'_, '_, '_, 'info, SwapWithOracle<'info>>) -> Result<()> {
+    let quote = &ctx.accounts.quote;
+
+    require!(
+        !ctx.accounts.oracle_in.paused && !ctx.accounts.oracle_out.paused,
+        OracleError::OraclePaused
+    );
+    require!(
+        ctx.accounts.core_config_in.burner == ctx.accounts.oracle_in.key(),
+        OracleError::SwapRequiresBurner
+    );
+
+    // Check quote expiry
+    let now = Clock::get()?.unix_timestamp;
+    require!(!quote.is_expired(now), OracleError::QuoteExpired);
+
+    // Slippage validation against the stored min_output
+    require!(
+        quote.output_amount >= quote.min_output,
+        OracleError::SlippageExceeded
+    );
+
+    // sss-core compliance of token A: the requester sends it
+    check_participant(
+        &ctx.accounts.core_config_in,
+        ctx.accounts.oracle_in.min_kyc_tier,
+        &QuoteDirection::Redeem,
+        &ctx.accounts.requester.key(),
+        ctx.accounts.requester_allowlist_in.as_deref(),
+        ctx.accounts.requester_blacklist_in.as_deref(),
+    )?;
+
+    // sss-core compliance of token B: requester and recipient
+    let min_kyc_tier = ctx.accounts.oracle_out.min_kyc_tier;
+    check_participant(
+        &ctx.accounts.core_config_out,
+        min_kyc_tier,
+        &QuoteDirection::Mint,
+        &ctx.accounts.requester.key(),
+        ctx.accounts.requester_allowlist.as_deref(),
+        ctx.accounts.requester_blacklist.as_deref(),
+    )?;
+    check_recipient(
+        &ctx.accounts.core_config_out,
+        min_kyc_tier,
+        &ctx.accounts.destination,
+        ctx.accounts.recipient_allowlist.as_deref(),
+        ctx.accounts.recipient_blacklist.as_deref(),
+    )?;
+
+    // Rolling USD caps: a redemption of token A and a mint of token B
+    let requester = ctx.accounts.requester.key();
+    enforce_caps(
+        &mut ctx.accounts.oracle_in,
+        ctx.accounts.user_usage_in.as_deref_mut().map(|u| &mut **u),
+        requester,
+        QuoteDirection::Redeem,
+        quote.usd_value,
+        now,
+    )?;
+    enforce_caps(
+        &mut ctx.accounts.oracle_out,
+        ctx.accounts.user_usage_out.as_deref_mut().map(|u| &mut **u),
+        requester,
+        QuoteDirection::Mint,
+        quote.usd_value,
+        now,
+    )?;
+
+    // ── 1. Escrow token A and burn it, signed by the input oracle PDA ──
+    invoke_transfer_checked(
+        &ctx.accounts.token_program.key(),
+        ctx.accounts.source.to_account_info(),
+        ctx.accounts.mint_in.to_account_info(),
+        ctx.accounts.escrow_in.to_account_info(),
+        ctx.accounts.requester.to_account_info(),
+        ctx.remaining_accounts,
+        quote.input_amount,
+        ctx.accounts.mint_in.decimals,
+        &[],
+    )?;
+
+    let mint_in_key = ctx.accounts.oracle_in.mint;
+    let in_bump     = ctx.accounts.oracle_in.bump;
+    let in_seeds    = &[b"sss-oracle".as_ref(), mint_in_key.as_ref(), &[in_bump]];
+    let in_signer   = &[&in_seeds[..]];
+
+    let burn_accounts = sss_core::cpi::accounts::BurnTokens {
+        burner:        ctx.accounts.oracle_in.to_account_info(),
+        config:        ctx.accounts.core_config_in.to_account_info(),
+        source:        ctx.accounts.escrow_in.to_account_info(),
+        mint:          ctx.accounts.mint_in.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    };
+    sss_core::cpi::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.sss_core_program.to_account_info(),
+            burn_accounts,
+            in_signer,
+        ),
+        quote.input_amount,
+    )?;
+
+    // ── 2. Mint token B, signed by the output oracle PDA ──
+    let mint_out_key = ctx.accounts.oracle_out.mint;
+    let out_bump     = ctx.accounts.oracle_out.bump;
+    let out_seeds    = &[b"sss-oracle".as_ref(), mint_out_key.as_ref(), &[out_bump]];
+    let out_signer   = &[&out_seeds[..]];
+
+    let mint_to = |destination: AccountInfo<'info>, amount: u64| -> Result<()> {
+        let cpi_accounts = sss_core::cpi::accounts::MintTokens {
+            minter:        ctx.accounts.oracle_out.to_account_info(),
+            config:        ctx.accounts.core_config_out.to_account_info(),
+            minter_config: ctx.accounts.minter_config_out.to_account_info(),
+            mint:          ctx.accounts.mint_out.to_account_info(),
+            destination,
+            token_program: ctx.accounts.token_program.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.sss_core_program.to_account_info();
+        let cpi_ctx     = CpiContext::new_with_signer(cpi_program, cpi_accounts, out_signer);
+
+        sss_core::cpi::mint(cpi_ctx, amount)
+    };
+
+    mint_to(ctx.accounts.destination.to_account_info(), quote.output_amount)?;
+    if quote.fee_amount > 0 {
+        mint_to(ctx.accounts.fee_vault_out.to_account_info(), quote.fee_amount)?;
+    }
+
+    // ── 3. Update both oracles' lifetime stats ──
+    let usd_value = quote.usd_value;
+    let fee_amount = quote.fee_amount;
+
+    let oracle_in = &mut ctx.accounts.oracle_in;
+    oracle_in.total_redeemed_usd = oracle_in
+        .total_redeemed_usd
+        .checked_add(usd_value)
+        .ok_or(OracleError::MathOverflow)?;
+    oracle_in.last_updated_at = now;
+
+    let oracle_out = &mut ctx.accounts.oracle_out;
+    oracle_out.total_minted_usd = oracle_out
+        .total_minted_usd
+        .checked_add(usd_value)
+        .ok_or(OracleError::MathOverflow)?;
+    oracle_out.total_fees_collected = oracle_out
+        .total_fees_collected
+        .checked_add(fee_amount)
+        .ok_or(OracleError::MathOverflow)?;
+    oracle_out.last_updated_at = now;
+
+    let quote = &ctx.accounts.quote;
+    emit!(SwapExecuted {
+        mint_in:       mint_in_key,
+        mint_out:      mint_out_key,
+        requester:     ctx.accounts.requester.key(),
+        recipient:     ctx.accounts.destination.key(),
+        input_amount:  quote.input_amount,
+        output_amount: quote.output_amount,
+        fee_amount,
+        usd_value,
+        price_in:      quote.price_in,
+        price_out:     quote.price_out,
+        timestamp:     now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// initialize_user_usage — Create a requester's rolling USD usage account
+//
+// Permissionless: anyone may pay for any user's account. It only records
+// volume, so creating it early cannot hurt the user. Required on oracle
+// mints and redemptions once the oracle sets a per-user cap.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+pub struct InitializeUserUsage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Any wallet; only its key seeds the usage PDA
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"sss-oracle", oracle_config.mint.as_ref()],
+        bump  = oracle_config.bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = UserUsage::LEN,
+        seeds = [b"sss-usage", oracle_config.mint.as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub user_usage: Account<'info, UserUsage>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_user_usage(ctx: Context<InitializeUserUsage>) -> Result<()> {
+    let usage = &mut ctx.accounts.user_usage;
+    usage.mint         = ctx.accounts.oracle_config.mint;
+    usage.user         = ctx.accounts.user.key();
+    usage.mint_usage   = UsageWindow::default();
+    usage.redeem_usage = UsageWindow::default();
+    usage.created_at   = Clock::get()?.unix_timestamp;
+    usage.bump         = ctx.bumps.user_usage;
+
+    Ok(())
+}
@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
 
+pub mod aggregation;
+pub mod attestation;
+pub mod compliance;
 pub mod errors;
 pub mod events;
 pub mod instructions;
+pub mod limits;
 pub mod math;
+pub mod pyth;
 pub mod state;
 pub mod switchboard;
 
@@ -28,6 +33,37 @@ pub mod sss_oracle {
         instructions::registry::register_feed(ctx, params)
     }
 
+    /// Deactivate a feed so it can no longer be quoted
+    pub fn deactivate_feed(ctx: Context<ManageFeed>, reason: String) -> Result<()> {
+        instructions::registry::deactivate_feed(ctx, reason)
+    }
+
+    /// Replace a feed's source accounts (passed as remaining accounts)
+    pub fn rotate_feed_source(
+        ctx: Context<ManageFeed>,
+        params: RotateFeedSourceParams,
+    ) -> Result<()> {
+        instructions::registry::rotate_feed_source(ctx, params)
+    }
+
+    /// Change how a feed's price is interpreted (composite legs passed as remaining accounts)
+    pub fn update_feed_type(ctx: Context<ManageFeed>, feed_type: FeedType) -> Result<()> {
+        instructions::registry::update_feed_type(ctx, feed_type)
+    }
+
+    /// Propose a two-step registry authority transfer
+    pub fn propose_registry_authority(
+        ctx: Context<ProposeRegistryAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::registry::propose_registry_authority(ctx, new_authority)
+    }
+
+    /// Accept a pending registry authority transfer (signed by the new authority)
+    pub fn accept_registry_authority(ctx: Context<AcceptRegistryAuthority>) -> Result<()> {
+        instructions::registry::accept_registry_authority(ctx)
+    }
+
     // ── Oracle Config ────────────────────────────────────────────────────────
 
     /// Initialize an oracle config for a specific SSS token mint
@@ -43,27 +79,82 @@ pub mod sss_oracle {
         instructions::oracle_config::pause_oracle(ctx, reason)
     }
 
-    /// Unpause oracle operations
-    pub fn unpause_oracle(ctx: Context<PauseOracle>) -> Result<()> {
-        instructions::oracle_config::unpause_oracle(ctx)
+    /// Unpause oracle operations, acknowledging the current pause reason
+    pub fn unpause_oracle(ctx: Context<PauseOracle>, params: UnpauseParams) -> Result<()> {
+        instructions::oracle_config::unpause_oracle(ctx, params)
+    }
+
+    /// Queue a risk-parameter update behind the config timelock
+    pub fn queue_config_update(
+        ctx: Context<QueueConfigUpdate>,
+        update: OracleParamsUpdate,
+    ) -> Result<()> {
+        instructions::oracle_config::queue_config_update(ctx, update)
+    }
+
+    /// Apply a queued risk-parameter update once its timelock has elapsed
+    pub fn apply_config_update(ctx: Context<ApplyConfigUpdate>) -> Result<()> {
+        instructions::oracle_config::apply_config_update(ctx)
     }
 
-    /// Propose a two-step authority transfer
+    /// Cancel a queued risk-parameter update
+    pub fn cancel_config_update(ctx: Context<CancelConfigUpdate>) -> Result<()> {
+        instructions::oracle_config::cancel_config_update(ctx)
+    }
+
+    /// Set an emergency manual price, bounded around the last good price
+    pub fn set_manual_price(
+        ctx: Context<SetManualPrice>,
+        params: ManualPriceParams,
+    ) -> Result<()> {
+        instructions::oracle_config::set_manual_price(ctx, params)
+    }
+
+    /// Clear the manual price before it expires
+    pub fn clear_manual_price(ctx: Context<ClearManualPrice>) -> Result<()> {
+        instructions::oracle_config::clear_manual_price(ctx)
+    }
+
+    /// Propose a two-step transfer of one role (its holder or the admin)
     pub fn propose_authority_transfer(
         ctx: Context<ProposeAuthorityTransfer>,
+        role: OracleRole,
         new_authority: Pubkey,
     ) -> Result<()> {
-        instructions::oracle_config::propose_authority_transfer(ctx, new_authority)
+        instructions::oracle_config::propose_authority_transfer(ctx, role, new_authority)
+    }
+
+    /// Accept a pending role transfer (must be signed by the new holder)
+    pub fn accept_authority_transfer(
+        ctx: Context<AcceptAuthorityTransfer>,
+        role: OracleRole,
+    ) -> Result<()> {
+        instructions::oracle_config::accept_authority_transfer(ctx, role)
+    }
+
+    // ── Fees ─────────────────────────────────────────────────────────────────
+
+    /// Create the oracle's fee vault token account
+    pub fn initialize_fee_vault(ctx: Context<InitializeFeeVault>) -> Result<()> {
+        instructions::fees::initialize_fee_vault(ctx)
     }
 
-    /// Accept a pending authority transfer (must be signed by new authority)
-    pub fn accept_authority_transfer(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
-        instructions::oracle_config::accept_authority_transfer(ctx)
+    /// Replace the fee-split recipients
+    pub fn set_fee_splits(ctx: Context<SetFeeSplits>, splits: Vec<FeeSplit>) -> Result<()> {
+        instructions::fees::set_fee_splits(ctx, splits)
+    }
+
+    /// Pay `amount` out of the fee vault to the split recipients and treasury
+    pub fn withdraw_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawFees<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::fees::withdraw_fees(ctx, amount)
     }
 
     // ── CPI ──────────────────────────────────────────────────────────────────
 
-    /// Update CPI multiplier (monthly admin operation for CpiIndexed tokens)
+    /// Update CPI multiplier (monthly CPI updater operation for CpiIndexed tokens)
     pub fn update_cpi_multiplier(
         ctx: Context<UpdateCpiMultiplier>,
         params: UpdateCpiParams,
@@ -71,6 +162,21 @@ pub mod sss_oracle {
         instructions::cpi_update::update_cpi_multiplier(ctx, params)
     }
 
+    // ── Price Accumulator ────────────────────────────────────────────────────
+
+    /// Create the TWAP/EMA accumulator for a registered feed (registry authority)
+    pub fn initialize_accumulator(
+        ctx: Context<InitializeAccumulator>,
+        params: InitializeAccumulatorParams,
+    ) -> Result<()> {
+        instructions::accumulator::initialize_accumulator(ctx, params)
+    }
+
+    /// Fold the feed's current median into its accumulator (permissionless crank)
+    pub fn update_accumulator(ctx: Context<UpdateAccumulator>) -> Result<()> {
+        instructions::accumulator::update_accumulator(ctx)
+    }
+
     // ── Quotes ───────────────────────────────────────────────────────────────
 
     /// Get a mint quote — USD in → tokens out
@@ -89,10 +195,116 @@ pub mod sss_oracle {
         instructions::quotes::get_redeem_quote(ctx, params)
     }
 
+    /// Read-only price, fee and output preview with the validations a quote would fail (return data)
+    pub fn get_price_diagnostics(
+        ctx: Context<GetPriceDiagnostics>,
+        params: DiagnosticsParams,
+    ) -> Result<PriceDiagnostics> {
+        instructions::quotes::get_price_diagnostics(ctx, params)
+    }
+
+    /// Close an expired, unexecuted quote and refund its rent to the requester (anyone)
+    pub fn close_expired_quote(ctx: Context<CloseExpiredQuote>) -> Result<()> {
+        instructions::quotes::close_expired_quote(ctx)
+    }
+
+    /// Close an expired, unexecuted swap quote and refund its rent to the requester (anyone)
+    pub fn close_expired_swap_quote(ctx: Context<CloseExpiredSwapQuote>) -> Result<()> {
+        instructions::quotes::close_expired_swap_quote(ctx)
+    }
+
+    /// Batch close expired mint/redeem or swap quotes passed as (quote, requester) pairs in remaining accounts
+    pub fn close_expired_quotes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseExpiredQuotes<'info>>,
+    ) -> Result<()> {
+        instructions::quotes::close_expired_quotes(ctx)
+    }
+
     // ── Atomic Execution ─────────────────────────────────────────────────────
 
-    /// Execute a stored mint quote — validates + emits event atomically
-    pub fn mint_with_oracle(ctx: Context<MintWithOracle>) -> Result<()> {
+    /// Execute a stored mint quote — validates and mints through sss-core atomically
+    pub fn mint_with_oracle<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintWithOracle<'info>>,
+    ) -> Result<()> {
         instructions::execute::mint_with_oracle(ctx)
     }
+
+    /// Price and mint in one instruction — no quote account, bounded by min_output and max_price
+    pub fn mint_at_market<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintAtMarket<'info>>,
+        params: MintAtMarketParams,
+    ) -> Result<QuoteResult> {
+        instructions::execute::mint_at_market(ctx, params)
+    }
+
+    /// Execute a stored redeem quote — escrows/burns tokens and records the USD owed
+    pub fn redeem_with_oracle<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemWithOracle<'info>>,
+    ) -> Result<()> {
+        instructions::execute::redeem_with_oracle(ctx)
+    }
+
+    /// Confirm the off-chain fiat payout for a redemption settlement
+    pub fn confirm_settlement(
+        ctx: Context<ConfirmSettlement>,
+        payout_reference: String,
+    ) -> Result<()> {
+        instructions::execute::confirm_settlement(ctx, payout_reference)
+    }
+
+    // ── Swaps ────────────────────────────────────────────────────────────────
+
+    /// Get a cross-stablecoin swap quote — token A in → token B out
+    pub fn get_swap_quote(
+        ctx: Context<GetSwapQuote>,
+        params: GetQuoteParams,
+    ) -> Result<SwapQuoteResult> {
+        instructions::swap::get_swap_quote(ctx, params)
+    }
+
+    /// Execute a stored swap quote — burns token A and mints token B through sss-core atomically
+    pub fn swap_with_oracle<'info>(
+        ctx: Context<'_, '_, '_, 'info, SwapWithOracle<'info>>,
+    ) -> Result<()> {
+        instructions::swap::swap_with_oracle(ctx)
+    }
+
+    // ── Peg Stability Module ─────────────────────────────────────────────────
+
+    /// Accept a USD stablecoin as PSM collateral for this mint, with a debt ceiling
+    pub fn register_psm_collateral(
+        ctx: Context<RegisterPsmCollateral>,
+        debt_ceiling_usd: u64,
+    ) -> Result<()> {
+        instructions::psm::register_psm_collateral(ctx, debt_ceiling_usd)
+    }
+
+    /// Change a PSM collateral's debt ceiling or active flag
+    pub fn update_psm_collateral(
+        ctx: Context<UpdatePsmCollateral>,
+        params: UpdatePsmCollateralParams,
+    ) -> Result<()> {
+        instructions::psm::update_psm_collateral(ctx, params)
+    }
+
+    /// Execute a stored mint quote paid in PSM collateral — deposits and mints atomically
+    pub fn mint_with_psm<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintWithPsm<'info>>,
+    ) -> Result<()> {
+        instructions::psm::mint_with_psm(ctx)
+    }
+
+    /// Execute a stored redeem quote against PSM collateral — burns and releases atomically
+    pub fn redeem_with_psm<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemWithPsm<'info>>,
+    ) -> Result<()> {
+        instructions::psm::redeem_with_psm(ctx)
+    }
+
+    // ── Issuance Caps ────────────────────────────────────────────────────────
+
+    /// Create a requester's rolling USD usage account (permissionless)
+    pub fn initialize_user_usage(ctx: Context<InitializeUserUsage>) -> Result<()> {
+        instructions::usage::initialize_user_usage(ctx)
+    }
 }
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::errors::OracleError;
+use crate::events::IssuanceCapExceeded;
+use crate::state::{OracleConfig, QuoteDirection, UsageWindow, UserUsage};
+
+// ─── Rolling issuance caps ──────────────────────────────────────────────────
+//
+// Every oracle mint and redemption counts its value, in the oracle's
+// `quote_denomination` units, against the global window for that
+// direction and, when passed, the requester's `UserUsage` window. A cap
+// of 0 disables it. A rejected operation emits `IssuanceCapExceeded`
+// before failing, so the hit is visible in the failed transaction's logs.
+
+/// Check the rolling caps for `amount_usd` in `direction` and record it.
+///
+/// `user_usage` must be the requester's `["sss-usage", mint, requester]`
+/// PDA (enforced by the caller's account constraints). It is required when
+/// the per-user cap for `direction` is set, and is updated whenever passed.
+///
+/// # Errors
+/// Returns `OracleError::UserUsageRequired`                          – a per-user cap is set but no usage account passed
+/// Returns `OracleError::GlobalMintCapExceeded` / `GlobalRedeemCapExceeded` – the global window is full
+/// Returns `OracleError::UserMintCapExceeded` / `UserRedeemCapExceeded`     – the requester's window is full
+pub fn enforce_caps(
+    oracle:     &mut OracleConfig,
+    user_usage: Option<&mut UserUsage>,
+    user:       Pubkey,
+    direction:  QuoteDirection,
+    amount_usd: u64,
+    now:        i64,
+) -> Result<()> {
+    let (global_cap, user_cap, global_err, user_err) = match direction {
+        QuoteDirection::Mint => (
+            oracle.global_mint_cap_usd,
+            oracle.user_mint_cap_usd,
+            OracleError::GlobalMintCapExceeded,
+            OracleError::UserMintCapExceeded,
+        ),
+        QuoteDirection::Redeem => (
+            oracle.global_redeem_cap_usd,
+            oracle.user_redeem_cap_usd,
+            OracleError::GlobalRedeemCapExceeded,
+            OracleError::UserRedeemCapExceeded,
+        ),
+    };
+    let mint = oracle.mint;
+
+    let global = match direction {
+        QuoteDirection::Mint   => &mut oracle.global_mint_usage,
+        QuoteDirection::Redeem => &mut oracle.global_redeem_usage,
+    };
+    check_window(global, global_cap, amount_usd, now, &mint, None, &direction, global_err)?;
+
+    match user_usage {
+        Some(usage) => {
+            let window = match direction {
+                QuoteDirection::Mint   => &mut usage.mint_usage,
+                QuoteDirection::Redeem => &mut usage.redeem_usage,
+            };
+            check_window(window, user_cap, amount_usd, now, &mint, Some(user), &direction, user_err)?;
+            window.record(amount_usd, now)?;
+        }
+        None => require!(user_cap == 0, OracleError::UserUsageRequired),
+    }
+
+    global.record(amount_usd, now)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn check_window(
+    window:     &UsageWindow,
+    cap_usd:    u64,
+    amount_usd: u64,
+    now:        i64,
+    mint:       &Pubkey,
+    user:       Option<Pubkey>,
+    direction:  &QuoteDirection,
+    error:      OracleError,
+) -> Result<()> {
+    if cap_usd == 0 || window.usage_with(amount_usd, now)? <= cap_usd {
+        return Ok(());
+    }
+
+    emit!(IssuanceCapExceeded {
+        mint:          *mint,
+        user,
+        direction:     format!("{:?}", direction),
+        attempted_usd: amount_usd,
+        window_usage:  window.usage_with(0, now)?,
+        cap_usd,
+        timestamp:     now,
+    });
+    Err(error.into())
+}
@@ -1,186 +1,527 @@
 use anchor_lang::prelude::*;
-use crate::state::FeedType;
+use crate::state::{CompositeOp, FeedType, QuoteDirection};
 use crate::errors::OracleError;
 
-/// Fixed-point scale for prices: 1_000_000 = 1.0
+/// Default fixed-point scale for prices: 1_000_000 = 1.0.
+/// Quotes price at the feed's own `decimals`; see `calc_token_amount_for_quote`.
 pub const PRICE_SCALE: u64 = 1_000_000;
 
+/// Number of decimals in PRICE_SCALE
+pub const PRICE_DECIMALS: u8 = 6;
+
 /// Fixed-point scale for CPI multiplier: 1_000_000 = 1.0
 pub const CPI_SCALE: u64 = 1_000_000;
 
-/// Token decimals for SSS standard (always 6)
-pub const TOKEN_DECIMALS: u32 = 6;
+/// Default token decimals of the SSS presets. The oracle reads the actual
+/// decimals from the mint (`OracleConfig.token_decimals`).
+pub const TOKEN_DECIMALS: u8 = 6;
+
+/// Decimals of the default quote denomination (USD cents). The oracle reads
+/// the actual decimals from `OracleConfig.quote_denomination`.
+pub const QUOTE_DECIMALS: u8 = 2;
+
+/// Upper bound on feed price decimals and mint decimals; keeps every
+/// intermediate product inside u128.
+pub const MAX_AMOUNT_DECIMALS: u8 = 12;
 
-/// 10^TOKEN_DECIMALS
-pub const TOKEN_SCALE: u64 = 1_000_000;
+// ─── Deterministic fixed-point ──────────────────────────────────────────────
+//
+// The quote pipeline never touches floating point. Feed values are carried
+// as exact (mantissa, scale) decimals, rescaled to PRICE_SCALE with an
+// explicit rounding mode, and every division below rounds in the protocol's
+// favour: token and USD outputs round down, fees and confidence round up,
+// and the price itself rounds in whichever direction yields the smaller
+// output for the quote direction (see `price_rounding`). The same inputs
+// therefore always produce the same quote, on-chain and off-chain.
+
+/// Rounding mode for fixed-point rescaling and division.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Toward zero (floor for non-negative values)
+    Down,
+    /// Away from zero (ceiling for non-negative values)
+    Up,
+}
+
+impl Rounding {
+    /// The other direction: a divisor must round this way for the quotient
+    /// to round the original way.
+    pub fn reversed(self) -> Self {
+        match self {
+            Rounding::Down => Rounding::Up,
+            Rounding::Up   => Rounding::Down,
+        }
+    }
+}
+
+/// Exact decimal value as read from a feed account: `mantissa / 10^scale`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScaledDecimal {
+    pub mantissa: i128,
+    pub scale:    u32,
+}
+
+impl ScaledDecimal {
+    pub fn new(mantissa: i128, scale: u32) -> Self {
+        Self { mantissa, scale }
+    }
+
+    /// Absolute value (used for standard deviations, which carry no sign).
+    pub fn abs(self) -> Self {
+        Self { mantissa: self.mantissa.saturating_abs(), scale: self.scale }
+    }
+
+    /// Rescale to `target_scale` decimals as an unsigned integer.
+    /// Negative values are rejected with `InvalidPrice`.
+    pub fn rescale(self, target_scale: u32, rounding: Rounding) -> Result<u64> {
+        require!(self.mantissa >= 0, OracleError::InvalidPrice);
+        let mantissa = self.mantissa as u128;
+
+        let result = if self.scale <= target_scale {
+            mantissa
+                .checked_mul(pow10(target_scale - self.scale)?)
+                .ok_or(OracleError::MathOverflow)?
+        } else {
+            div_round(mantissa, pow10(self.scale - target_scale)?, rounding)?
+        };
+
+        u64::try_from(result).map_err(|_| error!(OracleError::MathOverflow))
+    }
+
+    /// Rescale to PRICE_SCALE fixed-point (6 decimals).
+    pub fn to_price_scaled(self, rounding: Rounding) -> Result<u64> {
+        self.rescale(PRICE_DECIMALS as u32, rounding)
+    }
+}
+
+/// 10^exp as u128, erroring instead of overflowing.
+pub fn pow10(exp: u32) -> Result<u128> {
+    10u128.checked_pow(exp).ok_or_else(|| error!(OracleError::MathOverflow))
+}
+
+/// Divide with an explicit rounding mode.
+pub fn div_round(numerator: u128, denominator: u128, rounding: Rounding) -> Result<u128> {
+    require!(denominator > 0, OracleError::DivisionByZero);
+    let quotient = numerator / denominator;
+    match rounding {
+        Rounding::Down => Ok(quotient),
+        Rounding::Up if numerator % denominator != 0 => {
+            quotient.checked_add(1).ok_or_else(|| error!(OracleError::MathOverflow))
+        }
+        Rounding::Up => Ok(quotient),
+    }
+}
+
+/// Rounding to apply when converting a feed value to `price_scaled` so the
+/// resulting quote output can only be smaller than the exact result.
+///
+/// - Mint, price divides the input (Direct):   round price up
+/// - Mint, price multiplies the input (Inverse): round price down
+/// - Redeem is the mirror image.
+/// - CpiIndexed ignores the feed price, so the choice is immaterial.
+pub fn price_rounding(feed_type: &FeedType, direction: &QuoteDirection) -> Rounding {
+    let divides = match feed_type {
+        FeedType::Direct                      => true,
+        FeedType::Inverse                     => false,
+        FeedType::CpiIndexed                  => return Rounding::Down,
+        FeedType::Custom { base_type, .. }    => *base_type == 0,
+        FeedType::Composite { base_type, .. } => *base_type == 0,
+    };
+    match (direction, divides) {
+        (QuoteDirection::Mint, true)    => Rounding::Up,
+        (QuoteDirection::Mint, false)   => Rounding::Down,
+        (QuoteDirection::Redeem, true)  => Rounding::Down,
+        (QuoteDirection::Redeem, false) => Rounding::Up,
+    }
+}
 
 // ─── Token amount calculations ──────────────────────────────────────────────
+//
+// `price_scaled` carries `price_decimals` decimals (the feed's `decimals`),
+// token amounts carry `token_decimals` (the mint's decimals) and quote
+// amounts carry `quote_decimals` (the oracle's quote denomination, 2 for
+// cents):
+//
+//   TOKEN_SCALE = 10^token_decimals,  PRICE_SCALE = 10^price_decimals,
+//   QUOTE_SCALE = 10^quote_decimals
 
-/// Calculate how many tokens to mint for a given USD input.
+/// Calculate how many tokens to mint for a given quote-denominated input.
 ///
-/// All math uses u128 intermediaries to avoid overflow.
+/// All math uses u128 intermediaries to avoid overflow. The result is
+/// rounded down (in the protocol's favour).
 ///
-/// - `Direct`:     tokens = (usd_cents * TOKEN_SCALE * PRICE_SCALE) / (price_scaled * 100)
-/// - `Inverse`:    tokens = (usd_cents * price_scaled * TOKEN_SCALE) / (PRICE_SCALE * 100)
-/// - `CpiIndexed`: tokens = (usd_cents * TOKEN_SCALE * CPI_SCALE)   / (cpi_multiplier * 100)
+/// - `Direct`:     tokens = (quote_amount * TOKEN_SCALE * PRICE_SCALE) / (price_scaled * QUOTE_SCALE)
+/// - `Inverse`:    tokens = (quote_amount * price_scaled * TOKEN_SCALE) / (PRICE_SCALE * QUOTE_SCALE)
+/// - `CpiIndexed`: tokens = (quote_amount * TOKEN_SCALE * CPI_SCALE)   / (cpi_multiplier * QUOTE_SCALE)
 /// - `Custom`:     applies numerator/denominator scaling then delegates to Direct or Inverse
-pub fn calc_token_amount_for_usd(
-    usd_cents:       u64,
+/// - `Composite`:  the composed price is already final; delegates to Direct or Inverse
+pub fn calc_token_amount_for_quote(
+    quote_amount:    u64,
     price_scaled:    u64,
     feed_type:       &FeedType,
     cpi_multiplier:  u64,
+    token_decimals:  u8,
+    price_decimals:  u8,
+    quote_decimals:  u8,
 ) -> Result<u64> {
     require!(price_scaled > 0, OracleError::InvalidPrice);
+    let (token_scale, price_scale) = amount_scales(token_decimals, price_decimals)?;
+    let quote_scale = quote_scale(quote_decimals)?;
 
     let result = match feed_type {
         FeedType::Direct => {
-            // tokens = usd_cents / 100 * (1 / price) * TOKEN_SCALE
-            // = (usd_cents * TOKEN_SCALE * PRICE_SCALE) / (price_scaled * 100)
-            let numerator   = (usd_cents as u128)
-                .checked_mul(TOKEN_SCALE as u128)
-                .and_then(|v| v.checked_mul(PRICE_SCALE as u128))
+            // tokens = quote_amount / QUOTE_SCALE * (1 / price) * TOKEN_SCALE
+            // = (quote_amount * TOKEN_SCALE * PRICE_SCALE) / (price_scaled * QUOTE_SCALE)
+            let numerator   = (quote_amount as u128)
+                .checked_mul(token_scale)
+                .and_then(|v| v.checked_mul(price_scale))
                 .ok_or(OracleError::MathOverflow)?;
             let denominator = (price_scaled as u128)
-                .checked_mul(100)
+                .checked_mul(quote_scale)
                 .ok_or(OracleError::MathOverflow)?;
-            require!(denominator > 0, OracleError::DivisionByZero);
-            numerator / denominator
+            div_round(numerator, denominator, Rounding::Down)?
         }
 
         FeedType::Inverse => {
-            // tokens = usd_cents / 100 * price * TOKEN_SCALE
-            // = (usd_cents * price_scaled * TOKEN_SCALE) / (PRICE_SCALE * 100)
-            let numerator   = (usd_cents as u128)
+            // tokens = quote_amount / QUOTE_SCALE * price * TOKEN_SCALE
+            // = (quote_amount * price_scaled * TOKEN_SCALE) / (PRICE_SCALE * QUOTE_SCALE)
+            let numerator   = (quote_amount as u128)
                 .checked_mul(price_scaled as u128)
-                .and_then(|v| v.checked_mul(TOKEN_SCALE as u128))
+                .and_then(|v| v.checked_mul(token_scale))
                 .ok_or(OracleError::MathOverflow)?;
-            let denominator = (PRICE_SCALE as u128)
-                .checked_mul(100)
+            let denominator = price_scale
+                .checked_mul(quote_scale)
                 .ok_or(OracleError::MathOverflow)?;
-            numerator / denominator
+            div_round(numerator, denominator, Rounding::Down)?
         }
 
         FeedType::CpiIndexed => {
-            // tokens = (usd_cents / 100) / (cpi_multiplier / CPI_SCALE) * TOKEN_SCALE
-            // = (usd_cents * TOKEN_SCALE * CPI_SCALE) / (cpi_multiplier * 100)
+            // tokens = (quote_amount / QUOTE_SCALE) / (cpi_multiplier / CPI_SCALE) * TOKEN_SCALE
+            // = (quote_amount * TOKEN_SCALE * CPI_SCALE) / (cpi_multiplier * QUOTE_SCALE)
             require!(cpi_multiplier > 0, OracleError::InvalidCpiMultiplier);
-            let numerator   = (usd_cents as u128)
-                .checked_mul(TOKEN_SCALE as u128)
+            let numerator   = (quote_amount as u128)
+                .checked_mul(token_scale)
                 .and_then(|v| v.checked_mul(CPI_SCALE as u128))
                 .ok_or(OracleError::MathOverflow)?;
             let denominator = (cpi_multiplier as u128)
-                .checked_mul(100)
+                .checked_mul(quote_scale)
                 .ok_or(OracleError::MathOverflow)?;
-            numerator / denominator
+            div_round(numerator, denominator, Rounding::Down)?
         }
 
         FeedType::Custom { numerator, denominator, base_type } => {
-            require!(*denominator > 0, OracleError::DivisionByZero);
-            // Scale the price: adjusted_price = price * numerator / denominator
-            let adjusted_price = (price_scaled as u128)
-                .checked_mul(*numerator as u128)
-                .ok_or(OracleError::MathOverflow)?
-                / (*denominator as u128);
+            // Scale the price: adjusted_price = price * numerator / denominator,
+            // rounded up when it divides the input (Direct), down otherwise
+            let rounding = if *base_type == 0 { Rounding::Up } else { Rounding::Down };
+            let adjusted_price = div_round(
+                (price_scaled as u128)
+                    .checked_mul(*numerator as u128)
+                    .ok_or(OracleError::MathOverflow)?,
+                *denominator as u128,
+                rounding,
+            )?;
             let adjusted_u64 = u64::try_from(adjusted_price)
                 .map_err(|_| error!(OracleError::MathOverflow))?;
 
             // Delegate to Direct (base_type=0) or Inverse (base_type=1)
-            if *base_type == 0 {
-                return calc_token_amount_for_usd(usd_cents, adjusted_u64, &FeedType::Direct, cpi_multiplier);
-            } else {
-                return calc_token_amount_for_usd(usd_cents, adjusted_u64, &FeedType::Inverse, cpi_multiplier);
-            }
+            let base = if *base_type == 0 { FeedType::Direct } else { FeedType::Inverse };
+            return calc_token_amount_for_quote(
+                quote_amount, adjusted_u64, &base, cpi_multiplier,
+                token_decimals, price_decimals, quote_decimals,
+            );
+        }
+
+        FeedType::Composite { base_type, .. } => {
+            let base = if *base_type == 0 { FeedType::Direct } else { FeedType::Inverse };
+            return calc_token_amount_for_quote(
+                quote_amount, price_scaled, &base, cpi_multiplier,
+                token_decimals, price_decimals, quote_decimals,
+            );
         }
     };
 
     u64::try_from(result).map_err(|_| error!(OracleError::MathOverflow))
 }
 
-/// Calculate how many USD cents to return for a given token amount (reverse of mint).
-pub fn calc_usd_for_token_amount(
+/// Calculate how many quote units to return for a given token amount (reverse of mint).
+///
+/// The result is rounded down (in the protocol's favour).
+pub fn calc_quote_for_token_amount(
     token_amount:    u64,
     price_scaled:    u64,
     feed_type:       &FeedType,
     cpi_multiplier:  u64,
+    token_decimals:  u8,
+    price_decimals:  u8,
+    quote_decimals:  u8,
 ) -> Result<u64> {
     require!(price_scaled > 0, OracleError::InvalidPrice);
+    let (token_scale, price_scale) = amount_scales(token_decimals, price_decimals)?;
+    let quote_scale = quote_scale(quote_decimals)?;
 
     let result = match feed_type {
         FeedType::Direct => {
-            // usd_cents = token_amount / TOKEN_SCALE * price * 100
-            // = (token_amount * price_scaled * 100) / (TOKEN_SCALE * PRICE_SCALE)
+            // quote = token_amount / TOKEN_SCALE * price * QUOTE_SCALE
+            // = (token_amount * price_scaled * QUOTE_SCALE) / (TOKEN_SCALE * PRICE_SCALE)
             let numerator   = (token_amount as u128)
                 .checked_mul(price_scaled as u128)
-                .and_then(|v| v.checked_mul(100))
+                .and_then(|v| v.checked_mul(quote_scale))
                 .ok_or(OracleError::MathOverflow)?;
-            let denominator = (TOKEN_SCALE as u128)
-                .checked_mul(PRICE_SCALE as u128)
+            let denominator = token_scale
+                .checked_mul(price_scale)
                 .ok_or(OracleError::MathOverflow)?;
-            numerator / denominator
+            div_round(numerator, denominator, Rounding::Down)?
         }
 
         FeedType::Inverse => {
-            // usd_cents = token_amount / TOKEN_SCALE / price * 100
-            // = (token_amount * PRICE_SCALE * 100) / (TOKEN_SCALE * price_scaled)
+            // quote = token_amount / TOKEN_SCALE / price * QUOTE_SCALE
+            // = (token_amount * PRICE_SCALE * QUOTE_SCALE) / (TOKEN_SCALE * price_scaled)
             let numerator   = (token_amount as u128)
-                .checked_mul(PRICE_SCALE as u128)
-                .and_then(|v| v.checked_mul(100))
+                .checked_mul(price_scale)
+                .and_then(|v| v.checked_mul(quote_scale))
                 .ok_or(OracleError::MathOverflow)?;
-            let denominator = (TOKEN_SCALE as u128)
+            let denominator = token_scale
                 .checked_mul(price_scaled as u128)
                 .ok_or(OracleError::MathOverflow)?;
-            require!(denominator > 0, OracleError::DivisionByZero);
-            numerator / denominator
+            div_round(numerator, denominator, Rounding::Down)?
         }
 
         FeedType::CpiIndexed => {
-            // usd_cents = token_amount / TOKEN_SCALE * (cpi_multiplier / CPI_SCALE) * 100
-            // = (token_amount * cpi_multiplier * 100) / (TOKEN_SCALE * CPI_SCALE)
+            // quote = token_amount / TOKEN_SCALE * (cpi_multiplier / CPI_SCALE) * QUOTE_SCALE
+            // = (token_amount * cpi_multiplier * QUOTE_SCALE) / (TOKEN_SCALE * CPI_SCALE)
             require!(cpi_multiplier > 0, OracleError::InvalidCpiMultiplier);
             let numerator   = (token_amount as u128)
                 .checked_mul(cpi_multiplier as u128)
-                .and_then(|v| v.checked_mul(100))
+                .and_then(|v| v.checked_mul(quote_scale))
                 .ok_or(OracleError::MathOverflow)?;
-            let denominator = (TOKEN_SCALE as u128)
+            let denominator = token_scale
                 .checked_mul(CPI_SCALE as u128)
                 .ok_or(OracleError::MathOverflow)?;
-            numerator / denominator
+            div_round(numerator, denominator, Rounding::Down)?
         }
 
         FeedType::Custom { numerator, denominator, base_type } => {
-            require!(*denominator > 0, OracleError::DivisionByZero);
-            let adjusted_price = (price_scaled as u128)
-                .checked_mul(*numerator as u128)
-                .ok_or(OracleError::MathOverflow)?
-                / (*denominator as u128);
+            // Rounded down when the price multiplies the input (Direct), up otherwise
+            let rounding = if *base_type == 0 { Rounding::Down } else { Rounding::Up };
+            let adjusted_price = div_round(
+                (price_scaled as u128)
+                    .checked_mul(*numerator as u128)
+                    .ok_or(OracleError::MathOverflow)?,
+                *denominator as u128,
+                rounding,
+            )?;
             let adjusted_u64 = u64::try_from(adjusted_price)
                 .map_err(|_| error!(OracleError::MathOverflow))?;
 
-            if *base_type == 0 {
-                return calc_usd_for_token_amount(token_amount, adjusted_u64, &FeedType::Direct, cpi_multiplier);
-            } else {
-                return calc_usd_for_token_amount(token_amount, adjusted_u64, &FeedType::Inverse, cpi_multiplier);
-            }
+            let base = if *base_type == 0 { FeedType::Direct } else { FeedType::Inverse };
+            return calc_quote_for_token_amount(
+                token_amount, adjusted_u64, &base, cpi_multiplier,
+                token_decimals, price_decimals, quote_decimals,
+            );
+        }
+
+        FeedType::Composite { base_type, .. } => {
+            let base = if *base_type == 0 { FeedType::Direct } else { FeedType::Inverse };
+            return calc_quote_for_token_amount(
+                token_amount, price_scaled, &base, cpi_multiplier,
+                token_decimals, price_decimals, quote_decimals,
+            );
         }
     };
 
     u64::try_from(result).map_err(|_| error!(OracleError::MathOverflow))
 }
 
+/// One side of a cross-stablecoin swap, or any mint priced by its feed.
+pub struct SwapLeg<'a> {
+    pub price_scaled:   u64,
+    pub feed_type:      &'a FeedType,
+    pub cpi_multiplier: u64,
+    pub token_decimals: u8,
+    pub price_decimals: u8,
+}
+
+impl SwapLeg<'_> {
+    /// Quote-currency value of one whole token as an exact fraction `(num, den)`.
+    ///
+    /// - `Direct`:     price / PRICE_SCALE
+    /// - `Inverse`:    PRICE_SCALE / price
+    /// - `CpiIndexed`: cpi_multiplier / CPI_SCALE
+    /// - `Custom`:     the Direct or Inverse fraction of price * numerator / denominator
+    /// - `Composite`:  the Direct or Inverse fraction of the composed price
+    pub fn usd_per_token(&self) -> Result<(u128, u128)> {
+        require!(self.price_scaled > 0, OracleError::InvalidPrice);
+        let (_, price_scale) = amount_scales(self.token_decimals, self.price_decimals)?;
+        let price = self.price_scaled as u128;
+
+        Ok(match self.feed_type {
+            FeedType::Direct     => (price, price_scale),
+            FeedType::Inverse    => (price_scale, price),
+            FeedType::CpiIndexed => {
+                require!(self.cpi_multiplier > 0, OracleError::InvalidCpiMultiplier);
+                (self.cpi_multiplier as u128, CPI_SCALE as u128)
+            }
+            FeedType::Custom { numerator, denominator, base_type } => {
+                require!(*numerator > 0 && *denominator > 0, OracleError::DivisionByZero);
+                let adjusted = price
+                    .checked_mul(*numerator as u128)
+                    .ok_or(OracleError::MathOverflow)?;
+                let scale = price_scale
+                    .checked_mul(*denominator as u128)
+                    .ok_or(OracleError::MathOverflow)?;
+                if *base_type == 0 { (adjusted, scale) } else { (scale, adjusted) }
+            }
+            FeedType::Composite { base_type, .. } => {
+                if *base_type == 0 { (price, price_scale) } else { (price_scale, price) }
+            }
+        })
+    }
+}
+
+/// Convert `amount_in` base units of the `from` token into base units of the
+/// `to` token at the cross rate of their feeds.
+///
+/// Goes through the input's USD value kept at the output token's decimals:
+///
+/// ```text
+/// usd = amount_in * from_num * 10^to_dec / (from_den * 10^from_dec)   (rounded down)
+/// out = usd * to_den / to_num                                          (rounded down)
+/// ```
+///
+/// Both steps round in the protocol's favour.
+pub fn calc_swap_amount(amount_in: u64, from: &SwapLeg, to: &SwapLeg) -> Result<u64> {
+    let (from_num, from_den) = from.usd_per_token()?;
+    let (to_num, to_den)     = to.usd_per_token()?;
+    let (from_scale, _)      = amount_scales(from.token_decimals, from.price_decimals)?;
+    let (to_scale, _)        = amount_scales(to.token_decimals, to.price_decimals)?;
+
+    let usd = div_round(
+        (amount_in as u128)
+            .checked_mul(from_num)
+            .and_then(|v| v.checked_mul(to_scale))
+            .ok_or(OracleError::MathOverflow)?,
+        from_den.checked_mul(from_scale).ok_or(OracleError::MathOverflow)?,
+        Rounding::Down,
+    )?;
+    let out = div_round(
+        usd.checked_mul(to_den).ok_or(OracleError::MathOverflow)?,
+        to_num,
+        Rounding::Down,
+    )?;
+    u64::try_from(out).map_err(|_| error!(OracleError::MathOverflow))
+}
+
+/// (10^token_decimals, 10^price_decimals), bounded by MAX_AMOUNT_DECIMALS.
+fn amount_scales(token_decimals: u8, price_decimals: u8) -> Result<(u128, u128)> {
+    require!(
+        token_decimals <= MAX_AMOUNT_DECIMALS && price_decimals <= MAX_AMOUNT_DECIMALS,
+        OracleError::UnsupportedDecimals
+    );
+    Ok((pow10(token_decimals as u32)?, pow10(price_decimals as u32)?))
+}
+
+/// 10^quote_decimals, bounded by MAX_AMOUNT_DECIMALS.
+fn quote_scale(quote_decimals: u8) -> Result<u128> {
+    require!(quote_decimals <= MAX_AMOUNT_DECIMALS, OracleError::UnsupportedDecimals);
+    pow10(quote_decimals as u32)
+}
+
 // ─── Fee math ───────────────────────────────────────────────────────────────
 
 /// Apply a basis-point fee to a gross amount.
+/// The fee is rounded up (in the protocol's favour).
 /// Returns (net_amount, fee_amount).
 pub fn apply_fee(gross: u64, fee_bps: u16) -> Result<(u64, u64)> {
-    let fee = (gross as u128)
-        .checked_mul(fee_bps as u128)
-        .ok_or(OracleError::MathOverflow)?
-        / 10_000u128;
+    let fee = div_round(
+        (gross as u128)
+            .checked_mul(fee_bps as u128)
+            .ok_or(OracleError::MathOverflow)?,
+        10_000,
+        Rounding::Up,
+    )?;
     let fee_u64 = u64::try_from(fee).map_err(|_| error!(OracleError::MathOverflow))?;
     let net = gross.checked_sub(fee_u64).ok_or(OracleError::MathOverflow)?;
     Ok((net, fee_u64))
 }
 
+/// `amount * part / whole` — e.g. the token share of a USD-denominated fee.
+pub fn pro_rata(amount: u64, part: u64, whole: u64, rounding: Rounding) -> Result<u64> {
+    let share = div_round(
+        (amount as u128)
+            .checked_mul(part as u128)
+            .ok_or(OracleError::MathOverflow)?,
+        whole as u128,
+        rounding,
+    )?;
+    u64::try_from(share).map_err(|_| error!(OracleError::MathOverflow))
+}
+
+/// Split `amount` across `shares_bps`, each rounded down. Returns the
+/// per-share amounts and the remainder.
+pub fn split_by_bps(amount: u64, shares_bps: &[u16]) -> Result<(Vec<u64>, u64)> {
+    let mut parts     = Vec::with_capacity(shares_bps.len());
+    let mut remainder = amount;
+    for bps in shares_bps {
+        let part  = pro_rata(amount, *bps as u64, 10_000, Rounding::Down)?;
+        remainder = remainder.checked_sub(part).ok_or(OracleError::MathOverflow)?;
+        parts.push(part);
+    }
+    Ok((parts, remainder))
+}
+
+// ─── Peg fee curve ──────────────────────────────────────────────────────────
+
+/// How far the market trades from the oracle's fair value, in bps of the
+/// fair value: `market_price` (quote currency per whole token,
+/// `market_decimals`) against `fair`'s value per token. Negative below peg.
+/// Truncates toward zero.
+pub fn peg_deviation_bps(market_price: u64, market_decimals: u8, fair: &SwapLeg) -> Result<i64> {
+    require!(market_price > 0, OracleError::InvalidPrice);
+    let (fair_num, fair_den) = fair.usd_per_token()?;
+
+    // market / 10^market_decimals vs fair_num / fair_den, over a common denominator
+    let market = (market_price as u128)
+        .checked_mul(fair_den)
+        .ok_or(OracleError::MathOverflow)?;
+    let fair = fair_num
+        .checked_mul(pow10(market_decimals as u32)?)
+        .ok_or(OracleError::MathOverflow)?;
+    require!(fair > 0, OracleError::InvalidPrice);
+
+    let diff = market
+        .abs_diff(fair)
+        .checked_mul(10_000)
+        .ok_or(OracleError::MathOverflow)?;
+    let bps = i64::try_from(div_round(diff, fair, Rounding::Down)?)
+        .map_err(|_| error!(OracleError::MathOverflow))?;
+    Ok(if market < fair { -bps } else { bps })
+}
+
+// ─── Peg stability module ───────────────────────────────────────────────────
+
+/// Base units of a USD-pegged collateral with `decimals` decimals worth
+/// `quote_amount` USD quote units at par:
+/// `quote_amount * 10^decimals / 10^quote_decimals`.
+///
+/// Collateral taken in rounds up and collateral paid out rounds down.
+pub fn quote_to_collateral(
+    quote_amount:   u64,
+    quote_decimals: u8,
+    decimals:       u8,
+    rounding:       Rounding,
+) -> Result<u64> {
+    require!(decimals <= MAX_AMOUNT_DECIMALS, OracleError::UnsupportedDecimals);
+    let scale = pow10(decimals as u32)?;
+    let units = div_round(
+        (quote_amount as u128)
+            .checked_mul(scale)
+            .ok_or(OracleError::MathOverflow)?,
+        quote_scale(quote_decimals)?,
+        rounding,
+    )?;
+    u64::try_from(units).map_err(|_| error!(OracleError::MathOverflow))
+}
+
 // ─── Validation helpers ─────────────────────────────────────────────────────
 
 /// Reject if the price feed's last update is older than max_staleness.
@@ -200,25 +541,251 @@ pub fn validate_confidence(price_scaled: u64, confidence_scaled: u64, max_confid
         // 0 means no confidence check
         return Ok(());
     }
-    // confidence_pct_bps = (confidence / price) * 10_000
-    let conf_bps = (confidence_scaled as u128)
-        .checked_mul(10_000)
-        .ok_or(OracleError::MathOverflow)?
-        / (price_scaled as u128);
+    // confidence_pct_bps = (confidence / price) * 10_000, rounded up
+    let conf_bps = confidence_bps(price_scaled, confidence_scaled)?;
 
     require!(
-        conf_bps <= max_confidence_bps as u128,
+        conf_bps <= max_confidence_bps as u64,
         OracleError::ConfidenceTooWide
     );
     Ok(())
 }
 
+// ─── Circuit breaker ────────────────────────────────────────────────────────
+
+/// Move from `reference` to `price`, in bps of `reference`, rounded up.
+pub fn price_move_bps(reference: u64, price: u64) -> Result<u64> {
+    let moved = div_round(
+        (reference.abs_diff(price) as u128)
+            .checked_mul(10_000)
+            .ok_or(OracleError::MathOverflow)?,
+        reference as u128,
+        Rounding::Up,
+    )?;
+    u64::try_from(moved).map_err(|_| error!(OracleError::MathOverflow))
+}
+
+/// Band allowed `elapsed` seconds after the last good price: `max_move_bps`
+/// for every started `window_secs`, and never less than one window.
+pub fn allowed_move_bps(max_move_bps: u16, window_secs: i64, elapsed: i64) -> Result<u64> {
+    require!(window_secs > 0, OracleError::DivisionByZero);
+    let windows = div_round(elapsed.max(0) as u128, window_secs as u128, Rounding::Up)?.max(1);
+    let allowed = windows
+        .checked_mul(max_move_bps as u128)
+        .ok_or(OracleError::MathOverflow)?;
+    Ok(u64::try_from(allowed).unwrap_or(u64::MAX))
+}
+
+// ─── Time-weighted pricing ──────────────────────────────────────────────────
+
+/// Average price between two cumulative readings `dt` seconds apart.
+/// Cumulative values are `Σ price_scaled * seconds`.
+pub fn twap_from_cumulative(cum_end: u128, cum_start: u128, dt: i64, rounding: Rounding) -> Result<u64> {
+    require!(dt > 0, OracleError::DivisionByZero);
+    let delta = cum_end.checked_sub(cum_start).ok_or(OracleError::MathOverflow)?;
+    let twap  = div_round(delta, dt as u128, rounding)?;
+    u64::try_from(twap).map_err(|_| error!(OracleError::MathOverflow))
+}
+
+/// One EMA step: move `ema` toward `price` by `dt / period` (capped at 1).
+/// A zero `ema` is seeded with `price`.
+pub fn ema_step(ema: u64, price: u64, dt: i64, period_secs: i64) -> Result<u64> {
+    require!(period_secs > 0, OracleError::DivisionByZero);
+    if ema == 0 || dt >= period_secs {
+        return Ok(price);
+    }
+    let step = div_round(
+        (ema.abs_diff(price) as u128)
+            .checked_mul(dt.max(0) as u128)
+            .ok_or(OracleError::MathOverflow)?,
+        period_secs as u128,
+        Rounding::Down,
+    )? as u64; // ≤ |price − ema|
+    Ok(if price >= ema { ema + step } else { ema - step })
+}
+
+/// Of two candidate prices, the one that favours the protocol for a quote
+/// priced with `rounding` (see `price_rounding`): the higher one when prices
+/// round up, the lower one when they round down.
+pub fn conservative_price(a: u64, b: u64, rounding: Rounding) -> u64 {
+    match rounding {
+        Rounding::Up   => a.max(b),
+        Rounding::Down => a.min(b),
+    }
+}
+
+// ─── CPI accrual ────────────────────────────────────────────────────────────
+
+/// Value on the straight line from `from` at `start` to `to` at `end`,
+/// evaluated at `now`. Clamped to `from` before `start` and to `to` from
+/// `end` on; an empty window (`end <= start`) is a step to `to`. Values
+/// between the ends are rounded with `rounding`.
+pub fn interpolate_linear(
+    from:     u64,
+    to:       u64,
+    start:    i64,
+    end:      i64,
+    now:      i64,
+    rounding: Rounding,
+) -> Result<u64> {
+    if now >= end || end <= start {
+        return Ok(to);
+    }
+    if now <= start {
+        return Ok(from);
+    }
+    // (from × remaining + to × elapsed) / window, a weighted mean of the ends
+    let window   = (end - start) as u128;
+    let weighted = (from as u128)
+        .checked_mul((end - now) as u128)
+        .and_then(|a| a.checked_add((to as u128).checked_mul((now - start) as u128)?))
+        .ok_or(OracleError::MathOverflow)?;
+    let value = div_round(weighted, window, rounding)?;
+    u64::try_from(value).map_err(|_| error!(OracleError::MathOverflow))
+}
+
+// ─── Issuance caps ──────────────────────────────────────────────────────────
+
+/// Volume over the trailing `window` seconds from a sliding-window counter:
+/// all of the current window's `current` plus the share of the previous
+/// window's `previous` that still falls in the trailing window, assuming it
+/// was spread evenly. `elapsed` is the time since the current window began.
+/// Rounds up, so a cap is never under-counted.
+pub fn sliding_window_usage(current: u64, previous: u64, elapsed: i64, window: i64) -> Result<u64> {
+    require!(window > 0, OracleError::DivisionByZero);
+    let remaining = window.saturating_sub(elapsed.clamp(0, window)) as u128;
+    let carried   = div_round(
+        (previous as u128).checked_mul(remaining).ok_or(OracleError::MathOverflow)?,
+        window as u128,
+        Rounding::Up,
+    )?;
+    let carried = u64::try_from(carried).map_err(|_| error!(OracleError::MathOverflow))?;
+    current.checked_add(carried).ok_or_else(|| error!(OracleError::MathOverflow))
+}
+
+// ─── Multi-source aggregation ───────────────────────────────────────────────
+
+/// Median of `values` (sorted in place). An even count averages the two middle
+/// values using `rounding`.
+pub fn median(values: &mut [u64], rounding: Rounding) -> Result<u64> {
+    require!(!values.is_empty(), OracleError::InsufficientSources);
+    values.sort_unstable();
+    let mid = values.len() / 2;
+    if values.len() % 2 == 1 {
+        return Ok(values[mid]);
+    }
+    let sum = (values[mid - 1] as u128) + (values[mid] as u128);
+    let avg = div_round(sum, 2, rounding)?;
+    u64::try_from(avg).map_err(|_| error!(OracleError::MathOverflow))
+}
+
+/// Spread between the lowest and highest reading, in bps of the median (rounded up).
+pub fn spread_bps(min: u64, max: u64, median: u64) -> Result<u64> {
+    let spread = div_round(
+        (max.saturating_sub(min) as u128)
+            .checked_mul(10_000)
+            .ok_or(OracleError::MathOverflow)?,
+        median as u128,
+        Rounding::Up,
+    )?;
+    u64::try_from(spread).map_err(|_| error!(OracleError::MathOverflow))
+}
+
+/// Confidence interval as bps of the price (rounded up).
+pub fn confidence_bps(price_scaled: u64, confidence_scaled: u64) -> Result<u64> {
+    let conf_bps = div_round(
+        (confidence_scaled as u128)
+            .checked_mul(10_000)
+            .ok_or(OracleError::MathOverflow)?,
+        price_scaled as u128,
+        Rounding::Up,
+    )?;
+    u64::try_from(conf_bps).map_err(|_| error!(OracleError::MathOverflow))
+}
+
+// ─── Composite feeds ────────────────────────────────────────────────────────
+
+/// One leg's median price, as it enters a composite feed.
+pub struct LegPrice {
+    /// Leg median (fixed-point, `decimals` decimals)
+    pub price_scaled: u64,
+    pub decimals:     u8,
+    pub op:           CompositeOp,
+}
+
+/// Compose leg prices into a composite price with `decimals` decimals:
+/// start from 1 and multiply or divide by each leg in order, rounding every
+/// step with `rounding`.
+///
+/// For the result to round in one direction overall, `Divide` leg prices
+/// must themselves have been rounded the other way (`Rounding::reversed`).
+pub fn compose_price(legs: &[LegPrice], decimals: u8, rounding: Rounding) -> Result<u64> {
+    require!(decimals <= MAX_AMOUNT_DECIMALS, OracleError::UnsupportedDecimals);
+    let mut price = 10u128.pow(decimals as u32);
+
+    for leg in legs {
+        require!(leg.price_scaled > 0, OracleError::InvalidPrice);
+        require!(leg.decimals <= MAX_AMOUNT_DECIMALS, OracleError::UnsupportedDecimals);
+        let leg_scale = 10u128.pow(leg.decimals as u32);
+        let leg_price = leg.price_scaled as u128;
+
+        price = match leg.op {
+            CompositeOp::Multiply => div_round(
+                price.checked_mul(leg_price).ok_or(OracleError::MathOverflow)?,
+                leg_scale,
+                rounding,
+            )?,
+            CompositeOp::Divide => div_round(
+                price.checked_mul(leg_scale).ok_or(OracleError::MathOverflow)?,
+                leg_price,
+                rounding,
+            )?,
+        };
+        require!(price > 0, OracleError::InvalidPrice);
+    }
+
+    u64::try_from(price).map_err(|_| error!(OracleError::MathOverflow))
+}
+
+/// Confidence of a product or quotient of prices, in bps: to first order the
+/// legs' relative confidences add up, whether a leg multiplies or divides.
+pub fn combined_confidence_bps(leg_confidence_bps: &[u64]) -> Result<u64> {
+    leg_confidence_bps
+        .iter()
+        .try_fold(0u64, |acc, bps| acc.checked_add(*bps))
+        .ok_or_else(|| error!(OracleError::MathOverflow))
+}
+
 /// Check that output meets the minimum expected (slippage protection).
 pub fn check_slippage(output: u64, min_output: u64) -> Result<()> {
     require!(output >= min_output, OracleError::SlippageExceeded);
     Ok(())
 }
 
+/// All-in price of a mint: quote currency per whole token at PRICE_SCALE,
+/// fee included, for `quote_amount` paid and `net_tokens` base units
+/// received. Independent of the feed type, so one bound works for every feed.
+/// Rounds up, against the minter.
+pub fn effective_mint_price(
+    quote_amount:   u64,
+    net_tokens:     u64,
+    token_decimals: u8,
+    quote_decimals: u8,
+) -> Result<u64> {
+    require!(net_tokens > 0, OracleError::ZeroOutput);
+    require!(token_decimals <= MAX_AMOUNT_DECIMALS, OracleError::UnsupportedDecimals);
+
+    let numerator = (quote_amount as u128)
+        .checked_mul(pow10(token_decimals as u32)?)
+        .and_then(|v| v.checked_mul(PRICE_SCALE as u128))
+        .ok_or(OracleError::MathOverflow)?;
+    let denominator = quote_scale(quote_decimals)?
+        .checked_mul(net_tokens as u128)
+        .ok_or(OracleError::MathOverflow)?;
+    let price = div_round(numerator, denominator, Rounding::Up)?;
+    u64::try_from(price).map_err(|_| error!(OracleError::MathOverflow))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -228,7 +795,7 @@ mod tests {
         // EUR/USD = 1.08 → deposit $108 → get 100 EUR tokens
         let price = 1_080_000u64; // 1.08 * PRICE_SCALE
         let usd = 10_800u64;      // $108.00 in cents
-        let tokens = calc_token_amount_for_usd(usd, price, &FeedType::Direct, CPI_SCALE).unwrap();
+        let tokens = calc_token_amount_for_quote(usd, price, &FeedType::Direct, CPI_SCALE, TOKEN_DECIMALS, PRICE_DECIMALS, QUOTE_DECIMALS).unwrap();
         assert_eq!(tokens, 100_000_000); // 100 tokens * TOKEN_SCALE
     }
 
@@ -237,7 +804,7 @@ mod tests {
         // BRL/USD = 5.72 → deposit $100 → get 572 BRL tokens
         let price = 5_720_000u64; // 5.72 * PRICE_SCALE
         let usd = 10_000u64;      // $100.00 in cents
-        let tokens = calc_token_amount_for_usd(usd, price, &FeedType::Inverse, CPI_SCALE).unwrap();
+        let tokens = calc_token_amount_for_quote(usd, price, &FeedType::Inverse, CPI_SCALE, TOKEN_DECIMALS, PRICE_DECIMALS, QUOTE_DECIMALS).unwrap();
         assert_eq!(tokens, 572_000_000); // 572 tokens * TOKEN_SCALE
     }
 
@@ -246,7 +813,7 @@ mod tests {
         // CPI multiplier = 1.083 → deposit $108.30 → get 100 tokens
         let cpi_mult = 1_083_000u64; // 1.083 * CPI_SCALE
         let usd = 10_830u64;         // $108.30 in cents
-        let tokens = calc_token_amount_for_usd(usd, PRICE_SCALE, &FeedType::CpiIndexed, cpi_mult).unwrap();
+        let tokens = calc_token_amount_for_quote(usd, PRICE_SCALE, &FeedType::CpiIndexed, cpi_mult, TOKEN_DECIMALS, PRICE_DECIMALS, QUOTE_DECIMALS).unwrap();
         assert_eq!(tokens, 100_000_000); // 100 tokens
     }
 
@@ -261,17 +828,439 @@ mod tests {
     fn test_roundtrip_direct() {
         let price = 1_080_000u64;
         let usd = 10_800u64;
-        let tokens = calc_token_amount_for_usd(usd, price, &FeedType::Direct, CPI_SCALE).unwrap();
-        let usd_back = calc_usd_for_token_amount(tokens, price, &FeedType::Direct, CPI_SCALE).unwrap();
+        let tokens = calc_token_amount_for_quote(usd, price, &FeedType::Direct, CPI_SCALE, TOKEN_DECIMALS, PRICE_DECIMALS, QUOTE_DECIMALS).unwrap();
+        let usd_back = calc_quote_for_token_amount(tokens, price, &FeedType::Direct, CPI_SCALE, TOKEN_DECIMALS, PRICE_DECIMALS, QUOTE_DECIMALS).unwrap();
         assert_eq!(usd_back, usd);
     }
 
+    #[test]
+    fn test_apply_fee_rounds_up() {
+        // 30 bps of 1_001 = 3.003 → fee rounds up to 4
+        let (net, fee) = apply_fee(1_001, 30).unwrap();
+        assert_eq!(fee, 4);
+        assert_eq!(net, 997);
+    }
+
+    #[test]
+    fn test_rescale_down_rounding_modes() {
+        // 5.7200004 at 7 decimals → 6 decimals
+        let d = ScaledDecimal::new(57_200_004, 7);
+        assert_eq!(d.to_price_scaled(Rounding::Down).unwrap(), 5_720_000);
+        assert_eq!(d.to_price_scaled(Rounding::Up).unwrap(), 5_720_001);
+    }
+
+    #[test]
+    fn test_rescale_exact_is_mode_independent() {
+        // On-Demand precision: 1.08 * 10^18
+        let d = ScaledDecimal::new(1_080_000_000_000_000_000, 18);
+        assert_eq!(d.to_price_scaled(Rounding::Down).unwrap(), 1_080_000);
+        assert_eq!(d.to_price_scaled(Rounding::Up).unwrap(), 1_080_000);
+    }
+
+    #[test]
+    fn test_rescale_up_scale() {
+        // 572 / 10^2 = 5.72 → 5_720_000
+        let d = ScaledDecimal::new(572, 2);
+        assert_eq!(d.to_price_scaled(Rounding::Down).unwrap(), 5_720_000);
+    }
+
+    #[test]
+    fn test_rescale_rejects_negative() {
+        let d = ScaledDecimal::new(-1, 0);
+        assert!(d.to_price_scaled(Rounding::Down).is_err());
+        assert_eq!(d.abs().to_price_scaled(Rounding::Down).unwrap(), PRICE_SCALE);
+    }
+
+    #[test]
+    fn test_div_round() {
+        assert_eq!(div_round(10, 3, Rounding::Down).unwrap(), 3);
+        assert_eq!(div_round(10, 3, Rounding::Up).unwrap(), 4);
+        assert_eq!(div_round(9, 3, Rounding::Up).unwrap(), 3);
+        assert!(div_round(1, 0, Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn test_price_rounding_favours_protocol() {
+        assert_eq!(price_rounding(&FeedType::Direct, &QuoteDirection::Mint), Rounding::Up);
+        assert_eq!(price_rounding(&FeedType::Inverse, &QuoteDirection::Mint), Rounding::Down);
+        assert_eq!(price_rounding(&FeedType::Direct, &QuoteDirection::Redeem), Rounding::Down);
+        assert_eq!(price_rounding(&FeedType::Inverse, &QuoteDirection::Redeem), Rounding::Up);
+    }
+
+    #[test]
+    fn test_direct_mint_rounds_down() {
+        // EUR/USD = 1.08, $1.00 → 0.925925… tokens → 925_925 base units
+        let tokens = calc_token_amount_for_quote(100, 1_080_000, &FeedType::Direct, CPI_SCALE, TOKEN_DECIMALS, PRICE_DECIMALS, QUOTE_DECIMALS).unwrap();
+        assert_eq!(tokens, 925_925);
+    }
+
+    #[test]
+    fn test_median_odd() {
+        let mut v = [5_730_000, 5_700_000, 5_720_000];
+        assert_eq!(median(&mut v, Rounding::Down).unwrap(), 5_720_000);
+    }
+
+    #[test]
+    fn test_median_even_rounding() {
+        let mut v = [1_080_001, 1_080_000];
+        assert_eq!(median(&mut v, Rounding::Down).unwrap(), 1_080_000);
+        assert_eq!(median(&mut v, Rounding::Up).unwrap(), 1_080_001);
+    }
+
+    #[test]
+    fn test_median_empty() {
+        assert!(median(&mut [], Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn test_spread_bps() {
+        // 5.70 .. 5.73 around 5.72 → 0.03 / 5.72 = 52.4 bps → 53
+        assert_eq!(spread_bps(5_700_000, 5_730_000, 5_720_000).unwrap(), 53);
+        assert_eq!(spread_bps(1_000_000, 1_000_000, 1_000_000).unwrap(), 0);
+    }
+
     #[test]
     fn test_roundtrip_inverse() {
         let price = 5_720_000u64;
         let usd = 10_000u64;
-        let tokens = calc_token_amount_for_usd(usd, price, &FeedType::Inverse, CPI_SCALE).unwrap();
-        let usd_back = calc_usd_for_token_amount(tokens, price, &FeedType::Inverse, CPI_SCALE).unwrap();
+        let tokens = calc_token_amount_for_quote(usd, price, &FeedType::Inverse, CPI_SCALE, TOKEN_DECIMALS, PRICE_DECIMALS, QUOTE_DECIMALS).unwrap();
+        let usd_back = calc_quote_for_token_amount(tokens, price, &FeedType::Inverse, CPI_SCALE, TOKEN_DECIMALS, PRICE_DECIMALS, QUOTE_DECIMALS).unwrap();
         assert_eq!(usd_back, usd);
     }
+
+    #[test]
+    fn test_price_move_bps() {
+        // 5.72 → 7.436 is a 30% jump
+        assert_eq!(price_move_bps(5_720_000, 7_436_000).unwrap(), 3_000);
+        assert_eq!(price_move_bps(5_720_000, 4_004_000).unwrap(), 3_000);
+        // 1 unit on 5.72 rounds up to 1 bps
+        assert_eq!(price_move_bps(5_720_000, 5_720_001).unwrap(), 1);
+        assert_eq!(price_move_bps(5_720_000, 5_720_000).unwrap(), 0);
+        assert!(price_move_bps(0, 1).is_err());
+    }
+
+    #[test]
+    fn test_allowed_move_bps_per_window() {
+        // 500 bps per 5 minutes
+        assert_eq!(allowed_move_bps(500, 300, 0).unwrap(), 500);
+        assert_eq!(allowed_move_bps(500, 300, 300).unwrap(), 500);
+        assert_eq!(allowed_move_bps(500, 300, 301).unwrap(), 1_000);
+        assert_eq!(allowed_move_bps(500, 300, 3_600).unwrap(), 6_000);
+        assert!(allowed_move_bps(500, 0, 10).is_err());
+    }
+
+    #[test]
+    fn test_twap_from_cumulative() {
+        // 5.70 for 60s then 5.76 for 30s → 5.72
+        let cum = 5_700_000u128 * 60 + 5_760_000u128 * 30;
+        assert_eq!(twap_from_cumulative(cum, 0, 90, Rounding::Down).unwrap(), 5_720_000);
+        // 10 / 3 seconds rounds by mode
+        assert_eq!(twap_from_cumulative(10, 0, 3, Rounding::Down).unwrap(), 3);
+        assert_eq!(twap_from_cumulative(10, 0, 3, Rounding::Up).unwrap(), 4);
+        assert!(twap_from_cumulative(10, 0, 0, Rounding::Down).is_err());
+        assert!(twap_from_cumulative(0, 10, 5, Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn test_ema_step() {
+        // Seeded by the first price
+        assert_eq!(ema_step(0, 5_720_000, 0, 600).unwrap(), 5_720_000);
+        // A quarter of the period moves a quarter of the way, both directions
+        assert_eq!(ema_step(5_000_000, 6_000_000, 150, 600).unwrap(), 5_250_000);
+        assert_eq!(ema_step(6_000_000, 5_000_000, 150, 600).unwrap(), 5_750_000);
+        // A full period or more jumps to the price
+        assert_eq!(ema_step(5_000_000, 6_000_000, 600, 600).unwrap(), 6_000_000);
+        assert!(ema_step(5_000_000, 6_000_000, 10, 0).is_err());
+    }
+
+    #[test]
+    fn test_conservative_price() {
+        assert_eq!(conservative_price(5_700_000, 5_720_000, Rounding::Up), 5_720_000);
+        assert_eq!(conservative_price(5_700_000, 5_720_000, Rounding::Down), 5_700_000);
+    }
+
+    #[test]
+    fn test_pro_rata_fee_tokens() {
+        // 1000 tokens for $10.00 gross with a $0.03 fee → 3 tokens
+        assert_eq!(pro_rata(1_000_000_000, 3, 1_000, Rounding::Up).unwrap(), 3_000_000);
+        // 10 tokens, fee 1 of 3 → 3.33 rounds by mode
+        assert_eq!(pro_rata(10, 1, 3, Rounding::Up).unwrap(), 4);
+        assert_eq!(pro_rata(10, 1, 3, Rounding::Down).unwrap(), 3);
+        assert!(pro_rata(10, 1, 0, Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn test_split_by_bps() {
+        // 30% / 20% of 1001, the rest to the treasury
+        let (parts, rest) = split_by_bps(1_001, &[3_000, 2_000]).unwrap();
+        assert_eq!(parts, vec![300, 200]);
+        assert_eq!(rest, 501);
+        let (parts, rest) = split_by_bps(1_000, &[]).unwrap();
+        assert!(parts.is_empty());
+        assert_eq!(rest, 1_000);
+    }
+
+    // ── Non-6-decimal mints and feeds ──
+
+    #[test]
+    fn test_direct_decimals() {
+        // EUR/USD = 1.08 at 8 feed decimals; $108.00 → 100 tokens
+        let price = 108_000_000u64;
+        let tokens = calc_token_amount_for_quote(10_800, price, &FeedType::Direct, CPI_SCALE, 2, 8, QUOTE_DECIMALS).unwrap();
+        assert_eq!(tokens, 10_000);
+        let tokens = calc_token_amount_for_quote(10_800, price, &FeedType::Direct, CPI_SCALE, 9, 8, QUOTE_DECIMALS).unwrap();
+        assert_eq!(tokens, 100_000_000_000);
+        let usd = calc_quote_for_token_amount(100_000_000_000, price, &FeedType::Direct, CPI_SCALE, 9, 8, QUOTE_DECIMALS).unwrap();
+        assert_eq!(usd, 10_800);
+    }
+
+    #[test]
+    fn test_inverse_decimals() {
+        // BRL/USD = 5.72 at 8 feed decimals; $100.00 → 572 tokens
+        let price = 572_000_000u64;
+        let tokens = calc_token_amount_for_quote(10_000, price, &FeedType::Inverse, CPI_SCALE, 2, 8, QUOTE_DECIMALS).unwrap();
+        assert_eq!(tokens, 57_200);
+        let tokens = calc_token_amount_for_quote(10_000, price, &FeedType::Inverse, CPI_SCALE, 9, 8, QUOTE_DECIMALS).unwrap();
+        assert_eq!(tokens, 572_000_000_000);
+        let usd = calc_quote_for_token_amount(57_200, price, &FeedType::Inverse, CPI_SCALE, 2, 8, QUOTE_DECIMALS).unwrap();
+        assert_eq!(usd, 10_000);
+    }
+
+    #[test]
+    fn test_cpi_indexed_decimals() {
+        // CPI 1.083 ignores the feed price and its decimals
+        let cpi_mult = 1_083_000u64;
+        let two  = calc_token_amount_for_quote(10_000, 1, &FeedType::CpiIndexed, cpi_mult, 2, 8, QUOTE_DECIMALS).unwrap();
+        let nine = calc_token_amount_for_quote(10_000, 1, &FeedType::CpiIndexed, cpi_mult, 9, 0, QUOTE_DECIMALS).unwrap();
+        assert_eq!(two, 9_233);               // 92.33 tokens
+        assert_eq!(nine, 92_336_103_416);     // 92.336103416 tokens
+        let usd = calc_quote_for_token_amount(nine, 1, &FeedType::CpiIndexed, cpi_mult, 9, 0, QUOTE_DECIMALS).unwrap();
+        assert_eq!(usd, 9_999);               // rounded down twice
+    }
+
+    #[test]
+    fn test_custom_decimals() {
+        // JPY quoted per 100 units: feed 0.6700 at 4 decimals, scaled ×100 → 67 JPY/USD
+        let feed_type = FeedType::Custom { numerator: 100, denominator: 1, base_type: 1 };
+        let tokens = calc_token_amount_for_quote(100, 6_700, &feed_type, CPI_SCALE, 0, 4, QUOTE_DECIMALS).unwrap();
+        assert_eq!(tokens, 67);
+        let tokens = calc_token_amount_for_quote(100, 6_700, &feed_type, CPI_SCALE, 9, 4, QUOTE_DECIMALS).unwrap();
+        assert_eq!(tokens, 67_000_000_000);
+        let usd = calc_quote_for_token_amount(67_000_000_000, 6_700, &feed_type, CPI_SCALE, 9, 4, QUOTE_DECIMALS).unwrap();
+        assert_eq!(usd, 100);
+    }
+
+    #[test]
+    fn test_decimals_bounds() {
+        assert!(calc_token_amount_for_quote(100, 1_000_000, &FeedType::Direct, CPI_SCALE, 13, 6, QUOTE_DECIMALS).is_err());
+        assert!(calc_quote_for_token_amount(100, 1_000_000, &FeedType::Direct, CPI_SCALE, 6, 13, QUOTE_DECIMALS).is_err());
+        assert!(calc_token_amount_for_quote(100, 1_000_000, &FeedType::Direct, CPI_SCALE, 6, 6, 13).is_err());
+    }
+
+    #[test]
+    fn test_quote_decimals() {
+        // Micro-unit quotes: 108.000000 in → 100 tokens at 1.08, same as 10_800 cents
+        let micros = calc_token_amount_for_quote(108_000_000, 1_080_000, &FeedType::Direct, CPI_SCALE, 6, 6, 6).unwrap();
+        let cents  = calc_token_amount_for_quote(10_800, 1_080_000, &FeedType::Direct, CPI_SCALE, 6, 6, QUOTE_DECIMALS).unwrap();
+        assert_eq!(micros, cents);
+        // Sub-cent inputs quote instead of rounding to nothing: 0.005 at par → 0.005 tokens
+        assert_eq!(calc_token_amount_for_quote(5_000, 1_000_000, &FeedType::Direct, CPI_SCALE, 6, 6, 6).unwrap(), 5_000);
+        assert_eq!(calc_quote_for_token_amount(5_000, 1_000_000, &FeedType::Direct, CPI_SCALE, 6, 6, QUOTE_DECIMALS).unwrap(), 0);
+        // Redeem output carries the same decimals
+        assert_eq!(calc_quote_for_token_amount(100_000_000, 1_080_000, &FeedType::Direct, CPI_SCALE, 6, 6, 6).unwrap(), 108_000_000);
+        assert_eq!(effective_mint_price(108_000_000, 100_000_000, 6, 6).unwrap(), 1_080_000);
+    }
+
+    // ── CPI accrual ──
+
+    #[test]
+    fn test_interpolate_linear() {
+        // 1.000 → 1.010 over 100s
+        let (from, to) = (1_000_000u64, 1_010_000u64);
+        assert_eq!(interpolate_linear(from, to, 100, 200, 50, Rounding::Down).unwrap(), from);
+        assert_eq!(interpolate_linear(from, to, 100, 200, 100, Rounding::Down).unwrap(), from);
+        assert_eq!(interpolate_linear(from, to, 100, 200, 150, Rounding::Down).unwrap(), 1_005_000);
+        assert_eq!(interpolate_linear(from, to, 100, 200, 200, Rounding::Down).unwrap(), to);
+        assert_eq!(interpolate_linear(from, to, 100, 200, 300, Rounding::Down).unwrap(), to);
+        // Empty window steps straight to the target
+        assert_eq!(interpolate_linear(from, to, 100, 100, 100, Rounding::Down).unwrap(), to);
+    }
+
+    #[test]
+    fn test_interpolate_linear_rounding() {
+        // 1 unit over 3s: after 1s the exact value is from + 1/3
+        assert_eq!(interpolate_linear(10, 11, 0, 3, 1, Rounding::Down).unwrap(), 10);
+        assert_eq!(interpolate_linear(10, 11, 0, 3, 1, Rounding::Up).unwrap(), 11);
+        // Decreasing schedule: exact value is 11 − 1/3
+        assert_eq!(interpolate_linear(11, 10, 0, 3, 1, Rounding::Down).unwrap(), 10);
+        assert_eq!(interpolate_linear(11, 10, 0, 3, 1, Rounding::Up).unwrap(), 11);
+    }
+
+    // ── Cross-stablecoin swaps ──
+
+    fn leg(price_scaled: u64, feed_type: &FeedType, token_decimals: u8, price_decimals: u8) -> SwapLeg<'_> {
+        SwapLeg { price_scaled, feed_type, cpi_multiplier: CPI_SCALE, token_decimals, price_decimals }
+    }
+
+    #[test]
+    fn test_swap_brl_to_eur() {
+        // 572 BRL at 5.72 BRL/USD = $100 → at 1.08 USD/EUR = 92.592592 EUR
+        let brl = leg(5_720_000, &FeedType::Inverse, 6, 6);
+        let eur = leg(1_080_000, &FeedType::Direct, 6, 6);
+        assert_eq!(calc_swap_amount(572_000_000, &brl, &eur).unwrap(), 92_592_592);
+        // And back: 92.592592 EUR → 571.999994 BRL (both steps round down)
+        assert_eq!(calc_swap_amount(92_592_592, &eur, &brl).unwrap(), 571_999_994);
+    }
+
+    #[test]
+    fn test_swap_decimals() {
+        // Same swap with a 2-decimal BRL mint, an 8-decimal EUR feed and a 9-decimal EUR mint
+        let brl = leg(5_720_000, &FeedType::Inverse, 2, 6);
+        let eur = leg(108_000_000, &FeedType::Direct, 9, 8);
+        assert_eq!(calc_swap_amount(57_200, &brl, &eur).unwrap(), 92_592_592_592);
+    }
+
+    #[test]
+    fn test_swap_cpi_and_custom() {
+        // 100 CPI tokens at 1.083 = $108.30 → EUR at 1.08 = 100.277777 EUR
+        let cpi_type = FeedType::CpiIndexed;
+        let cpi = SwapLeg { cpi_multiplier: 1_083_000, ..leg(1, &cpi_type, 6, 0) };
+        let eur = leg(1_080_000, &FeedType::Direct, 6, 6);
+        assert_eq!(calc_swap_amount(100_000_000, &cpi, &eur).unwrap(), 100_277_777);
+
+        // Custom ×100 inverse (JPY per 100 USD-cents style feed) matches plain Inverse
+        let custom_type = FeedType::Custom { numerator: 100, denominator: 1, base_type: 1 };
+        let custom = leg(1_500_000, &custom_type, 6, 6);       // 1.5 × 100 = 150 JPY/USD
+        let plain  = leg(150_000_000, &FeedType::Inverse, 6, 6);
+        assert_eq!(
+            calc_swap_amount(1_000_000, &eur, &custom).unwrap(),
+            calc_swap_amount(1_000_000, &eur, &plain).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_swap_rejects_zero_price() {
+        let eur  = leg(1_080_000, &FeedType::Direct, 6, 6);
+        let zero = leg(0, &FeedType::Direct, 6, 6);
+        assert!(calc_swap_amount(1_000_000, &eur, &zero).is_err());
+        assert!(calc_swap_amount(1_000_000, &zero, &eur).is_err());
+    }
+
+    // ── Composite feeds ──
+
+    fn leg_price(price_scaled: u64, decimals: u8, op: CompositeOp) -> LegPrice {
+        LegPrice { price_scaled, decimals, op }
+    }
+
+    #[test]
+    fn test_compose_eur_from_brl_cross() {
+        // EURBRL 6.1776 ÷ BRLUSD 5.72 = 1.08 USD per EUR
+        let legs = [
+            leg_price(6_177_600, 6, CompositeOp::Multiply),
+            leg_price(572_000_000, 8, CompositeOp::Divide),
+        ];
+        assert_eq!(compose_price(&legs, 6, Rounding::Down).unwrap(), 1_080_000);
+        assert_eq!(compose_price(&legs, 6, Rounding::Up).unwrap(), 1_080_000);
+        // Any output precision
+        assert_eq!(compose_price(&legs, 8, Rounding::Down).unwrap(), 108_000_000);
+
+        // And the composite prices a mint like the equivalent Direct feed
+        let composite = FeedType::Composite { legs: vec![], base_type: 0 };
+        assert_eq!(
+            calc_token_amount_for_quote(10_800, 1_080_000, &composite, CPI_SCALE, TOKEN_DECIMALS, PRICE_DECIMALS, QUOTE_DECIMALS).unwrap(),
+            calc_token_amount_for_quote(10_800, 1_080_000, &FeedType::Direct, CPI_SCALE, TOKEN_DECIMALS, PRICE_DECIMALS, QUOTE_DECIMALS).unwrap(),
+        );
+        assert_eq!(price_rounding(&composite, &QuoteDirection::Mint), Rounding::Up);
+    }
+
+    #[test]
+    fn test_compose_rounding() {
+        // 1 ÷ 3 at 6 decimals
+        let legs = [leg_price(3_000_000, 6, CompositeOp::Divide)];
+        assert_eq!(compose_price(&legs, 6, Rounding::Down).unwrap(), 333_333);
+        assert_eq!(compose_price(&legs, 6, Rounding::Up).unwrap(), 333_334);
+        assert_eq!(Rounding::Up.reversed(), Rounding::Down);
+    }
+
+    #[test]
+    fn test_compose_rejects_zero_and_overflow() {
+        assert!(compose_price(&[leg_price(0, 6, CompositeOp::Multiply)], 6, Rounding::Down).is_err());
+        // Result below one unit of the output precision
+        assert!(compose_price(&[leg_price(u64::MAX, 0, CompositeOp::Divide)], 6, Rounding::Down).is_err());
+        // Result beyond u64
+        assert!(compose_price(&[leg_price(u64::MAX, 0, CompositeOp::Multiply)], 6, Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn test_combined_confidence() {
+        assert_eq!(combined_confidence_bps(&[12, 30, 5]).unwrap(), 47);
+        assert_eq!(combined_confidence_bps(&[]).unwrap(), 0);
+        assert!(combined_confidence_bps(&[u64::MAX, 1]).is_err());
+    }
+
+    // ── Peg stability module ──
+
+    #[test]
+    fn test_quote_to_collateral() {
+        // $108.00 of USDC (6 decimals)
+        assert_eq!(quote_to_collateral(10_800, QUOTE_DECIMALS, 6, Rounding::Down).unwrap(), 108_000_000);
+        assert_eq!(quote_to_collateral(10_800, QUOTE_DECIMALS, 6, Rounding::Up).unwrap(), 108_000_000);
+        // Below-cent collateral precision rounds in the protocol's favour
+        assert_eq!(quote_to_collateral(150, QUOTE_DECIMALS, 0, Rounding::Down).unwrap(), 1);
+        assert_eq!(quote_to_collateral(150, QUOTE_DECIMALS, 0, Rounding::Up).unwrap(), 2);
+        assert!(quote_to_collateral(1, QUOTE_DECIMALS, 13, Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn test_effective_mint_price() {
+        // $108.00 for 100 EUR tokens is 1.08 USD/EUR
+        assert_eq!(effective_mint_price(10_800, 100_000_000, 6, QUOTE_DECIMALS).unwrap(), 1_080_000);
+        // The fee raises the all-in price: 99.7 tokens net → 1.08325 (rounded up)
+        assert_eq!(effective_mint_price(10_800, 99_700_000, 6, QUOTE_DECIMALS).unwrap(), 1_083_250);
+        // Inverse pegs are bounded the same way: $100 for 572 BRL is 0.174825... USD/BRL
+        assert_eq!(effective_mint_price(10_000, 572_000_000, 6, QUOTE_DECIMALS).unwrap(), 174_826);
+        assert!(effective_mint_price(10_800, 0, 6, QUOTE_DECIMALS).is_err());
+    }
+
+    #[test]
+    fn test_sliding_window_usage() {
+        let day = 86_400;
+        // At the start of a window, all of the previous window still counts
+        assert_eq!(sliding_window_usage(0, 1_000_000, 0, day).unwrap(), 1_000_000);
+        // Halfway through, half of it
+        assert_eq!(sliding_window_usage(200_000, 1_000_000, day / 2, day).unwrap(), 700_000);
+        // The carried share rounds up
+        assert_eq!(sliding_window_usage(0, 1, day - 1, day).unwrap(), 1);
+        // At the end of the window, only the current volume
+        assert_eq!(sliding_window_usage(200_000, 1_000_000, day, day).unwrap(), 200_000);
+        assert!(sliding_window_usage(0, 0, 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_peg_deviation_bps() {
+        // EUR at 1.08 USD (Direct, 6 decimals)
+        let eur = SwapLeg {
+            price_scaled:   1_080_000,
+            feed_type:      &FeedType::Direct,
+            cpi_multiplier: CPI_SCALE,
+            token_decimals: 6,
+            price_decimals: 6,
+        };
+        assert_eq!(peg_deviation_bps(1_080_000, 6, &eur).unwrap(), 0);
+        // Market at 1.0692 is 1% below peg, at 1.0908 1% above; other decimals agree
+        assert_eq!(peg_deviation_bps(1_069_200, 6, &eur).unwrap(), -100);
+        assert_eq!(peg_deviation_bps(109_080_000, 8, &eur).unwrap(), 100);
+
+        // BRL at 5.00 per USD (Inverse): fair value 0.20 USD per token
+        let brl = SwapLeg {
+            price_scaled:   5_000_000,
+            feed_type:      &FeedType::Inverse,
+            cpi_multiplier: CPI_SCALE,
+            token_decimals: 6,
+            price_decimals: 6,
+        };
+        assert_eq!(peg_deviation_bps(190_000, 6, &brl).unwrap(), -500);
+        assert!(peg_deviation_bps(0, 6, &brl).is_err());
+    }
 }
+
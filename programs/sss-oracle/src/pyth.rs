@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use crate::errors::OracleError;
+use crate::math::{pow10, ScaledDecimal};
+
+// ─── Constants ───────────────────────────────────────────────────────────────
+
+/// Anchor discriminator of the Pyth receiver's `PriceUpdateV2` account:
+/// `sha256("account:PriceUpdateV2")[..8]`.
+pub const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// Same hard staleness cap as the Switchboard path.
+const MAX_PRICE_AGE_SECONDS: i64 = 60;
+
+/// Same decimal scale bound as the Switchboard path.
+const MAX_DECIMAL_SCALE: u32 = 18;
+
+/// `VerificationLevel` Borsh tags.
+const VERIFICATION_PARTIAL: u8 = 0;
+const VERIFICATION_FULL: u8 = 1;
+
+// ─── Output type ─────────────────────────────────────────────────────────────
+
+/// Parsed price data from a Pyth pull-oracle `PriceUpdateV2` account.
+#[derive(Debug, Clone, Copy)]
+pub struct PythPrice {
+    /// Aggregate price (always > 0 after validation).
+    pub value: ScaledDecimal,
+    /// Confidence interval, same scale as `value`.
+    pub confidence: ScaledDecimal,
+    /// Unix timestamp at which the price was published.
+    pub publish_time: i64,
+}
+
+// ─── Public entry points ─────────────────────────────────────────────────────
+
+/// Read and validate the price from a Pyth `PriceUpdateV2` account.
+///
+/// Only fully verified updates (all Wormhole guardian signatures checked)
+/// are accepted.
+///
+/// # Errors
+/// Returns `OracleError::FeedNotReady`            – bad discriminator / too small / bad exponent
+/// Returns `OracleError::InsufficientVerification` – update is only partially verified
+/// Returns `OracleError::PriceTooStale`           – price older than `MAX_PRICE_AGE_SECONDS`
+/// Returns `OracleError::InvalidPrice`            – value ≤ 0
+pub fn read_pyth_price(account_data: &[u8]) -> Result<PythPrice> {
+    let price = parse_pyth_price(account_data)?;
+    validate_pyth_price(&price, Clock::get()?.unix_timestamp)?;
+    Ok(price)
+}
+
+/// Parse a `PriceUpdateV2` account using its Borsh layout.
+///
+/// ## Layout reference
+///
+/// ```text
+/// Offset  0 :  8-byte anchor discriminator
+/// Offset  8 :  write_authority       Pubkey (32 bytes)
+/// Offset 40 :  verification_level    enum: Partial { num_signatures: u8 } = [0, n]
+///                                          Full                          = [1]
+/// Offset  p :  price_message  (p = 41 for Full)
+///   +  0 :  feed_id            [u8; 32]
+///   + 32 :  price              i64
+///   + 40 :  conf               u64
+///   + 48 :  exponent           i32
+///   + 52 :  publish_time       i64
+///   + 60 :  prev_publish_time  i64
+///   + 68 :  ema_price          i64
+///   + 76 :  ema_conf           u64
+/// Offset p + 84 :  posted_slot  u64
+/// ```
+///
+/// The Borsh enum makes the message offset depend on the verification level,
+/// so the layout cannot be read at fixed offsets.
+pub fn parse_pyth_price(account_data: &[u8]) -> Result<PythPrice> {
+    require!(
+        account_data.len() >= 8 && account_data[..8] == PRICE_UPDATE_V2_DISCRIMINATOR,
+        OracleError::FeedNotReady
+    );
+
+    const LEVEL_OFFSET: usize = 40;
+    let level = *account_data.get(LEVEL_OFFSET).ok_or(OracleError::FeedNotReady)?;
+    let msg = match level {
+        VERIFICATION_FULL    => LEVEL_OFFSET + 1,
+        VERIFICATION_PARTIAL => return Err(error!(OracleError::InsufficientVerification)),
+        _                    => return Err(error!(OracleError::FeedNotReady)),
+    };
+    require!(account_data.len() >= msg + 92, OracleError::FeedNotReady);
+
+    let price        = read_i64(account_data, msg + 32)?;
+    let conf         = read_u64(account_data, msg + 40)?;
+    let exponent     = read_i32(account_data, msg + 48)?;
+    let publish_time = read_i64(account_data, msg + 52)?;
+
+    Ok(PythPrice {
+        value:        from_exponent(price as i128, exponent)?,
+        confidence:   from_exponent(conf as i128, exponent)?,
+        publish_time,
+    })
+}
+
+/// Common post-parse validation, mirroring the Switchboard path.
+pub fn validate_pyth_price(price: &PythPrice, now: i64) -> Result<()> {
+    let age = now.checked_sub(price.publish_time).unwrap_or(i64::MAX);
+
+    require!(age >= 0, OracleError::PriceTooStale);              // clock skew guard
+    require!(age <= MAX_PRICE_AGE_SECONDS, OracleError::PriceTooStale);
+    require!(price.value.mantissa > 0, OracleError::InvalidPrice);
+
+    Ok(())
+}
+
+// ─── Low-level helpers ────────────────────────────────────────────────────────
+
+/// Convert a Pyth (value, exponent) pair to an exact `ScaledDecimal`.
+/// `real = value * 10^exponent`
+fn from_exponent(value: i128, exponent: i32) -> Result<ScaledDecimal> {
+    if exponent <= 0 {
+        let scale = exponent.unsigned_abs();
+        require!(scale <= MAX_DECIMAL_SCALE, OracleError::FeedNotReady);
+        Ok(ScaledDecimal::new(value, scale))
+    } else {
+        let factor = i128::try_from(pow10(exponent as u32)?)
+            .map_err(|_| error!(OracleError::MathOverflow))?;
+        let mantissa = value.checked_mul(factor).ok_or(OracleError::MathOverflow)?;
+        Ok(ScaledDecimal::new(mantissa, 0))
+    }
+}
+
+fn read_i32(data: &[u8], offset: usize) -> Result<i32> {
+    let bytes = data.get(offset..offset + 4).ok_or(OracleError::FeedNotReady)?;
+    Ok(i32::from_le_bytes(bytes.try_into().map_err(|_| error!(OracleError::FeedNotReady))?))
+}
+
+fn read_i64(data: &[u8], offset: usize) -> Result<i64> {
+    let bytes = data.get(offset..offset + 8).ok_or(OracleError::FeedNotReady)?;
+    Ok(i64::from_le_bytes(bytes.try_into().map_err(|_| error!(OracleError::FeedNotReady))?))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64> {
+    let bytes = data.get(offset..offset + 8).ok_or(OracleError::FeedNotReady)?;
+    Ok(u64::from_le_bytes(bytes.try_into().map_err(|_| error!(OracleError::FeedNotReady))?))
+}
+
+// ─── Unit tests ───────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::Rounding;
+
+    // Synthetic account bytes laid out per the PriceUpdateV2 Borsh schema, not
+    // captured from a cluster (see fixtures/README.md)
+    const FULL: &[u8]    = include_bytes!("../fixtures/pyth_price_update_v2_full.bin");
+    const PARTIAL: &[u8] = include_bytes!("../fixtures/pyth_price_update_v2_partial.bin");
+
+    #[test]
+    fn test_parse_full_fixture() {
+        // USD/BRL = 5.72312 ± 0.0015, exponent -5
+        let price = parse_pyth_price(FULL).unwrap();
+        assert_eq!(price.value, ScaledDecimal::new(572_312, 5));
+        assert_eq!(price.value.to_price_scaled(Rounding::Down).unwrap(), 5_723_120);
+        assert_eq!(price.confidence.to_price_scaled(Rounding::Up).unwrap(), 1_500);
+        assert_eq!(price.publish_time, 1_760_000_000);
+    }
+
+    #[test]
+    fn test_partial_verification_rejected() {
+        assert!(parse_pyth_price(PARTIAL).is_err());
+    }
+
+    #[test]
+    fn test_wrong_discriminator_rejected() {
+        let mut data = FULL.to_vec();
+        data[0] ^= 0xFF;
+        assert!(parse_pyth_price(&data).is_err());
+    }
+
+    #[test]
+    fn test_truncated_rejected() {
+        assert!(parse_pyth_price(&FULL[..100]).is_err());
+    }
+
+    #[test]
+    fn test_validate_staleness() {
+        let price = parse_pyth_price(FULL).unwrap();
+        assert!(validate_pyth_price(&price, 1_760_000_030).is_ok());
+        assert!(validate_pyth_price(&price, 1_760_000_000 + MAX_PRICE_AGE_SECONDS + 1).is_err());
+        assert!(validate_pyth_price(&price, 1_759_999_999).is_err());
+    }
+
+    #[test]
+    fn test_positive_exponent() {
+        let d = from_exponent(12, 2).unwrap();
+        assert_eq!(d, ScaledDecimal::new(1_200, 0));
+    }
+
+    #[test]
+    fn test_exponent_out_of_range() {
+        assert!(from_exponent(1, -19).is_err());
+    }
+}
@@ -1,52 +1,73 @@
 use anchor_lang::prelude::*;
+use crate::errors::OracleError;
+use crate::math::{interpolate_linear, sliding_window_usage, Rounding, MAX_AMOUNT_DECIMALS};
 
 // ═══════════════════════════════════════════════════════════════════════════
-// FeedRegistry — Global singleton tracking all registered price feeds
+// FeedRegistry — Global singleton holding the feed-management authority
 // PDA seed: ["sss-feed-registry"]
+//
+// Feeds themselves live in per-symbol `FeedEntry` PDAs, so the number of
+// feeds is not bounded by the registry's account size.
 // ═══════════════════════════════════════════════════════════════════════════
 
-pub const MAX_FEEDS: usize = 64;
+/// Maximum number of price source accounts behind a single feed
+pub const MAX_FEED_SOURCES: usize = 3;
+
+/// Maximum feed symbol length
+pub const MAX_SYMBOL_LEN: usize = 12;
+
+/// Maximum number of registered feeds a composite feed combines
+pub const MAX_COMPOSITE_LEGS: usize = 3;
+
+/// Maximum number of source accounts behind one quote (a composite feed's
+/// legs each bring their own sources)
+pub const MAX_QUOTE_SOURCES: usize = MAX_COMPOSITE_LEGS * MAX_FEED_SOURCES;
 
 #[account]
 pub struct FeedRegistry {
-    /// Authority who can register new feeds
-    pub authority:    Pubkey,
-    /// Current number of registered feeds
-    pub feed_count:   u8,
-    /// All registered feeds
-    pub feeds:        Vec<FeedEntry>,
+    /// Authority who can register and manage feeds
+    pub authority:         Pubkey,
+    /// Proposed new authority (two-step transfer)
+    pub pending_authority: Option<Pubkey>,
+    /// Number of feeds ever registered (deactivated feeds included)
+    pub feed_count:        u32,
     /// PDA bump
-    pub bump:         u8,
+    pub bump:              u8,
 }
 
 impl FeedRegistry {
     pub const LEN: usize = 8   // discriminator
-        + 32                    // authority
-        + 1                     // feed_count
-        + 4                     // vec len prefix
-        + (MAX_FEEDS * FeedEntry::LEN)
-        + 1                     // bump
-        + 16;                   // slack
-
-    pub fn find_feed(&self, symbol: &str) -> Option<&FeedEntry> {
-        self.feeds.iter().find(|f| f.symbol == symbol && f.active)
-    }
+        + 32                   // authority
+        + 33                   // pending_authority
+        + 4                    // feed_count
+        + 1                    // bump
+        + 16;                  // slack
 }
 
-/// A single registered price feed entry
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+// ═══════════════════════════════════════════════════════════════════════════
+// FeedEntry — One registered price feed
+// PDA seed: ["sss-feed", symbol]
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[account]
 pub struct FeedEntry {
     /// Feed symbol, max 12 chars: "BRLUSD", "EURUSD"
     pub symbol:           String,
-    /// Switchboard aggregator account address
-    pub switchboard_feed: Pubkey,
+    /// Price source accounts backing this feed (1..=MAX_FEED_SOURCES).
+    /// The quote price is the median of the fresh sources. Empty for
+    /// composite feeds, whose legs carry the sources.
+    pub sources:          Vec<PriceSource>,
+    /// Minimum number of fresh sources required to quote (0 for composite feeds)
+    pub min_sources:      u8,
+    /// Max allowed spread between the fresh sources, as bps of the median (0 = no check)
+    pub max_spread_bps:   u16,
     /// How to interpret the price value
     pub feed_type:        FeedType,
     /// Base currency code, max 8 chars: "BRL", "EUR"
     pub base_currency:    String,
     /// Quote currency code, max 8 chars: "USD"
     pub quote_currency:   String,
-    /// Price decimals from the feed
+    /// Decimals quotes price this feed at (fixed-point scale of `price_scaled`)
     pub decimals:         u8,
     /// Whether this feed is currently active
     pub active:           bool,
@@ -54,19 +75,49 @@ pub struct FeedEntry {
     pub registered_at:    i64,
     /// Who registered it
     pub registered_by:    Pubkey,
+    /// Last time the sources or feed type were changed
+    pub updated_at:       i64,
+    /// PDA bump
+    pub bump:             u8,
 }
 
 impl FeedEntry {
-    // symbol(4+12) + switchboard_feed(32) + feed_type(1+16+16+1=34 max)
-    // + base_currency(4+8) + quote_currency(4+8) + decimals(1)
-    // + active(1) + registered_at(8) + registered_by(32)
-    pub const LEN: usize = 16 + 32 + 35 + 12 + 12 + 1 + 1 + 8 + 32;
+    // discriminator(8) + symbol(4+12) + sources(4+3*33) + min_sources(1)
+    // + max_spread_bps(2) + feed_type(FeedType::MAX_LEN) + base_currency(4+8)
+    // + quote_currency(4+8) + decimals(1) + active(1) + registered_at(8)
+    // + registered_by(32) + updated_at(8) + bump(1)
+    pub const LEN: usize = 8 + 16 + (4 + MAX_FEED_SOURCES * PriceSource::LEN) + 1 + 2
+        + FeedType::MAX_LEN + 12 + 12 + 1 + 1 + 8 + 32 + 8 + 1;
 
-    pub fn matches_feed_key(&self, key: &Pubkey) -> bool {
-        self.switchboard_feed == *key
+    /// Check that `keys` are exactly this feed's sources, in registration order.
+    /// Requiring every source prevents callers from cherry-picking a favourable subset.
+    pub fn matches_sources<'a>(&self, keys: impl ExactSizeIterator<Item = &'a Pubkey>) -> bool {
+        keys.len() == self.sources.len()
+            && keys.zip(self.sources.iter()).all(|(key, source)| *key == source.account)
     }
 }
 
+/// One price source account and the provider that owns its layout
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub struct PriceSource {
+    /// Which parser reads this account
+    pub kind:    SourceKind,
+    /// Source account address
+    pub account: Pubkey,
+}
+
+impl PriceSource {
+    pub const LEN: usize = 1 + 32;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SourceKind {
+    /// Switchboard V2 aggregator or On-Demand `PullFeedAccountData`
+    Switchboard,
+    /// Pyth pull-oracle `PriceUpdateV2` (fully verified updates only)
+    Pyth,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // FeedType — Price interpretation strategy
 // ═══════════════════════════════════════════════════════════════════════════
@@ -92,6 +143,45 @@ pub enum FeedType {
         /// 0 = Direct after scaling, 1 = Inverse after scaling
         base_type:   u8,
     },
+
+    /// Price derived from other registered feeds: start from 1 and multiply
+    /// or divide by each leg's raw median price in turn, then apply base_type.
+    /// EUR from a BRL cross: EURBRL (6.1776 BRL per EUR) ÷ BRLUSD (5.72 BRL per USD)
+    /// = 1.08 USD per EUR, Direct
+    Composite {
+        /// 2..=MAX_COMPOSITE_LEGS registered, non-composite feeds
+        legs:      Vec<CompositeLeg>,
+        /// 0 = Direct after composing, 1 = Inverse after composing
+        base_type: u8,
+    },
+}
+
+impl FeedType {
+    /// Largest serialized size: Composite with MAX_COMPOSITE_LEGS legs
+    pub const MAX_LEN: usize = 1 + (4 + MAX_COMPOSITE_LEGS * CompositeLeg::LEN) + 1;
+
+    pub fn is_composite(&self) -> bool {
+        matches!(self, FeedType::Composite { .. })
+    }
+}
+
+/// One registered feed inside a composite feed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct CompositeLeg {
+    /// Symbol of the registered feed
+    pub symbol: String,
+    /// Whether the leg's price multiplies or divides the running price
+    pub op:     CompositeOp,
+}
+
+impl CompositeLeg {
+    pub const LEN: usize = (4 + MAX_SYMBOL_LEN) + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CompositeOp {
+    Multiply,
+    Divide,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -99,16 +189,37 @@ pub enum FeedType {
 // PDA seed: ["sss-oracle", mint.key()]
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Current `OracleConfig` schema. Version 1 accounts predate the multi-source
+/// feeds, roles, caps and quote denomination and cannot be read by this
+/// program; see "Upgrading From Version 1" in docs/ORACLE.md.
+pub const ORACLE_CONFIG_VERSION: u8 = 2;
+
 #[account]
 pub struct OracleConfig {
-    /// Schema version for future migrations
+    /// Schema version (`ORACLE_CONFIG_VERSION`)
     pub version:                 u8,
     /// The SSS token mint this oracle prices
     pub mint:                    Pubkey,
-    /// Authority that can update config, update CPI, pause/unpause
-    pub authority:               Pubkey,
-    /// Two-step authority transfer target
-    pub pending_authority:       Option<Pubkey>,
+    /// Decimals of `mint`, read at initialization
+    pub token_decimals:          u8,
+    /// Unit of mint inputs, redeem outputs, caps and USD stats; fixed at initialization
+    pub quote_denomination:      QuoteDenomination,
+    /// Admin: risk parameters, manual price, PSM, settlements, role transfers
+    pub admin:                   Pubkey,
+    /// Two-step admin transfer target
+    pub pending_admin:           Option<Pubkey>,
+    /// Pauser: pause and unpause
+    pub pauser:                  Pubkey,
+    /// Two-step pauser transfer target
+    pub pending_pauser:          Option<Pubkey>,
+    /// CPI updater: publishes CPI multipliers
+    pub cpi_updater:             Pubkey,
+    /// Two-step CPI updater transfer target
+    pub pending_cpi_updater:     Option<Pubkey>,
+    /// Fee manager: fee vault, fee splits and withdrawals
+    pub fee_manager:             Pubkey,
+    /// Two-step fee manager transfer target
+    pub pending_fee_manager:     Option<Pubkey>,
     /// Symbol of the registered feed to use (must exist in FeedRegistry)
     pub feed_symbol:             String,
     /// Human-readable description
@@ -119,25 +230,91 @@ pub struct OracleConfig {
     pub mint_fee_bps:            u16,
     /// Redeem fee in basis points
     pub redeem_fee_bps:          u16,
+    /// Fee on swaps into this mint, in basis points of the output
+    pub swap_fee_bps:            u16,
     /// Max allowed confidence interval as % of price (in bps)
     pub max_confidence_bps:      u16,
     /// Quote validity window in seconds
     pub quote_validity_secs:     i64,
-    /// CPI multiplier (fixed-point * 1_000_000). 1.083 = 1_083_000
+    /// CPI multiplier at `cpi_accrual_start` (fixed-point * 1_000_000). 1.083 = 1_083_000
     pub cpi_multiplier:          u64,
+    /// Multiplier the schedule reaches at `cpi_accrual_end`
+    pub cpi_target_multiplier:   u64,
+    /// Start of the current accrual window
+    pub cpi_accrual_start:       i64,
+    /// End of the current accrual window
+    pub cpi_accrual_end:         i64,
+    /// Max change of one CPI update, in bps of the current multiplier
+    pub cpi_max_change_bps:      u16,
     /// Timestamp of last CPI update
     pub cpi_last_updated:        i64,
     /// Minimum seconds between CPI updates
     pub cpi_min_update_interval: i64,
     /// Source description for CPI data audit trail
     pub cpi_data_source:         String,
+    /// Attesters whose signatures CPI updates need (empty = CPI updater alone)
+    pub cpi_attesters:           CpiAttesterSet,
+    /// Reference month ("YYYY-MM") of the last CPI update
+    pub cpi_reference_month:     String,
     /// Whether minting/redeeming is suspended
     pub paused:                  bool,
     /// Reason for current pause
     pub pause_reason:            String,
-    /// Lifetime stats: total USD value minted through oracle
+    /// Machine-readable cause of the current pause
+    pub pause_code:              PauseReason,
+    /// Last feed price accepted by a quote (feed decimals, 0 = none yet)
+    pub last_good_price:         u64,
+    /// Timestamp of `last_good_price`
+    pub last_good_price_at:      i64,
+    /// Max price move per window, in bps of `last_good_price` (0 = breaker off)
+    pub max_price_move_bps:      u16,
+    /// Window the move limit applies to, in seconds
+    pub price_move_window_secs:  i64,
+    /// Feed price that tripped the circuit breaker (0 = not tripped)
+    pub tripped_price:           u64,
+    /// Max distance of a manual price from `last_good_price`, in bps (0 = overrides disabled)
+    pub manual_price_band_bps:   u16,
+    /// Emergency manual price (feed decimals, 0 = no override)
+    pub manual_price:            u64,
+    /// Time the manual price stops applying
+    pub manual_price_expires_at: i64,
+    /// Why the manual price was set
+    pub manual_price_reason:     String,
+    /// Which price quotes use: spot, accumulator average, or the safer of both
+    pub pricing_mode:            PricingMode,
+    /// Delay between queueing and applying a risk-parameter update
+    pub config_timelock_secs:    i64,
+    /// Queued risk-parameter update, if any
+    pub pending_update:          Option<OracleParamsUpdate>,
+    /// Earliest time `pending_update` can be applied
+    pub pending_update_eta:      i64,
+    /// Revenue-share recipients paid out of `withdraw_fees`
+    pub fee_splits:              Vec<FeeSplit>,
+    /// Minimum sss-core allowlist `kyc_tier` for oracle issuance (0 = none)
+    pub min_kyc_tier:            u8,
+    /// Rolling 24h cap on value minted through the oracle, all users, in
+    /// `quote_denomination` units despite the `_usd` suffix (0 = none)
+    pub global_mint_cap_usd:     u64,
+    /// Rolling 24h cap on value redeemed through the oracle, all users, in
+    /// `quote_denomination` units despite the `_usd` suffix (0 = none)
+    pub global_redeem_cap_usd:   u64,
+    /// Rolling 24h cap on value minted per requester, in `quote_denomination`
+    /// units despite the `_usd` suffix (0 = none)
+    pub user_mint_cap_usd:       u64,
+    /// Rolling 24h cap on value redeemed per requester, in `quote_denomination`
+    /// units despite the `_usd` suffix (0 = none)
+    pub user_redeem_cap_usd:     u64,
+    /// Value minted through the oracle in the rolling window, all users (`quote_denomination` units)
+    pub global_mint_usage:       UsageWindow,
+    /// Value redeemed through the oracle in the rolling window, all users (`quote_denomination` units)
+    pub global_redeem_usage:     UsageWindow,
+    /// Peg-defense fee curve replacing the flat mint/redeem fees (no points = off)
+    pub peg_fee_curve:           PegFeeCurve,
+    /// Lifetime stats: total value minted through oracle, in `quote_denomination`
+    /// units despite the `_usd` suffix
     pub total_minted_usd:        u64,
-    /// Lifetime stats: total USD value redeemed through oracle
+    /// Lifetime stats: total value redeemed through oracle, in `quote_denomination`
+    /// units despite the `_usd` suffix
     pub total_redeemed_usd:      u64,
     /// Lifetime stats: total fees collected in token base units
     pub total_fees_collected:    u64,
@@ -153,21 +330,59 @@ impl OracleConfig {
     pub const LEN: usize = 8   // discriminator
         + 1                     // version
         + 32                    // mint
-        + 32                    // authority
-        + 1 + 32                // pending_authority (Option<Pubkey>)
+        + 1                     // token_decimals
+        + QuoteDenomination::LEN // quote_denomination
+        + 32                    // admin
+        + 1 + 32                // pending_admin (Option<Pubkey>)
+        + 32                    // pauser
+        + 1 + 32                // pending_pauser
+        + 32                    // cpi_updater
+        + 1 + 32                // pending_cpi_updater
+        + 32                    // fee_manager
+        + 1 + 32                // pending_fee_manager
         + 4 + 12                // feed_symbol
         + 4 + 100               // description
         + 8                     // max_staleness_secs
         + 2                     // mint_fee_bps
         + 2                     // redeem_fee_bps
+        + 2                     // swap_fee_bps
         + 2                     // max_confidence_bps
         + 8                     // quote_validity_secs
         + 8                     // cpi_multiplier
+        + 8                     // cpi_target_multiplier
+        + 8                     // cpi_accrual_start
+        + 8                     // cpi_accrual_end
+        + 2                     // cpi_max_change_bps
         + 8                     // cpi_last_updated
         + 8                     // cpi_min_update_interval
         + 4 + 50                // cpi_data_source
+        + CpiAttesterSet::LEN   // cpi_attesters
+        + 4 + 16                // cpi_reference_month
         + 1                     // paused
         + 4 + 100               // pause_reason
+        + 1                     // pause_code
+        + 8                     // last_good_price
+        + 8                     // last_good_price_at
+        + 2                     // max_price_move_bps
+        + 8                     // price_move_window_secs
+        + 8                     // tripped_price
+        + 2                     // manual_price_band_bps
+        + 8                     // manual_price
+        + 8                     // manual_price_expires_at
+        + 4 + MAX_MANUAL_PRICE_REASON_LEN // manual_price_reason
+        + 1                     // pricing_mode
+        + 8                     // config_timelock_secs
+        + 1 + OracleParamsUpdate::LEN // pending_update
+        + 8                     // pending_update_eta
+        + 4 + MAX_FEE_SPLITS * FeeSplit::LEN // fee_splits
+        + 1                     // min_kyc_tier
+        + 8                     // global_mint_cap_usd
+        + 8                     // global_redeem_cap_usd
+        + 8                     // user_mint_cap_usd
+        + 8                     // user_redeem_cap_usd
+        + UsageWindow::LEN      // global_mint_usage
+        + UsageWindow::LEN      // global_redeem_usage
+        + PegFeeCurve::LEN      // peg_fee_curve
         + 8                     // total_minted_usd
         + 8                     // total_redeemed_usd
         + 8                     // total_fees_collected
@@ -177,6 +392,504 @@ impl OracleConfig {
         + 64;                   // slack
 }
 
+// ── Risk parameter bounds ────────────────────────────────────────────────────
+
+/// Max mint/redeem/swap fee (10%)
+pub const MAX_FEE_BPS: u16 = 1_000;
+/// Max per-source confidence interval (10%)
+pub const MAX_CONFIDENCE_BPS: u16 = 1_000;
+/// Allowed feed staleness range
+pub const MIN_STALENESS_SECS: i64 = 10;
+pub const MAX_STALENESS_SECS: i64 = 3_600;
+/// Allowed quote validity range
+pub const MIN_QUOTE_VALIDITY_SECS: i64 = 10;
+pub const MAX_QUOTE_VALIDITY_SECS: i64 = 600;
+/// Allowed config update timelock range
+pub const MIN_CONFIG_TIMELOCK_SECS: i64 = 3_600;
+pub const MAX_CONFIG_TIMELOCK_SECS: i64 = 7 * 86_400;
+/// Max description length
+pub const MAX_DESCRIPTION_LEN: usize = 100;
+/// Max change a single CPI update may publish (20%)
+pub const MAX_CPI_CHANGE_BPS: u16 = 2_000;
+/// Longest CPI accrual window
+pub const MAX_CPI_ACCRUAL_WINDOW_SECS: i64 = 366 * 86_400;
+/// Max CPI attesters
+pub const MAX_CPI_ATTESTERS: usize = 5;
+/// Max disagreement allowed between attested CPI multipliers (5%)
+pub const MAX_CPI_ATTESTATION_TOLERANCE_BPS: u16 = 500;
+/// Max band a manual price may sit in around the last good price (20%)
+pub const MAX_MANUAL_PRICE_BAND_BPS: u16 = 2_000;
+/// Longest a single manual price stays in force
+pub const MAX_MANUAL_PRICE_DURATION_SECS: i64 = 86_400;
+/// Max manual price reason length
+pub const MAX_MANUAL_PRICE_REASON_LEN: usize = 64;
+
+impl OracleConfig {
+    /// Enforce the hard bounds on every risk parameter.
+    pub fn validate_risk_params(&self) -> Result<()> {
+        require!(
+            self.mint_fee_bps <= MAX_FEE_BPS
+                && self.redeem_fee_bps <= MAX_FEE_BPS
+                && self.swap_fee_bps <= MAX_FEE_BPS,
+            OracleError::FeeTooHigh
+        );
+        require!(
+            self.max_confidence_bps <= MAX_CONFIDENCE_BPS
+                && (MIN_STALENESS_SECS..=MAX_STALENESS_SECS).contains(&self.max_staleness_secs)
+                && (MIN_QUOTE_VALIDITY_SECS..=MAX_QUOTE_VALIDITY_SECS).contains(&self.quote_validity_secs)
+                && (MIN_CONFIG_TIMELOCK_SECS..=MAX_CONFIG_TIMELOCK_SECS).contains(&self.config_timelock_secs)
+                && self.manual_price_band_bps <= MAX_MANUAL_PRICE_BAND_BPS
+                && (1..=MAX_CPI_CHANGE_BPS).contains(&self.cpi_max_change_bps),
+            OracleError::RiskParamOutOfBounds
+        );
+        require!(
+            self.max_price_move_bps == 0 || self.price_move_window_secs > 0,
+            OracleError::InvalidCircuitBreakerConfig
+        );
+        require!(self.feed_symbol.len() <= 12, OracleError::SymbolTooLong);
+        require!(
+            self.description.len() <= MAX_DESCRIPTION_LEN,
+            OracleError::DescriptionTooLong
+        );
+        self.cpi_attesters.validate()?;
+        self.peg_fee_curve.validate()?;
+        Ok(())
+    }
+
+    /// Overwrite every field set in `update`.
+    pub fn apply_update(&mut self, update: &OracleParamsUpdate) {
+        if let Some(v) = update.mint_fee_bps           { self.mint_fee_bps           = v; }
+        if let Some(v) = update.redeem_fee_bps         { self.redeem_fee_bps         = v; }
+        if let Some(v) = update.swap_fee_bps           { self.swap_fee_bps           = v; }
+        if let Some(v) = update.max_staleness_secs     { self.max_staleness_secs     = v; }
+        if let Some(v) = update.max_confidence_bps     { self.max_confidence_bps     = v; }
+        if let Some(v) = update.quote_validity_secs    { self.quote_validity_secs    = v; }
+        if let Some(v) = &update.feed_symbol           { self.feed_symbol            = v.clone(); }
+        if let Some(v) = &update.description           { self.description            = v.clone(); }
+        if let Some(v) = update.max_price_move_bps     { self.max_price_move_bps     = v; }
+        if let Some(v) = update.price_move_window_secs { self.price_move_window_secs = v; }
+        if let Some(v) = update.pricing_mode           { self.pricing_mode           = v; }
+        if let Some(v) = update.config_timelock_secs   { self.config_timelock_secs   = v; }
+        if let Some(v) = update.manual_price_band_bps  { self.manual_price_band_bps  = v; }
+        if let Some(v) = update.cpi_max_change_bps     { self.cpi_max_change_bps     = v; }
+        if let Some(v) = &update.cpi_attesters         { self.cpi_attesters          = v.clone(); }
+        if let Some(v) = update.min_kyc_tier           { self.min_kyc_tier           = v; }
+        if let Some(v) = update.global_mint_cap_usd    { self.global_mint_cap_usd    = v; }
+        if let Some(v) = update.global_redeem_cap_usd  { self.global_redeem_cap_usd  = v; }
+        if let Some(v) = update.user_mint_cap_usd      { self.user_mint_cap_usd      = v; }
+        if let Some(v) = update.user_redeem_cap_usd    { self.user_redeem_cap_usd    = v; }
+        if let Some(v) = update.peg_fee_curve.clone()  { self.peg_fee_curve          = v; }
+    }
+
+    /// CPI multiplier in effect at `now`, interpolated linearly along the
+    /// current accrual window and rounded with `rounding`.
+    pub fn cpi_multiplier_at(&self, now: i64, rounding: Rounding) -> Result<u64> {
+        interpolate_linear(
+            self.cpi_multiplier,
+            self.cpi_target_multiplier,
+            self.cpi_accrual_start,
+            self.cpi_accrual_end,
+            now,
+            rounding,
+        )
+    }
+
+    /// Whether quotes currently price off `manual_price` instead of the feed.
+    pub fn manual_price_active(&self, now: i64) -> bool {
+        self.manual_price > 0 && now < self.manual_price_expires_at
+    }
+
+    /// Current holder of `role`.
+    pub fn role_holder(&self, role: OracleRole) -> Pubkey {
+        match role {
+            OracleRole::Admin      => self.admin,
+            OracleRole::Pauser     => self.pauser,
+            OracleRole::CpiUpdater => self.cpi_updater,
+            OracleRole::FeeManager => self.fee_manager,
+        }
+    }
+
+    /// Proposed next holder of `role`, if a transfer is pending.
+    pub fn pending_role_holder(&self, role: OracleRole) -> Option<Pubkey> {
+        match role {
+            OracleRole::Admin      => self.pending_admin,
+            OracleRole::Pauser     => self.pending_pauser,
+            OracleRole::CpiUpdater => self.pending_cpi_updater,
+            OracleRole::FeeManager => self.pending_fee_manager,
+        }
+    }
+
+    /// The holder and pending-holder slots of `role`.
+    pub fn role_slots(&mut self, role: OracleRole) -> (&mut Pubkey, &mut Option<Pubkey>) {
+        match role {
+            OracleRole::Admin      => (&mut self.admin, &mut self.pending_admin),
+            OracleRole::Pauser     => (&mut self.pauser, &mut self.pending_pauser),
+            OracleRole::CpiUpdater => (&mut self.cpi_updater, &mut self.pending_cpi_updater),
+            OracleRole::FeeManager => (&mut self.fee_manager, &mut self.pending_fee_manager),
+        }
+    }
+}
+
+/// Operator roles of an oracle, each held by one key and handed over in two
+/// steps (`propose_authority_transfer` / `accept_authority_transfer`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OracleRole {
+    /// Risk parameters, manual price, PSM collateral, settlements, role transfers
+    Admin,
+    /// `pause_oracle` / `unpause_oracle`
+    Pauser,
+    /// `update_cpi_multiplier`
+    CpiUpdater,
+    /// `initialize_fee_vault`, `set_fee_splits`, `withdraw_fees`
+    FeeManager,
+}
+
+/// Unit that an oracle's quote amounts are counted in: mint inputs, redeem
+/// outputs, settlements, issuance caps and lifetime stats. The oracle's feed
+/// must price the token in `currency`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct QuoteDenomination {
+    /// ISO 4217 code, e.g. `*b"USD"` or `*b"EUR"`
+    pub currency: [u8; 3],
+    /// Decimals of one quote unit: 2 = cents, 6 = micro-units
+    pub decimals: u8,
+}
+
+impl QuoteDenomination {
+    pub const LEN: usize = 3 + 1;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.currency.iter().all(u8::is_ascii_uppercase)
+                && self.decimals <= MAX_AMOUNT_DECIMALS,
+            OracleError::InvalidQuoteDenomination
+        );
+        Ok(())
+    }
+
+    pub fn is_usd(&self) -> bool {
+        self.currency == *b"USD"
+    }
+
+    /// Whether `feed` prices its base currency in this denomination's currency
+    pub fn matches_feed(&self, feed: &FeedEntry) -> bool {
+        feed.quote_currency.as_bytes() == self.currency
+    }
+}
+
+/// Risk-parameter changes queued behind the config timelock. `None` fields
+/// are left unchanged.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug, Default)]
+pub struct OracleParamsUpdate {
+    pub mint_fee_bps:           Option<u16>,
+    pub redeem_fee_bps:         Option<u16>,
+    pub swap_fee_bps:           Option<u16>,
+    pub max_staleness_secs:     Option<i64>,
+    pub max_confidence_bps:     Option<u16>,
+    pub quote_validity_secs:    Option<i64>,
+    pub feed_symbol:            Option<String>,
+    pub description:            Option<String>,
+    pub max_price_move_bps:     Option<u16>,
+    pub price_move_window_secs: Option<i64>,
+    pub pricing_mode:           Option<PricingMode>,
+    pub config_timelock_secs:   Option<i64>,
+    pub manual_price_band_bps:  Option<u16>,
+    pub cpi_max_change_bps:     Option<u16>,
+    pub cpi_attesters:          Option<CpiAttesterSet>,
+    pub min_kyc_tier:           Option<u8>,
+    /// Caps in the oracle's `quote_denomination` units, as on `OracleConfig`
+    pub global_mint_cap_usd:    Option<u64>,
+    pub global_redeem_cap_usd:  Option<u64>,
+    pub user_mint_cap_usd:      Option<u64>,
+    pub user_redeem_cap_usd:    Option<u64>,
+    pub peg_fee_curve:          Option<PegFeeCurve>,
+}
+
+impl OracleParamsUpdate {
+    pub const LEN: usize = (1 + 2)  // mint_fee_bps
+        + (1 + 2)               // redeem_fee_bps
+        + (1 + 2)               // swap_fee_bps
+        + (1 + 8)               // max_staleness_secs
+        + (1 + 2)               // max_confidence_bps
+        + (1 + 8)               // quote_validity_secs
+        + (1 + 4 + 12)          // feed_symbol
+        + (1 + 4 + MAX_DESCRIPTION_LEN) // description
+        + (1 + 2)               // max_price_move_bps
+        + (1 + 8)               // price_move_window_secs
+        + (1 + 1)               // pricing_mode
+        + (1 + 8)               // config_timelock_secs
+        + (1 + 2)               // manual_price_band_bps
+        + (1 + 2)               // cpi_max_change_bps
+        + (1 + CpiAttesterSet::LEN) // cpi_attesters
+        + (1 + 1)               // min_kyc_tier
+        + (1 + 8)               // global_mint_cap_usd
+        + (1 + 8)               // global_redeem_cap_usd
+        + (1 + 8)               // user_mint_cap_usd
+        + (1 + 8)               // user_redeem_cap_usd
+        + (1 + PegFeeCurve::LEN); // peg_fee_curve
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+// ── CPI attesters ────────────────────────────────────────────────────────────
+
+/// M-of-N attesters for CPI updates. Each attester signs
+/// `(reference_month, multiplier)` through the Ed25519 precompile.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug, Default)]
+pub struct CpiAttesterSet {
+    /// Attester signing keys (0..=MAX_CPI_ATTESTERS, distinct)
+    pub attesters:     Vec<Pubkey>,
+    /// Signatures required (M); 0 only with no attesters
+    pub threshold:     u8,
+    /// Max spread between the attested multipliers, in bps of their median
+    pub tolerance_bps: u16,
+}
+
+impl CpiAttesterSet {
+    pub const LEN: usize = 4 + MAX_CPI_ATTESTERS * 32 + 1 + 2;
+
+    /// Whether CPI updates need attestations at all.
+    pub fn enabled(&self) -> bool {
+        self.threshold > 0
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let n = self.attesters.len();
+        require!(
+            n <= MAX_CPI_ATTESTERS
+                && (self.threshold as usize) <= n
+                && (self.threshold > 0) == (n > 0)
+                && self.tolerance_bps <= MAX_CPI_ATTESTATION_TOLERANCE_BPS,
+            OracleError::InvalidAttesterSet
+        );
+        for (i, key) in self.attesters.iter().enumerate() {
+            require!(!self.attesters[..i].contains(key), OracleError::InvalidAttesterSet);
+        }
+        Ok(())
+    }
+}
+
+// ── Peg fee curve ────────────────────────────────────────────────────────────
+
+/// Maximum number of points on a peg fee curve
+pub const MAX_FEE_CURVE_POINTS: usize = 8;
+
+/// One point of a peg fee curve: the fees at a market deviation from peg.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FeeCurvePoint {
+    /// Market price vs. the oracle's fair value, in bps (negative = below peg)
+    pub deviation_bps:  i32,
+    pub mint_fee_bps:   u16,
+    pub redeem_fee_bps: u16,
+}
+
+impl FeeCurvePoint {
+    pub const LEN: usize = 4 + 2 + 2;
+}
+
+/// Mint and redeem fees as piecewise-linear functions of how far the token's
+/// secondary market trades from peg, e.g. cheaper redemptions and dearer
+/// mints below peg. Between points the fees are interpolated (rounded up);
+/// beyond the ends they stay at the end values.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug, Default)]
+pub struct PegFeeCurve {
+    /// Registered feed quoting the token's market price in quote currency per whole token
+    pub market_feed_symbol: String,
+    /// 0 (curve off) or 2..=MAX_FEE_CURVE_POINTS points, strictly increasing deviation
+    pub points:             Vec<FeeCurvePoint>,
+}
+
+impl PegFeeCurve {
+    pub const LEN: usize = 4 + 12 + 4 + MAX_FEE_CURVE_POINTS * FeeCurvePoint::LEN;
+
+    /// Whether quotes price their fee off the curve.
+    pub fn enabled(&self) -> bool {
+        !self.points.is_empty()
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if !self.enabled() {
+            return Ok(());
+        }
+        require!(
+            (2..=MAX_FEE_CURVE_POINTS).contains(&self.points.len())
+                && !self.market_feed_symbol.is_empty()
+                && self.market_feed_symbol.len() <= 12,
+            OracleError::InvalidFeeCurve
+        );
+        for (i, point) in self.points.iter().enumerate() {
+            require!(
+                point.mint_fee_bps <= MAX_FEE_BPS && point.redeem_fee_bps <= MAX_FEE_BPS,
+                OracleError::FeeTooHigh
+            );
+            require!(
+                i == 0 || self.points[i - 1].deviation_bps < point.deviation_bps,
+                OracleError::InvalidFeeCurve
+            );
+        }
+        Ok(())
+    }
+
+    /// Fee in bps for `direction` at `deviation_bps`.
+    pub fn fee_bps_at(&self, direction: &QuoteDirection, deviation_bps: i64) -> Result<u16> {
+        let fee = |p: &FeeCurvePoint| match direction {
+            QuoteDirection::Mint   => p.mint_fee_bps,
+            QuoteDirection::Redeem => p.redeem_fee_bps,
+        };
+        require!(self.enabled(), OracleError::InvalidFeeCurve);
+        // First point at or past the deviation; the last one beyond the curve
+        let i = self
+            .points
+            .iter()
+            .position(|p| p.deviation_bps as i64 >= deviation_bps)
+            .unwrap_or(self.points.len() - 1);
+        if i == 0 {
+            return Ok(fee(&self.points[0]));
+        }
+
+        let (lower, upper) = (&self.points[i - 1], &self.points[i]);
+        let bps = interpolate_linear(
+            fee(lower) as u64,
+            fee(upper) as u64,
+            lower.deviation_bps as i64,
+            upper.deviation_bps as i64,
+            deviation_bps,
+            Rounding::Up,
+        )?;
+        u16::try_from(bps).map_err(|_| error!(OracleError::MathOverflow))
+    }
+}
+
+// ── Fee splits ───────────────────────────────────────────────────────────────
+
+/// Maximum number of fee-split recipients
+pub const MAX_FEE_SPLITS: usize = 4;
+
+/// A revenue-share recipient: receives `bps` of every fee withdrawal, the
+/// remainder goes to the treasury.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub struct FeeSplit {
+    /// Recipient token account (same mint)
+    pub recipient: Pubkey,
+    /// Share of each withdrawal in bps
+    pub bps:       u16,
+}
+
+impl FeeSplit {
+    pub const LEN: usize = 32 + 2;
+}
+
+/// Why the oracle is paused
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PauseReason {
+    /// Not paused
+    None,
+    /// Paused by the pauser via `pause_oracle`
+    Manual,
+    /// A feed reading moved beyond `max_price_move_bps` of the last good price
+    CircuitBreaker,
+}
+
+/// Price a quote is computed from
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PricingMode {
+    /// Median of the current source readings
+    Spot,
+    /// Time-weighted average from the feed's `PriceAccumulator`
+    Twap,
+    /// Exponential moving average from the feed's `PriceAccumulator`
+    Ema,
+    /// Spot or TWAP, whichever gives the smaller output for the direction
+    Conservative,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PriceAccumulator — Per-feed TWAP/EMA state, advanced by a permissionless crank
+// PDA seed: ["sss-accumulator", feed_symbol]
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Ring buffer length for cumulative-price observations
+pub const MAX_OBSERVATIONS: usize = 24;
+
+#[account]
+pub struct PriceAccumulator {
+    /// Registry feed this accumulator tracks
+    pub feed_symbol:              String,
+    /// Latest cranked median price (fixed-point, feed decimals)
+    pub last_price:               u64,
+    /// Timestamp of `last_price`
+    pub last_update:              i64,
+    /// Σ price * seconds since the first crank, up to `last_update`
+    pub cumulative_price:         u128,
+    /// Exponential moving average (fixed-point, feed decimals)
+    pub ema_price:                u64,
+    /// TWAP lookback window in seconds
+    pub twap_window_secs:         i64,
+    /// EMA time constant in seconds
+    pub ema_period_secs:          i64,
+    /// Minimum seconds between cranks
+    pub min_update_interval_secs: i64,
+    /// Per-source staleness limit applied by the crank
+    pub max_staleness_secs:       i64,
+    /// Per-source confidence limit applied by the crank
+    pub max_confidence_bps:       u16,
+    /// Ring buffer of (timestamp, cumulative) observations
+    pub observations:             [Observation; MAX_OBSERVATIONS],
+    /// Slot the next observation is written to
+    pub observation_index:        u8,
+    /// Number of observations written (≤ MAX_OBSERVATIONS)
+    pub observation_count:        u8,
+    /// PDA bump
+    pub bump:                     u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct Observation {
+    pub timestamp:        i64,
+    pub cumulative_price: u128,
+}
+
+impl Observation {
+    pub const LEN: usize = 8 + 16;
+}
+
+impl PriceAccumulator {
+    pub const LEN: usize = 8   // discriminator
+        + 4 + 12                // feed_symbol
+        + 8                     // last_price
+        + 8                     // last_update
+        + 16                    // cumulative_price
+        + 8                     // ema_price
+        + 8                     // twap_window_secs
+        + 8                     // ema_period_secs
+        + 8                     // min_update_interval_secs
+        + 8                     // max_staleness_secs
+        + 2                     // max_confidence_bps
+        + MAX_OBSERVATIONS * Observation::LEN // observations
+        + 1                     // observation_index
+        + 1                     // observation_count
+        + 1                     // bump
+        + 32;                   // slack
+
+    /// Cumulative price extrapolated to `now`, holding `last_price`.
+    pub fn cumulative_at(&self, now: i64) -> Option<u128> {
+        let dt = now.checked_sub(self.last_update).filter(|dt| *dt >= 0)? as u128;
+        self.cumulative_price.checked_add((self.last_price as u128).checked_mul(dt)?)
+    }
+
+    /// Newest observation at least `twap_window_secs` before `now`.
+    pub fn window_start(&self, now: i64) -> Option<Observation> {
+        let cutoff = now.checked_sub(self.twap_window_secs)?;
+        self.observations[..self.observation_count as usize]
+            .iter()
+            .filter(|o| o.timestamp <= cutoff)
+            .max_by_key(|o| o.timestamp)
+            .copied()
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // PendingQuote — On-chain quote, consumed atomically by mint/redeem
 // PDA seed: ["sss-quote", mint.key(), requester.key(), nonce_bytes]
@@ -192,14 +905,22 @@ pub struct PendingQuote {
     pub direction:        QuoteDirection,
     /// Feed symbol used
     pub feed_symbol:      String,
-    /// Input amount (USD cents for mint, token units for redeem)
+    /// Input amount (quote units for mint, token units for redeem)
     pub input_amount:     u64,
-    /// Output amount (token units for mint, USD cents for redeem)
+    /// Output amount (token units for mint, quote units for redeem)
     pub output_amount:    u64,
     /// Fee amount in output units
     pub fee_amount:       u64,
-    /// Exact price snapshot (fixed-point * 1_000_000)
+    /// Exact price snapshot (fixed-point, feed decimals)
     pub price_snapshot:   u64,
+    /// Decimals of `price_snapshot`
+    pub price_decimals:   u8,
+    /// How `price_snapshot` was derived
+    pub pricing_mode:     PricingMode,
+    /// Source accounts whose readings made up the median price
+    pub sources_used:     Vec<Pubkey>,
+    /// Priced off the emergency manual price instead of the sources
+    pub manual_override:  bool,
     /// Unix timestamp after which this quote is invalid
     pub valid_until:      i64,
     /// Minimum acceptable output (slippage protection)
@@ -224,6 +945,10 @@ impl PendingQuote {
         + 8                     // output_amount
         + 8                     // fee_amount
         + 8                     // price_snapshot
+        + 1                     // price_decimals
+        + 1                     // pricing_mode
+        + (4 + MAX_QUOTE_SOURCES * 32) // sources_used
+        + 1                     // manual_override
         + 8                     // valid_until
         + 8                     // min_output
         + 1                     // used
@@ -243,6 +968,298 @@ pub enum QuoteDirection {
     Redeem,
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// PendingSwapQuote — On-chain cross-stablecoin swap quote, consumed by swap_with_oracle
+// PDA seed: ["sss-swap-quote", mint_in.key(), requester.key(), nonce_bytes]
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[account]
+pub struct PendingSwapQuote {
+    /// SSS mint being swapped out of (burned)
+    pub mint_in:            Pubkey,
+    /// SSS mint being swapped into (minted)
+    pub mint_out:           Pubkey,
+    /// Who requested the quote
+    pub requester:          Pubkey,
+    /// Feed symbol pricing `mint_in`
+    pub feed_symbol_in:     String,
+    /// Feed symbol pricing `mint_out`
+    pub feed_symbol_out:    String,
+    /// Tokens of `mint_in` to burn (base units)
+    pub input_amount:       u64,
+    /// Tokens of `mint_out` minted to the requester after fees (base units)
+    pub output_amount:      u64,
+    /// Swap fee minted to the `mint_out` fee vault (base units)
+    pub fee_amount:         u64,
+    /// Value of the input in the `quote_denomination` both oracles share
+    /// (not necessarily USD)
+    pub usd_value:          u64,
+    /// Price snapshot of the input feed (fixed-point, feed decimals)
+    pub price_in:           u64,
+    /// Decimals of `price_in`
+    pub price_decimals_in:  u8,
+    /// Price snapshot of the output feed (fixed-point, feed decimals)
+    pub price_out:          u64,
+    /// Decimals of `price_out`
+    pub price_decimals_out: u8,
+    /// Either leg priced off an emergency manual price
+    pub manual_override:    bool,
+    /// Unix timestamp after which this quote is invalid
+    pub valid_until:        i64,
+    /// Minimum acceptable output (slippage protection)
+    pub min_output:         u64,
+    /// Creation timestamp
+    pub created_at:         i64,
+    /// Caller nonce to prevent PDA collision
+    pub nonce:              u64,
+    /// PDA bump
+    pub bump:               u8,
+}
+
+impl PendingSwapQuote {
+    pub const LEN: usize = 8   // discriminator
+        + 32                    // mint_in
+        + 32                    // mint_out
+        + 32                    // requester
+        + (4 + 12)              // feed_symbol_in
+        + (4 + 12)              // feed_symbol_out
+        + 8                     // input_amount
+        + 8                     // output_amount
+        + 8                     // fee_amount
+        + 8                     // usd_value
+        + 8                     // price_in
+        + 1                     // price_decimals_in
+        + 8                     // price_out
+        + 1                     // price_decimals_out
+        + 1                     // manual_override
+        + 8                     // valid_until
+        + 8                     // min_output
+        + 8                     // created_at
+        + 8                     // nonce
+        + 1                     // bump
+        + 32;                   // slack
+
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        current_time > self.valid_until
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PsmCollateral — One collateral accepted by a mint's peg stability module
+// PDA seed: ["sss-psm", mint.key(), collateral_mint.key()]
+//
+// Collateral is held in a vault token account owned by the oracle config PDA
+// (PDA seed: ["sss-psm-vault", mint.key(), collateral_mint.key()]) and is
+// valued at par in USD.
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[account]
+pub struct PsmCollateral {
+    /// The SSS token mint
+    pub mint:                Pubkey,
+    /// Accepted collateral mint (a USD stablecoin, e.g. USDC)
+    pub collateral_mint:     Pubkey,
+    /// Decimals of `collateral_mint`
+    pub collateral_decimals: u8,
+    /// Vault token account holding the deposited collateral
+    pub vault:               Pubkey,
+    /// Max USD value of SSS tokens outstanding against this collateral, in the
+    /// oracle's quote decimals (PSM oracles always quote in USD)
+    pub debt_ceiling_usd:    u64,
+    /// USD value of SSS tokens outstanding against this collateral, in the
+    /// oracle's quote decimals.
+    /// Equals the par value of the collateral held in the vault.
+    pub debt_usd:            u64,
+    /// Lifetime collateral deposited (collateral base units)
+    pub total_deposited:     u64,
+    /// Lifetime collateral released (collateral base units)
+    pub total_released:      u64,
+    /// Whether new PSM mints are accepted (redemptions always are)
+    pub active:              bool,
+    /// Creation timestamp
+    pub created_at:          i64,
+    /// Last modification timestamp
+    pub updated_at:          i64,
+    /// PDA bump
+    pub bump:                u8,
+}
+
+impl PsmCollateral {
+    pub const LEN: usize = 8   // discriminator
+        + 32                    // mint
+        + 32                    // collateral_mint
+        + 1                     // collateral_decimals
+        + 32                    // vault
+        + 8                     // debt_ceiling_usd
+        + 8                     // debt_usd
+        + 8                     // total_deposited
+        + 8                     // total_released
+        + 1                     // active
+        + 8                     // created_at
+        + 8                     // updated_at
+        + 1                     // bump
+        + 32;                   // slack
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// UsageWindow — Rolling quote volume for the issuance caps
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Length of the rolling window the issuance caps apply to
+pub const USAGE_WINDOW_SECS: i64 = 86_400;
+
+/// Volume in the oracle's `quote_denomination` units over the trailing `USAGE_WINDOW_SECS`, kept as a sliding-window
+/// counter: the volume of the current day-aligned window plus the part of
+/// the previous window's that still falls in the trailing one.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct UsageWindow {
+    /// Start of the current window
+    pub window_start: i64,
+    /// `quote_denomination` units recorded in the current window
+    pub current_usd:  u64,
+    /// `quote_denomination` units recorded in the window before it
+    pub previous_usd: u64,
+}
+
+impl UsageWindow {
+    pub const LEN: usize = 8 + 8 + 8;
+
+    /// The counter advanced to the window containing `now`.
+    fn rolled(&self, now: i64) -> Self {
+        let start = now - now.rem_euclid(USAGE_WINDOW_SECS);
+        match start - self.window_start {
+            0                 => *self,
+            USAGE_WINDOW_SECS => Self { window_start: start, current_usd: 0, previous_usd: self.current_usd },
+            _                 => Self { window_start: start, current_usd: 0, previous_usd: 0 },
+        }
+    }
+
+    /// Quote units over the trailing window if `amount_usd` were added now.
+    pub fn usage_with(&self, amount_usd: u64, now: i64) -> Result<u64> {
+        let w = self.rolled(now);
+        sliding_window_usage(w.current_usd, w.previous_usd, now - w.window_start, USAGE_WINDOW_SECS)?
+            .checked_add(amount_usd)
+            .ok_or_else(|| error!(OracleError::MathOverflow))
+    }
+
+    /// Record `amount_usd` at `now`.
+    pub fn record(&mut self, amount_usd: u64, now: i64) -> Result<()> {
+        let mut w = self.rolled(now);
+        w.current_usd = w.current_usd.checked_add(amount_usd).ok_or(OracleError::MathOverflow)?;
+        *self = w;
+        Ok(())
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// UserUsage — One requester's rolling volume through a mint's oracle
+// PDA seed: ["sss-usage", mint.key(), user.key()]
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[account]
+pub struct UserUsage {
+    /// The SSS token mint
+    pub mint:          Pubkey,
+    /// The requester this usage belongs to
+    pub user:          Pubkey,
+    /// Value minted in the rolling window (`quote_denomination` units)
+    pub mint_usage:    UsageWindow,
+    /// Value redeemed in the rolling window (`quote_denomination` units)
+    pub redeem_usage:  UsageWindow,
+    /// Creation timestamp
+    pub created_at:    i64,
+    /// PDA bump
+    pub bump:          u8,
+}
+
+impl UserUsage {
+    pub const LEN: usize = 8   // discriminator
+        + 32                    // mint
+        + 32                    // user
+        + UsageWindow::LEN      // mint_usage
+        + UsageWindow::LEN      // redeem_usage
+        + 8                     // created_at
+        + 1                     // bump
+        + 16;                   // slack
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// RedemptionSettlement — Fiat payout owed for an executed redeem quote
+// PDA seed: ["sss-settlement", mint.key(), redeemer.key(), nonce_bytes]
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Maximum length of the off-chain payout reference (wire id, PIX id, ...)
+pub const MAX_PAYOUT_REFERENCE: usize = 64;
+
+#[account]
+pub struct RedemptionSettlement {
+    /// The SSS token mint
+    pub mint:             Pubkey,
+    /// Who redeemed the tokens
+    pub redeemer:         Pubkey,
+    /// Tokens taken from the redeemer (token base units)
+    pub token_amount:     u64,
+    /// Amount owed to the redeemer after fees, in the oracle's `quote_denomination`
+    /// units despite the `usd_` prefix
+    pub usd_owed:         u64,
+    /// Redeem fee withheld (`quote_denomination` units)
+    pub fee_amount:       u64,
+    /// Price snapshot from the consumed quote (fixed-point, feed decimals)
+    pub price_used:       u64,
+    /// Feed symbol used
+    pub feed_symbol:      String,
+    /// Whether the fiat payout has been confirmed by the oracle admin
+    pub settled:          bool,
+    /// Off-chain payout reference recorded on confirmation
+    pub payout_reference: String,
+    /// Redemption timestamp
+    pub created_at:       i64,
+    /// Payout confirmation timestamp (0 until settled)
+    pub settled_at:       i64,
+    /// Nonce of the consumed quote
+    pub nonce:            u64,
+    /// PDA bump
+    pub bump:             u8,
+}
+
+impl RedemptionSettlement {
+    pub const LEN: usize = 8   // discriminator
+        + 32                    // mint
+        + 32                    // redeemer
+        + 8                     // token_amount
+        + 8                     // usd_owed
+        + 8                     // fee_amount
+        + 8                     // price_used
+        + (4 + 12)              // feed_symbol
+        + 1                     // settled
+        + (4 + MAX_PAYOUT_REFERENCE) // payout_reference
+        + 8                     // created_at
+        + 8                     // settled_at
+        + 8                     // nonce
+        + 1                     // bump
+        + 32;                   // slack
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SourceReport — Per-source reading reported with every quote (not an account)
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SourceReport {
+    /// Source account
+    pub source:         Pubkey,
+    /// Provider of the source account
+    pub kind:           SourceKind,
+    /// Price read (fixed-point, feed decimals), 0 if unreadable
+    pub price:          u64,
+    /// Confidence as bps of price, 0 if unreadable
+    pub confidence_bps: u64,
+    /// Seconds since the source's last update, -1 if unreadable
+    pub age_secs:       i64,
+    /// Whether the reading passed staleness/confidence and entered the median
+    pub used:           bool,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // QuoteResult — Instruction return value (not an account)
 // ═══════════════════════════════════════════════════════════════════════════
@@ -254,4 +1271,66 @@ pub struct QuoteResult {
     pub price_used:     u64,
     pub valid_until:    i64,
     pub quote_account:  Pubkey,
+    /// True when the reading tripped the circuit breaker: the oracle is now
+    /// paused, no quote was stored and the amounts are zero
+    pub tripped:        bool,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SwapQuoteResult — Instruction return value (not an account)
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SwapQuoteResult {
+    pub output_amount:  u64,
+    pub fee_amount:     u64,
+    pub price_in:       u64,
+    pub price_out:      u64,
+    pub valid_until:    i64,
+    pub quote_account:  Pubkey,
+    /// True when either feed tripped its oracle's circuit breaker: that
+    /// oracle is now paused, no quote was stored and the amounts are zero
+    pub tripped:        bool,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PriceDiagnostics — Return value of get_price_diagnostics (not an account)
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PriceDiagnostics {
+    /// Median of every readable source, ignoring the staleness and
+    /// confidence limits (feed decimals); 0 if no source is readable
+    pub raw_price:           u64,
+    /// Decimals of `raw_price` and `price_used`
+    pub price_decimals:      u8,
+    /// Confidence of `raw_price` as bps of it
+    pub confidence_bps:      u64,
+    /// Seconds since the oldest reading in `raw_price`, -1 if none
+    pub age_secs:            i64,
+    pub feed_type:           FeedType,
+    pub pricing_mode:        PricingMode,
+    /// An emergency manual price is in force; the sources are not used
+    pub manual_override:     bool,
+    /// Price a quote would use now; 0 if it would fail
+    pub price_used:          u64,
+    /// Fee for the direction asked: off the peg fee curve when set, else the flat fee
+    pub fee_bps:             u16,
+    /// Output before the fee (token units for mint, quote units for redeem)
+    pub gross_output:        u64,
+    pub fee_amount:          u64,
+    pub net_output:          u64,
+    /// Per-source readings behind `raw_price`
+    pub sources:             Vec<SourceReport>,
+    /// The oracle is paused
+    pub paused:              bool,
+    /// A quote would fail on staleness
+    pub stale:               bool,
+    /// A quote would fail on the confidence limit
+    pub confidence_too_wide: bool,
+    /// A quote would trip the circuit breaker on the current reading
+    pub breaker_would_trip:  bool,
+    /// Error code a quote would fail with now: 0 if it would succeed,
+    /// u32::MAX for a runtime error without an Anchor code
+    pub error_code:          u32,
 }
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use switchboard_on_demand::{PullFeedAccountData, Discriminator as _};
 use crate::errors::OracleError;
+use crate::math::ScaledDecimal;
 
 // ─── Constants ───────────────────────────────────────────────────────────────
 
@@ -12,9 +13,8 @@ const MAX_PRICE_AGE_SECONDS: i64 = 60;
 /// A round with zero successes should never be trusted.
 const MIN_ROUND_SUCCESS: u32 = 1;
 
-/// Maximum allowed decimal scale. i128→f64 precision is lost beyond
-/// ~18 significant digits anyway, and scale > 38 will make divisor
-/// overflow to f64::INFINITY, silently zeroing the result.
+/// Maximum allowed decimal scale. On-Demand feeds use 18; anything larger
+/// is treated as a malformed account rather than silently rescaled.
 const MAX_DECIMAL_SCALE: u32 = 18;
 
 /// Minimum account size we expect for a Switchboard V2 aggregator.
@@ -24,14 +24,17 @@ const MIN_AGGREGATOR_SIZE: usize = 500;
 // ─── Output type ─────────────────────────────────────────────────────────────
 
 /// Parsed, validated price data from a Switchboard feed.
+///
+/// Values are kept as exact decimals straight from the account bytes —
+/// no floating point is involved. Rescale with `ScaledDecimal::to_price_scaled`.
 #[derive(Debug, Clone, Copy)]
 pub struct SwitchboardPrice {
-    /// The latest confirmed result value (always > 0.0 after validation).
-    pub value: f64,
+    /// The latest confirmed result value (always > 0 after validation).
+    pub value: ScaledDecimal,
     /// Standard deviation / confidence interval.
-    /// For V3 On-Demand feeds this is sourced from `result.std_deviation`
+    /// For V3 On-Demand feeds this is sourced from `result.std_dev`
     /// rather than the `max_variance` config field.
-    pub std_deviation: f64,
+    pub std_deviation: ScaledDecimal,
     /// Unix timestamp of the latest confirmed round (or last update for V3).
     pub round_open_timestamp: i64,
 }
@@ -53,25 +56,33 @@ pub struct SwitchboardPrice {
 /// # Errors
 /// Returns `OracleError::FeedNotReady`  – bad discriminator / too small / zero successes
 /// Returns `OracleError::PriceStale`    – price older than `MAX_PRICE_AGE_SECONDS`
-/// Returns `OracleError::InvalidPrice`  – value ≤ 0 or non-finite
-/// Returns `OracleError::InvalidFeedData` – scale out of range
-/// Returns `OracleError::DivisionByZero` – (shouldn't happen with scale guard, kept as safety net)
+/// Returns `OracleError::InvalidPrice`  – value ≤ 0
+/// Returns `OracleError::FeedNotReady`  – scale out of range
 pub fn read_switchboard_price(account_data: &[u8]) -> Result<SwitchboardPrice> {
+    let price = parse_switchboard_price(account_data)?;
+    validate_switchboard_price(&price, Clock::get()?.unix_timestamp)?;
+    Ok(price)
+}
+
+/// Parse a Switchboard V2 or V3 On-Demand account without any clock-based
+/// validation. Callers that need to report a reading even when it is stale
+/// (e.g. multi-source aggregation) parse first and validate separately with
+/// `validate_switchboard_price`.
+pub fn parse_switchboard_price(account_data: &[u8]) -> Result<SwitchboardPrice> {
     // Need at least 8 bytes to read the discriminator.
     require!(account_data.len() >= 8, OracleError::FeedNotReady);
 
-    let price = if account_data.starts_with(PullFeedAccountData::DISCRIMINATOR) {
-        read_ondemand_pull_feed(account_data)?
+    if account_data.starts_with(PullFeedAccountData::DISCRIMINATOR) {
+        read_ondemand_pull_feed(account_data)
     } else {
-        read_v2_aggregator_feed(account_data)?
-    };
-
-    // ── Common post-parse validation ──────────────────────────────────────
+        read_v2_aggregator_feed(account_data)
+    }
+}
 
+/// Common post-parse validation shared by the V2 and V3 paths.
+pub fn validate_switchboard_price(price: &SwitchboardPrice, now: i64) -> Result<()> {
     // 1. Staleness check — applies to both V2 and V3.
-    let clock = Clock::get()?;
-    let age = clock
-        .unix_timestamp
+    let age = now
         .checked_sub(price.round_open_timestamp)
         .unwrap_or(i64::MAX); // overflow → treat as infinitely old
 
@@ -79,10 +90,9 @@ pub fn read_switchboard_price(account_data: &[u8]) -> Result<SwitchboardPrice> {
     require!(age <= MAX_PRICE_AGE_SECONDS, OracleError::PriceTooStale);
 
     // 2. Sanity-check the value itself.
-    require!(price.value.is_finite(), OracleError::InvalidPrice);
-    require!(price.value > 0.0, OracleError::InvalidPrice);
+    require!(price.value.mantissa > 0, OracleError::InvalidPrice);
 
-    Ok(price)
+    Ok(())
 }
 
 // ─── V3 On-Demand path ───────────────────────────────────────────────────────
@@ -100,11 +110,11 @@ fn read_ondemand_pull_feed(account_data: &[u8]) -> Result<SwitchboardPrice> {
     // On-Demand feeds use a fixed precision of 18 as per switchboard_on_demand crate
     let scale = 18;
 
-    // result is of type CurrentResult 
-    let value = decimal_to_f64(feed.result.value, scale)?;
-    
-    // We can also extract standard deviation from feed.result.std_dev
-    let std_deviation = decimal_to_f64(feed.result.std_dev, scale).unwrap_or(0.0);
+    // result is of type CurrentResult
+    let value = checked_decimal(feed.result.value, scale)?;
+
+    // Standard deviation shares the same precision
+    let std_deviation = checked_decimal(feed.result.std_dev, scale)?;
 
     Ok(SwitchboardPrice {
         value,
@@ -170,12 +180,8 @@ fn read_v2_aggregator_feed(account_data: &[u8]) -> Result<SwitchboardPrice> {
 
 /// Read a `SwitchboardDecimal` (i128 mantissa + u32 scale) starting at `offset`.
 ///
-/// `value = mantissa / 10^scale`
-///
-/// Precision note: casting i128 → f64 loses precision for mantissas with more
-/// than ~15–16 significant decimal digits.  This is acceptable for typical
-/// price feeds but callers should be aware of the trade-off.
-fn read_switchboard_decimal(data: &[u8], offset: usize) -> Result<f64> {
+/// `value = mantissa / 10^scale`, kept exact.
+fn read_switchboard_decimal(data: &[u8], offset: usize) -> Result<ScaledDecimal> {
     require!(
         data.len() >= offset + 20,
         OracleError::FeedNotReady
@@ -193,26 +199,16 @@ fn read_switchboard_decimal(data: &[u8], offset: usize) -> Result<f64> {
             .map_err(|_| error!(OracleError::FeedNotReady))?,
     );
 
-    decimal_to_f64(mantissa, scale)
+    checked_decimal(mantissa, scale)
 }
 
-/// Convert a (mantissa, scale) pair to f64.
+/// Build a `ScaledDecimal` from a (mantissa, scale) pair, rejecting
+/// out-of-range scales.
 ///
 /// Shared by both V2 and V3 paths.
-fn decimal_to_f64(mantissa: i128, scale: u32) -> Result<f64> {
-    // Guard against scale values that would push the divisor to infinity,
-    // silently zeroing the result.
+fn checked_decimal(mantissa: i128, scale: u32) -> Result<ScaledDecimal> {
     require!(scale <= MAX_DECIMAL_SCALE, OracleError::FeedNotReady);
-
-    let divisor = 10f64.powi(scale as i32);
-
-    // With the scale guard above, divisor is always a normal positive f64.
-    // The explicit check is kept as a safety net.
-    if divisor == 0.0 || !divisor.is_finite() {
-        return Err(error!(OracleError::DivisionByZero));
-    }
-
-    Ok(mantissa as f64 / divisor)
+    Ok(ScaledDecimal::new(mantissa, scale))
 }
 
 /// Read a little-endian `u32` at `offset`.
@@ -240,34 +236,36 @@ fn read_i64(data: &[u8], offset: usize) -> Result<i64> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::math::Rounding;
 
-    // ── decimal_to_f64 ────────────────────────────────────────────────────
+    // ── checked_decimal ───────────────────────────────────────────────────
 
     #[test]
-    fn test_decimal_to_f64_basic() {
-        // 12345 / 10^2 = 123.45
-        let result = decimal_to_f64(12345, 2).unwrap();
-        assert!((result - 123.45).abs() < 1e-9);
+    fn test_checked_decimal_basic() {
+        // 12345 / 10^2 = 123.45 → 123_450_000 at PRICE_SCALE
+        let result = checked_decimal(12345, 2).unwrap();
+        assert_eq!(result.to_price_scaled(Rounding::Down).unwrap(), 123_450_000);
     }
 
     #[test]
-    fn test_decimal_to_f64_zero_scale() {
-        // 42 / 10^0 = 42.0
-        let result = decimal_to_f64(42, 0).unwrap();
-        assert!((result - 42.0).abs() < 1e-9);
+    fn test_checked_decimal_zero_scale() {
+        // 42 / 10^0 = 42
+        let result = checked_decimal(42, 0).unwrap();
+        assert_eq!(result.to_price_scaled(Rounding::Down).unwrap(), 42_000_000);
     }
 
     #[test]
-    fn test_decimal_to_f64_negative_mantissa() {
-        // -5000 / 10^3 = -5.0
-        let result = decimal_to_f64(-5000, 3).unwrap();
-        assert!((result - (-5.0)).abs() < 1e-9);
+    fn test_checked_decimal_negative_mantissa() {
+        // -5000 / 10^3 = -5.0 — kept exact, rejected on rescale
+        let result = checked_decimal(-5000, 3).unwrap();
+        assert_eq!(result, ScaledDecimal::new(-5000, 3));
+        assert!(result.to_price_scaled(Rounding::Down).is_err());
     }
 
     #[test]
-    fn test_decimal_to_f64_scale_too_large() {
+    fn test_checked_decimal_scale_too_large() {
         // scale > MAX_DECIMAL_SCALE should error
-        assert!(decimal_to_f64(1, MAX_DECIMAL_SCALE + 1).is_err());
+        assert!(checked_decimal(1, MAX_DECIMAL_SCALE + 1).is_err());
     }
 
     // ── read_u32 / read_i64 ───────────────────────────────────────────────
@@ -306,7 +304,7 @@ mod tests {
         data[16..20].copy_from_slice(&scale.to_le_bytes());
 
         let value = read_switchboard_decimal(&data, 0).unwrap();
-        assert!((value - 1.0).abs() < 1e-9, "got {}", value);
+        assert_eq!(value, ScaledDecimal::new(1_000_000, 6));
     }
 
     #[test]
@@ -334,7 +332,7 @@ mod tests {
     #[test]
     fn test_v2_valid_round() {
         const ROUND_BASE: usize = 208;
-        let mut data = vec![0u8; ROUND_BASE + 100];
+        let mut data = vec![0u8; MIN_AGGREGATOR_SIZE + 100];
 
         // num_success = 3
         data[ROUND_BASE..ROUND_BASE + 4].copy_from_slice(&3u32.to_le_bytes());
@@ -356,8 +354,8 @@ mod tests {
         data[ROUND_BASE + 61..ROUND_BASE + 65].copy_from_slice(&std_scale.to_le_bytes());
 
         let price = read_v2_aggregator_feed(&data).unwrap();
-        assert!((price.value - 50.0).abs() < 1e-6, "value: {}", price.value);
-        assert!((price.std_deviation - 0.01).abs() < 1e-9, "std_dev: {}", price.std_deviation);
+        assert_eq!(price.value.to_price_scaled(Rounding::Down).unwrap(), 50_000_000);
+        assert_eq!(price.std_deviation.to_price_scaled(Rounding::Up).unwrap(), 10_000);
         assert_eq!(price.round_open_timestamp, ts);
     }
 }
\ No newline at end of file
@@ -1,12 +1,15 @@
 import {
+    AccountMeta,
     Connection,
     PublicKey,
     Keypair,
     SystemProgram,
 } from '@solana/web3.js';
+import { TOKEN_2022_PROGRAM_ID, getAssociatedTokenAddressSync } from '@solana/spl-token';
 import { Program, BN, AnchorProvider } from '@coral-xyz/anchor';
 import { SolanaNetwork } from '../types';
 import oracleIdl from '../idl/sss_oracle.json';
+import coreIdl from '../idl/sss_core.json';
 import NodeWallet from '@coral-xyz/anchor/dist/cjs/nodewallet';
 import { parseProgramError } from '../errors';
 
@@ -16,6 +19,16 @@ export const PRICE_SCALE = 1_000_000;
 export const CPI_SCALE = 1_000_000;
 export const TOKEN_SCALE = 1_000_000;
 
+/** Integer division rounding up, matching the program's `Rounding::Up`. */
+function ceilDiv(numerator: bigint, denominator: bigint): bigint {
+    return (numerator + denominator - 1n) / denominator;
+}
+
+/** Read-only, non-signer account meta for `remainingAccounts`. */
+function readonlyMeta(pubkey: PublicKey): AccountMeta {
+    return { pubkey, isSigner: false, isWritable: false };
+}
+
 const NETWORK_RPC: Record<SolanaNetwork, string> = {
     [SolanaNetwork.DEVNET]: "https://api.devnet.solana.com",
     [SolanaNetwork.MAINNET]: "https://api.mainnet-beta.solana.com",
@@ -40,6 +53,26 @@ export enum FeedType {
     Custom = 'custom',
 }
 
+/** Provider whose account layout a price source uses. */
+export enum SourceKind {
+    Switchboard = 'switchboard',
+    Pyth = 'pyth',
+}
+
+/**
+ * Which price quotes use.
+ *
+ * - `Spot`: the median of the feed's sources.
+ * - `Twap` / `Ema`: the feed's price accumulator average.
+ * - `Conservative`: the safer of spot and the accumulator average.
+ */
+export enum PricingMode {
+    Spot = 'spot',
+    Twap = 'twap',
+    Ema = 'ema',
+    Conservative = 'conservative',
+}
+
 // ─── Known Switchboard feed addresses on devnet ──────────────────────────────
 
 export const KNOWN_FEEDS: Record<string, { devnet: string; mainnet: string; type: FeedType }> = {
@@ -62,22 +95,64 @@ export interface RegisterFeedParams {
     baseCurrency: string;
     quoteCurrency: string;
     decimals: number;
-    switchboardFeed: PublicKey;
+    /** Price source accounts (1..=3), in the order quotes pass them. */
+    sources: { account: PublicKey; kind: SourceKind }[];
+    /** Fresh sources required to quote (default: all of them). */
+    minSources?: number;
+    /** Max spread between sources in bps of their median (default 0 = no check). */
+    maxSpreadBps?: number;
+}
+
+/** One point of a peg-defense fee curve. */
+export interface FeeCurvePoint {
+    /** Market price vs. the oracle's fair value, in bps (negative = below peg). */
+    deviationBps: number;
+    mintFeeBps: number;
+    redeemFeeBps: number;
 }
 
-/** Parameters for initializing oracle config for a mint. */
+/**
+ * Parameters for initializing oracle config for a mint.
+ * Optional fields default to the most permissive valid setting.
+ */
 export interface InitializeOracleParams {
     mint: PublicKey;
     feedSymbol: string;
     description: string;
+    /** Unit of mint inputs, redeem outputs and caps (default USD cents). */
+    quoteDenomination?: { currency: string; decimals: number };
     maxStalenessSecs: number;
     mintFeeBps: number;
     redeemFeeBps: number;
+    /** Fee on swaps into this mint (default 0). */
+    swapFeeBps?: number;
     maxConfidenceBps: number;
     quoteValiditySecs: number;
     cpiMultiplier: number;
     cpiMinUpdateInterval: number;
+    /** Max change of one CPI update, in bps (default 2_000, the program's ceiling). */
+    cpiMaxChangeBps?: number;
+    /** M-of-N CPI attesters (default none: the CPI updater alone). */
+    cpiAttesters?: { attesters: PublicKey[]; threshold: number; toleranceBps: number };
     cpiDataSource: string;
+    /** Circuit breaker: max price move per window, in bps (default 0 = off). */
+    maxPriceMoveBps?: number;
+    priceMoveWindowSecs?: number;
+    /** Default `PricingMode.Spot`; other modes need the feed's accumulator. */
+    pricingMode?: PricingMode;
+    /** Delay for later risk-parameter updates (default 86_400). */
+    configTimelockSecs?: number;
+    /** Band for emergency manual prices, in bps (default 0 = disabled). */
+    manualPriceBandBps?: number;
+    /** Minimum sss-core allowlist KYC tier (default 0 = none). */
+    minKycTier?: number;
+    /** Rolling 24h caps in quote units (default 0 = none). */
+    globalMintCap?: number;
+    globalRedeemCap?: number;
+    userMintCap?: number;
+    userRedeemCap?: number;
+    /** Peg-defense fee curve (default off). */
+    pegFeeCurve?: { marketFeedSymbol: string; points: FeeCurvePoint[] };
 }
 
 /** Parameters for requesting a quote. */
@@ -119,7 +194,9 @@ export interface OracleInfo {
 /** Read-only snapshot of a registered feed. */
 export interface FeedInfo {
     symbol: string;
-    switchboardFeed: PublicKey;
+    /** First price source (`null` for composite feeds, whose legs carry the sources). */
+    switchboardFeed: PublicKey | null;
+    sources: { account: PublicKey; kind: SourceKind }[];
     feedType: string;
     baseCurrency: string;
     quoteCurrency: string;
@@ -177,6 +254,52 @@ export class OracleModule {
         );
     }
 
+    /**
+     * Derive the FeedEntry PDA for a feed symbol.
+     *
+     * @example
+     * ```ts
+     * const [feedPda] = OracleModule.findFeedPda('BRLUSD', programId);
+     * ```
+     */
+    static findFeedPda(symbol: string, programId: PublicKey): [PublicKey, number] {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("sss-feed"), Buffer.from(symbol)],
+            programId,
+        );
+    }
+
+    /**
+     * Build the message a CPI attester signs for `(oracleConfig, referenceMonth, multiplier)`.
+     * `oracleConfig` is the mint's OracleConfig PDA, which binds the attestation
+     * to that mint. Sign it with the attester's Ed25519 key and add an Ed25519
+     * program instruction to the `updateCpiMultiplier` transaction.
+     *
+     * @example
+     * ```ts
+     * const [oraclePda] = OracleModule.findOracleConfigPda(brlCpiMint, programId);
+     * const msg = OracleModule.cpiAttestationMessage(oraclePda, '2026-01', 1_083_000n);
+     * const ix  = Ed25519Program.createInstructionWithPrivateKey({ privateKey: attester.secretKey, message: msg });
+     * ```
+     */
+    static cpiAttestationMessage(
+        oracleConfig: PublicKey,
+        referenceMonth: string,
+        multiplier: bigint,
+    ): Buffer {
+        if (!/^\d{4}-(0[1-9]|1[0-2])$/.test(referenceMonth)) {
+            throw new Error(`Reference month must be YYYY-MM: ${referenceMonth}`);
+        }
+        const value = Buffer.alloc(8);
+        value.writeBigUInt64LE(multiplier);
+        return Buffer.concat([
+            Buffer.from("sss-oracle:cpi-attestation:v2"),
+            oracleConfig.toBuffer(),
+            Buffer.from(referenceMonth),
+            value,
+        ]);
+    }
+
     /**
      * Derive the OracleConfig PDA for a specific mint.
      *
@@ -192,6 +315,21 @@ export class OracleModule {
         );
     }
 
+    /**
+     * Derive the sss-core StablecoinConfig PDA for a mint.
+     *
+     * @example
+     * ```ts
+     * const [coreConfigPda] = OracleModule.findCoreConfigPda(mintAddress);
+     * ```
+     */
+    static findCoreConfigPda(mint: PublicKey): [PublicKey, number] {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("sss-config"), mint.toBuffer()],
+            new PublicKey(coreIdl.address),
+        );
+    }
+
     /**
      * Derive the PendingQuote PDA.
      *
@@ -220,6 +358,170 @@ export class OracleModule {
         );
     }
 
+    /**
+     * Derive the PendingSwapQuote PDA. Keyed by the input mint.
+     *
+     * @example
+     * ```ts
+     * const [swapQuotePda] = OracleModule.findSwapQuotePda(brlMint, requester, nonce, programId);
+     * ```
+     */
+    static findSwapQuotePda(
+        mintIn: PublicKey,
+        requester: PublicKey,
+        nonce: bigint,
+        programId: PublicKey,
+    ): [PublicKey, number] {
+        const nonceBuffer = Buffer.alloc(8);
+        nonceBuffer.writeBigUInt64LE(nonce);
+        return PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("sss-swap-quote"),
+                mintIn.toBuffer(),
+                requester.toBuffer(),
+                nonceBuffer,
+            ],
+            programId,
+        );
+    }
+
+    /**
+     * Derive the PsmCollateral PDA for one collateral of a mint's peg stability module.
+     *
+     * @example
+     * ```ts
+     * const [psmPda] = OracleModule.findPsmCollateralPda(mint, usdcMint, programId);
+     * ```
+     */
+    static findPsmCollateralPda(
+        mint: PublicKey,
+        collateralMint: PublicKey,
+        programId: PublicKey,
+    ): [PublicKey, number] {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("sss-psm"), mint.toBuffer(), collateralMint.toBuffer()],
+            programId,
+        );
+    }
+
+    /**
+     * Derive a requester's UserUsage PDA, which tracks their rolling issuance-cap usage.
+     *
+     * @example
+     * ```ts
+     * const [usagePda] = OracleModule.findUserUsagePda(mint, wallet, programId);
+     * ```
+     */
+    static findUserUsagePda(
+        mint: PublicKey,
+        user: PublicKey,
+        programId: PublicKey,
+    ): [PublicKey, number] {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("sss-usage"), mint.toBuffer(), user.toBuffer()],
+            programId,
+        );
+    }
+
+    /**
+     * Derive a feed's PriceAccumulator PDA, which non-Spot pricing modes read.
+     *
+     * @example
+     * ```ts
+     * const [accumulatorPda] = OracleModule.findAccumulatorPda('BRLUSD', programId);
+     * ```
+     */
+    static findAccumulatorPda(symbol: string, programId: PublicKey): [PublicKey, number] {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("sss-accumulator"), Buffer.from(symbol)],
+            programId,
+        );
+    }
+
+    /**
+     * Derive a mint's oracle fee vault PDA (a token account of the mint).
+     *
+     * @example
+     * ```ts
+     * const [feeVaultPda] = OracleModule.findFeeVaultPda(mint, programId);
+     * ```
+     */
+    static findFeeVaultPda(mint: PublicKey, programId: PublicKey): [PublicKey, number] {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("sss-fee-vault"), mint.toBuffer()],
+            programId,
+        );
+    }
+
+    /**
+     * Derive the RedemptionSettlement PDA a redeem quote's execution records.
+     * Uses the same nonce as the redeem quote.
+     *
+     * @example
+     * ```ts
+     * const [settlementPda] = OracleModule.findSettlementPda(mint, requester, nonce, programId);
+     * ```
+     */
+    static findSettlementPda(
+        mint: PublicKey,
+        requester: PublicKey,
+        nonce: bigint,
+        programId: PublicKey,
+    ): [PublicKey, number] {
+        const nonceBuffer = Buffer.alloc(8);
+        nonceBuffer.writeBigUInt64LE(nonce);
+        return PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("sss-settlement"),
+                mint.toBuffer(),
+                requester.toBuffer(),
+                nonceBuffer,
+            ],
+            programId,
+        );
+    }
+
+    /**
+     * Derive the sss-core MinterConfig PDA registered for a mint's oracle config.
+     *
+     * @example
+     * ```ts
+     * const [oraclePda]  = OracleModule.findOracleConfigPda(mint, programId);
+     * const [minterPda]  = OracleModule.findMinterConfigPda(mint, oraclePda);
+     * ```
+     */
+    static findMinterConfigPda(mint: PublicKey, oracleConfig: PublicKey): [PublicKey, number] {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("sss-minter"), mint.toBuffer(), oracleConfig.toBuffer()],
+            new PublicKey(coreIdl.address),
+        );
+    }
+
+    /**
+     * Derive a wallet's sss-core allowlist and blacklist entry PDAs for a mint.
+     * Oracle issuance checks them; the blacklist entry may not exist.
+     *
+     * @example
+     * ```ts
+     * const { allowlist, blacklist } = OracleModule.findComplianceEntryPdas(mint, wallet);
+     * ```
+     */
+    static findComplianceEntryPdas(
+        mint: PublicKey,
+        wallet: PublicKey,
+    ): { allowlist: PublicKey; blacklist: PublicKey } {
+        const coreProgramId = new PublicKey(coreIdl.address);
+        const [allowlist] = PublicKey.findProgramAddressSync(
+            [Buffer.from("sss-allowlist"), mint.toBuffer(), wallet.toBuffer()],
+            coreProgramId,
+        );
+        const [blacklist] = PublicKey.findProgramAddressSync(
+            [Buffer.from("sss-blacklist"), mint.toBuffer(), wallet.toBuffer()],
+            coreProgramId,
+        );
+        return { allowlist, blacklist };
+    }
+
     // ── Registry operations ───────────────────────────────────────────────────
 
     /**
@@ -253,16 +555,17 @@ export class OracleModule {
 
     /**
      * Register a new price feed in the global registry.
+     * The source accounts are passed as remaining accounts, in order.
      *
      * @example
      * ```ts
      * await oracle.registerFeed(authority, programId, {
-     *   symbol:          'BRLUSD',
-     *   feedType:        FeedType.Inverse,
-     *   baseCurrency:    'BRL',
-     *   quoteCurrency:   'USD',
-     *   decimals:        8,
-     *   switchboardFeed: new PublicKey(KNOWN_FEEDS.BRLUSD.devnet),
+     *   symbol:        'BRLUSD',
+     *   feedType:      FeedType.Inverse,
+     *   baseCurrency:  'BRL',
+     *   quoteCurrency: 'USD',
+     *   decimals:      8,
+     *   sources:       [{ account: new PublicKey(KNOWN_FEEDS.BRLUSD.devnet), kind: SourceKind.Switchboard }],
      * });
      * ```
      */
@@ -273,11 +576,15 @@ export class OracleModule {
     ): Promise<string> {
         const program = this.buildProgram(authority, programId);
         const [registryPda] = OracleModule.findRegistryPda(programId);
+        const [feedPda] = OracleModule.findFeedPda(params.symbol, programId);
 
         try {
             return await program.methods
                 .registerFeed({
                     symbol: params.symbol,
+                    minSources: params.minSources ?? params.sources.length,
+                    maxSpreadBps: params.maxSpreadBps ?? 0,
+                    sourceKinds: params.sources.map(s => ({ [s.kind]: {} })),
                     feedType: this.encodeFeedType(params.feedType),
                     baseCurrency: params.baseCurrency,
                     quoteCurrency: params.quoteCurrency,
@@ -287,9 +594,10 @@ export class OracleModule {
                     payer: authority.publicKey,
                     authority: authority.publicKey,
                     registry: registryPda,
-                    switchboardFeed: params.switchboardFeed,
+                    feed: feedPda,
                     systemProgram: SystemProgram.programId,
                 } as any)
+                .remainingAccounts(params.sources.map(s => readonlyMeta(s.account)))
                 .signers([authority])
                 .rpc();
         } catch (e) {
@@ -301,6 +609,7 @@ export class OracleModule {
 
     /**
      * Initialize an oracle config for a specific SSS token mint.
+     * `authority` must be the mint's sss-core master authority.
      *
      * @example
      * ```ts
@@ -326,28 +635,55 @@ export class OracleModule {
     ): Promise<string> {
         const program = this.buildProgram(authority, programId);
         const [oraclePda] = OracleModule.findOracleConfigPda(params.mint, programId);
-        const [registryPda] = OracleModule.findRegistryPda(programId);
+        const [feedPda] = OracleModule.findFeedPda(params.feedSymbol, programId);
+        const [coreConfigPda] = OracleModule.findCoreConfigPda(params.mint);
+        const denomination = params.quoteDenomination ?? { currency: 'USD', decimals: 2 };
+        if (Buffer.from(denomination.currency).length !== 3) {
+            throw new Error(`Quote currency must be a 3-letter ISO code: ${denomination.currency}`);
+        }
 
         try {
             return await program.methods
                 .initializeOracle({
                     feedSymbol: params.feedSymbol,
                     description: params.description,
+                    quoteDenomination: {
+                        currency: Array.from(Buffer.from(denomination.currency)),
+                        decimals: denomination.decimals,
+                    },
                     maxStalenessSecs: new BN(params.maxStalenessSecs),
                     mintFeeBps: params.mintFeeBps,
                     redeemFeeBps: params.redeemFeeBps,
+                    swapFeeBps: params.swapFeeBps ?? 0,
                     maxConfidenceBps: params.maxConfidenceBps,
                     quoteValiditySecs: new BN(params.quoteValiditySecs),
                     cpiMultiplier: new BN(params.cpiMultiplier),
                     cpiMinUpdateInterval: new BN(params.cpiMinUpdateInterval),
+                    cpiMaxChangeBps: params.cpiMaxChangeBps ?? 2_000,
+                    cpiAttesters: params.cpiAttesters ?? { attesters: [], threshold: 0, toleranceBps: 0 },
                     cpiDataSource: params.cpiDataSource,
+                    maxPriceMoveBps: params.maxPriceMoveBps ?? 0,
+                    priceMoveWindowSecs: new BN(params.priceMoveWindowSecs ?? 0),
+                    pricingMode: { [params.pricingMode ?? PricingMode.Spot]: {} },
+                    configTimelockSecs: new BN(params.configTimelockSecs ?? 86_400),
+                    manualPriceBandBps: params.manualPriceBandBps ?? 0,
+                    minKycTier: params.minKycTier ?? 0,
+                    globalMintCapUsd: new BN(params.globalMintCap ?? 0),
+                    globalRedeemCapUsd: new BN(params.globalRedeemCap ?? 0),
+                    userMintCapUsd: new BN(params.userMintCap ?? 0),
+                    userRedeemCapUsd: new BN(params.userRedeemCap ?? 0),
+                    pegFeeCurve: {
+                        marketFeedSymbol: params.pegFeeCurve?.marketFeedSymbol ?? '',
+                        points: params.pegFeeCurve?.points ?? [],
+                    },
                 })
                 .accounts({
                     payer: authority.publicKey,
                     authority: authority.publicKey,
                     mint: params.mint,
+                    coreConfig: coreConfigPda,
                     oracleConfig: oraclePda,
-                    registry: registryPda,
+                    feed: feedPda,
                     systemProgram: SystemProgram.programId,
                 } as any)
                 .signers([authority])
@@ -361,6 +697,8 @@ export class OracleModule {
 
     /**
      * Get a mint quote — how many tokens for a USD input.
+     * The feed's sources, accumulator, peg-curve market feed and the
+     * requester's compliance entries are derived from the oracle config.
      *
      * @returns QuoteResult with the on-chain quote account for atomic execution.
      *
@@ -381,12 +719,10 @@ export class OracleModule {
         params: QuoteRequest,
     ): Promise<QuoteResult> {
         const program = this.buildProgram(requester, programId);
-        const [oraclePda] = OracleModule.findOracleConfigPda(mint, programId);
-        const [registryPda] = OracleModule.findRegistryPda(programId);
         const [quotePda] = OracleModule.findQuotePda(mint, requester.publicKey, params.nonce, programId);
-
-        const oracleInfo = await this.getOracleInfo(programId, mint);
-        const feedAddress = await this.getFeedAddress(programId, oracleInfo.feedSymbol);
+        const { accounts, remainingAccounts } = await this.quoteAccounts(
+            program, programId, mint, requester.publicKey,
+        );
 
         try {
             await program.methods
@@ -395,30 +731,15 @@ export class OracleModule {
                     minOutput: new BN(params.minOutput),
                     nonce: new BN(params.nonce.toString()),
                 })
-                .accounts({
-                    requester: requester.publicKey,
-                    oracleConfig: oraclePda,
-                    registry: registryPda,
-                    switchboardFeed: feedAddress,
-                    quote: quotePda,
-                    systemProgram: SystemProgram.programId,
-                } as any)
+                .accounts({ ...accounts, quote: quotePda } as any)
+                .remainingAccounts(remainingAccounts)
                 .signers([requester])
                 .rpc();
         } catch (e) {
             throw parseProgramError(e, true);
         }
 
-        const quoteData = await (program.account as any).pendingQuote.fetch(quotePda);
-
-        return {
-            outputAmount: quoteData.outputAmount.toNumber(),
-            feeAmount: quoteData.feeAmount.toNumber(),
-            priceUsed: quoteData.priceSnapshot.toNumber(),
-            priceHuman: quoteData.priceSnapshot.toNumber() / PRICE_SCALE,
-            validUntil: new Date(quoteData.validUntil.toNumber() * 1000),
-            quoteAccount: quotePda,
-        };
+        return this.fetchQuoteResult(program, quotePda);
     }
 
     /**
@@ -441,12 +762,10 @@ export class OracleModule {
         params: QuoteRequest,
     ): Promise<QuoteResult> {
         const program = this.buildProgram(requester, programId);
-        const [oraclePda] = OracleModule.findOracleConfigPda(mint, programId);
-        const [registryPda] = OracleModule.findRegistryPda(programId);
         const [quotePda] = OracleModule.findQuotePda(mint, requester.publicKey, params.nonce, programId);
-
-        const oracleInfo = await this.getOracleInfo(programId, mint);
-        const feedAddress = await this.getFeedAddress(programId, oracleInfo.feedSymbol);
+        const { accounts, remainingAccounts } = await this.quoteAccounts(
+            program, programId, mint, requester.publicKey,
+        );
 
         try {
             await program.methods
@@ -455,41 +774,27 @@ export class OracleModule {
                     minOutput: new BN(params.minOutput),
                     nonce: new BN(params.nonce.toString()),
                 })
-                .accounts({
-                    requester: requester.publicKey,
-                    oracleConfig: oraclePda,
-                    registry: registryPda,
-                    switchboardFeed: feedAddress,
-                    quote: quotePda,
-                    systemProgram: SystemProgram.programId,
-                } as any)
+                .accounts({ ...accounts, quote: quotePda } as any)
+                .remainingAccounts(remainingAccounts)
                 .signers([requester])
                 .rpc();
         } catch (e) {
             throw parseProgramError(e, true);
         }
 
-        const quoteData = await (program.account as any).pendingQuote.fetch(quotePda);
-
-        return {
-            outputAmount: quoteData.outputAmount.toNumber(),
-            feeAmount: quoteData.feeAmount.toNumber(),
-            priceUsed: quoteData.priceSnapshot.toNumber(),
-            priceHuman: quoteData.priceSnapshot.toNumber() / PRICE_SCALE,
-            validUntil: new Date(quoteData.validUntil.toNumber() * 1000),
-            quoteAccount: quotePda,
-        };
+        return this.fetchQuoteResult(program, quotePda);
     }
 
     /**
      * Execute a stored mint quote atomically.
      * Must be called within quote validity window or transaction reverts.
+     * Tokens go to `recipient`'s Token-2022 associated token account.
      *
      * @example
      * ```ts
      * const txSig = await oracle.mintWithOracle(
      *   user, programId, brlMint,
-     *   BigInt(Date.now()),  // same nonce used in getMintQuote
+     *   nonce,  // same nonce used in getMintQuote
      * );
      * ```
      */
@@ -498,34 +803,148 @@ export class OracleModule {
         programId: PublicKey,
         mint: PublicKey,
         nonce: bigint,
+        recipient: PublicKey = requester.publicKey,
     ): Promise<string> {
         const program = this.buildProgram(requester, programId);
         const [oraclePda] = OracleModule.findOracleConfigPda(mint, programId);
         const [quotePda] = OracleModule.findQuotePda(mint, requester.publicKey, nonce, programId);
+        const [coreConfigPda] = OracleModule.findCoreConfigPda(mint);
+        const [minterPda] = OracleModule.findMinterConfigPda(mint, oraclePda);
+        const [feeVaultPda] = OracleModule.findFeeVaultPda(mint, programId);
+        const requesterEntries = OracleModule.findComplianceEntryPdas(mint, requester.publicKey);
+        const recipientEntries = OracleModule.findComplianceEntryPdas(mint, recipient);
 
-        return await program.methods
-            .mintWithOracle()
-            .accounts({
-                requester: requester.publicKey,
-                oracleConfig: oraclePda,
-                quote: quotePda,
-            } as any)
-            .signers([requester])
-            .rpc();
+        try {
+            return await program.methods
+                .mintWithOracle()
+                .accounts({
+                    requester: requester.publicKey,
+                    oracleConfig: oraclePda,
+                    quote: quotePda,
+                    coreConfig: coreConfigPda,
+                    minterConfig: minterPda,
+                    mint,
+                    destination: getAssociatedTokenAddressSync(mint, recipient, false, TOKEN_2022_PROGRAM_ID),
+                    feeVault: feeVaultPda,
+                    requesterAllowlist: requesterEntries.allowlist,
+                    requesterBlacklist: requesterEntries.blacklist,
+                    recipientAllowlist: recipientEntries.allowlist,
+                    recipientBlacklist: recipientEntries.blacklist,
+                    userUsage: await this.userUsageOrNull(programId, mint, requester.publicKey),
+                    sssCoreProgram: new PublicKey(coreIdl.address),
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                } as any)
+                .signers([requester])
+                .rpc();
+        } catch (e) {
+            throw parseProgramError(e, true);
+        }
+    }
+
+    /**
+     * Execute a stored redeem quote: takes the tokens from the requester's
+     * associated token account, burns them through the oracle escrow and
+     * records the quote-unit amount owed in a RedemptionSettlement.
+     * Pass the mint's transfer-hook extra accounts, if it has a hook.
+     *
+     * @example
+     * ```ts
+     * const txSig = await oracle.redeemWithOracle(user, programId, brlMint, nonce);
+     * const [settlementPda] = OracleModule.findSettlementPda(brlMint, user.publicKey, nonce, programId);
+     * ```
+     */
+    async redeemWithOracle(
+        requester: Keypair,
+        programId: PublicKey,
+        mint: PublicKey,
+        nonce: bigint,
+        transferHookAccounts: AccountMeta[] = [],
+    ): Promise<string> {
+        const program = this.buildProgram(requester, programId);
+        const [oraclePda] = OracleModule.findOracleConfigPda(mint, programId);
+        const [quotePda] = OracleModule.findQuotePda(mint, requester.publicKey, nonce, programId);
+        const [settlementPda] = OracleModule.findSettlementPda(mint, requester.publicKey, nonce, programId);
+        const [coreConfigPda] = OracleModule.findCoreConfigPda(mint);
+        const [feeVaultPda] = OracleModule.findFeeVaultPda(mint, programId);
+        const requesterEntries = OracleModule.findComplianceEntryPdas(mint, requester.publicKey);
+
+        try {
+            return await program.methods
+                .redeemWithOracle()
+                .accounts({
+                    requester: requester.publicKey,
+                    oracleConfig: oraclePda,
+                    quote: quotePda,
+                    settlement: settlementPda,
+                    coreConfig: coreConfigPda,
+                    mint,
+                    source: getAssociatedTokenAddressSync(mint, requester.publicKey, false, TOKEN_2022_PROGRAM_ID),
+                    escrow: getAssociatedTokenAddressSync(mint, oraclePda, true, TOKEN_2022_PROGRAM_ID),
+                    feeVault: feeVaultPda,
+                    requesterAllowlist: requesterEntries.allowlist,
+                    requesterBlacklist: requesterEntries.blacklist,
+                    userUsage: await this.userUsageOrNull(programId, mint, requester.publicKey),
+                    sssCoreProgram: new PublicKey(coreIdl.address),
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                } as any)
+                .remainingAccounts(transferHookAccounts)
+                .signers([requester])
+                .rpc();
+        } catch (e) {
+            throw parseProgramError(e, true);
+        }
+    }
+
+    /**
+     * Create a user's UserUsage account. Required before minting or redeeming
+     * through an oracle with per-user caps.
+     *
+     * @example
+     * ```ts
+     * await oracle.initializeUserUsage(payer, programId, brlMint, user.publicKey);
+     * ```
+     */
+    async initializeUserUsage(
+        payer: Keypair,
+        programId: PublicKey,
+        mint: PublicKey,
+        user: PublicKey,
+    ): Promise<string> {
+        const program = this.buildProgram(payer, programId);
+        const [oraclePda] = OracleModule.findOracleConfigPda(mint, programId);
+        const [usagePda] = OracleModule.findUserUsagePda(mint, user, programId);
+
+        try {
+            return await program.methods
+                .initializeUserUsage()
+                .accounts({
+                    payer: payer.publicKey,
+                    user,
+                    oracleConfig: oraclePda,
+                    userUsage: usagePda,
+                    systemProgram: SystemProgram.programId,
+                } as any)
+                .signers([payer])
+                .rpc();
+        } catch (e) {
+            throw parseProgramError(e, true);
+        }
     }
 
     // ── CPI operations ────────────────────────────────────────────────────────
 
     /**
      * Update the CPI multiplier for inflation-indexed tokens.
-     * Admin-only, call once per month with official CPI data.
+     * Signed by the oracle's CPI updater, once per month with official CPI data.
      *
      * @example
      * ```ts
      * await oracle.updateCpiMultiplier(authority, programId, brlCpiMint, {
-     *   newMultiplier:  1_083_000,    // 1.083 * CPI_SCALE
-     *   referenceMonth: '2026-01',
-     *   dataSource:     'IBGE - IPCA',
+     *   newMultiplier:     1_083_000,    // 1.083 * CPI_SCALE
+     *   accrualWindowSecs: 30 * 86_400,  // accrue over ~one month
+     *   referenceMonth:    '2026-01',
+     *   dataSource:        'IBGE - IPCA',
      * });
      * ```
      */
@@ -535,6 +954,7 @@ export class OracleModule {
         mint: PublicKey,
         params: {
             newMultiplier: number;
+            accrualWindowSecs: number;
             referenceMonth: string;
             dataSource: string;
         },
@@ -545,11 +965,12 @@ export class OracleModule {
         return await program.methods
             .updateCpiMultiplier({
                 newMultiplier: new BN(params.newMultiplier),
+                accrualWindowSecs: new BN(params.accrualWindowSecs),
                 referenceMonth: params.referenceMonth,
                 dataSource: params.dataSource,
             })
             .accounts({
-                authority: authority.publicKey,
+                cpiUpdater: authority.publicKey,
                 oracleConfig: oraclePda,
             } as any)
             .signers([authority])
@@ -589,7 +1010,7 @@ export class OracleModule {
     }
 
     /**
-     * List all registered feeds from the global registry.
+     * List all active feeds (one FeedEntry PDA per symbol).
      *
      * @example
      * ```ts
@@ -599,14 +1020,18 @@ export class OracleModule {
      */
     async listFeeds(programId: PublicKey): Promise<FeedInfo[]> {
         const program = this.readProgram(programId);
-        const [registryPda] = OracleModule.findRegistryPda(programId);
-        const registry = await (program.account as any).feedRegistry.fetch(registryPda);
+        const entries = await (program.account as any).feedEntry.all();
 
-        return registry.feeds
+        return entries
+            .map((e: any) => e.account)
             .filter((f: any) => f.active)
             .map((f: any) => ({
                 symbol: f.symbol,
-                switchboardFeed: f.switchboardFeed,
+                switchboardFeed: f.sources[0]?.account ?? null,
+                sources: f.sources.map((s: any) => ({
+                    account: s.account,
+                    kind: Object.keys(s.kind)[0] as SourceKind,
+                })),
                 feedType: Object.keys(f.feedType)[0],
                 baseCurrency: f.baseCurrency,
                 quoteCurrency: f.quoteCurrency,
@@ -622,11 +1047,14 @@ export class OracleModule {
      * Useful for UI price previews — call this on every keystroke,
      * then call `getMintQuote` only on form submit.
      *
-     * @param usdCents      - USD amount in cents (e.g. 10_000 = $100)
-     * @param priceScaled   - Price * PRICE_SCALE (e.g. 5_720_000 = 5.72)
+     * @param quoteAmount   - Input in quote units (e.g. 10_000 = $100 in USD cents)
+     * @param priceScaled   - Price * 10^priceDecimals (e.g. 5_720_000 = 5.72)
      * @param feedType      - Feed interpretation strategy
      * @param mintFeeBps    - Mint fee in basis points (e.g. 30 = 0.3%)
      * @param cpiMultiplier - CPI multiplier * CPI_SCALE (default 1_000_000 = 1.0)
+     * @param tokenDecimals - Mint decimals (default 6)
+     * @param priceDecimals - Feed `decimals` the price is scaled by (default 6)
+     * @param quoteDecimals - Oracle `quoteDenomination.decimals` (default 2, cents)
      *
      * @returns Object with gross, fee, net token amounts, and human-readable price.
      *
@@ -638,39 +1066,48 @@ export class OracleModule {
      * ```
      */
     simulateMintQuote(
-        usdCents: number,
+        quoteAmount: number,
         priceScaled: number,
         feedType: FeedType,
         mintFeeBps: number,
         cpiMultiplier: number = CPI_SCALE,
+        tokenDecimals: number = 6,
+        priceDecimals: number = 6,
+        quoteDecimals: number = 2,
     ): { gross: number; fee: number; net: number; priceHuman: number } {
-        let gross: number;
-
+        const quote = BigInt(quoteAmount);
+        const price = BigInt(priceScaled);
+        const tokenScale = 10n ** BigInt(tokenDecimals);
+        const priceScale = 10n ** BigInt(priceDecimals);
+        const quoteScale = 10n ** BigInt(quoteDecimals);
+        let gross: bigint;
+
+        // Integer math with the same rounding as the on-chain program:
+        // outputs round down, fees round up.
         switch (feedType) {
             case FeedType.Direct:
-                gross = (usdCents * TOKEN_SCALE * PRICE_SCALE) / (priceScaled * 100);
+                gross = (quote * tokenScale * priceScale) / (price * quoteScale);
                 break;
 
             case FeedType.Inverse:
-                gross = (usdCents * priceScaled * TOKEN_SCALE) / (PRICE_SCALE * 100);
+                gross = (quote * price * tokenScale) / (priceScale * quoteScale);
                 break;
 
             case FeedType.CpiIndexed:
-                gross = (usdCents * TOKEN_SCALE * CPI_SCALE) / (100 * cpiMultiplier);
+                gross = (quote * tokenScale * BigInt(CPI_SCALE)) / (quoteScale * BigInt(cpiMultiplier));
                 break;
 
             default:
                 throw new Error(`Unsupported feed type for simulation: ${feedType}`);
         }
 
-        const fee = Math.floor(gross * mintFeeBps / 10_000);
-        const net = Math.floor(gross - fee);
+        const fee = ceilDiv(gross * BigInt(mintFeeBps), 10_000n);
 
         return {
-            gross: Math.floor(gross),
-            fee,
-            net,
-            priceHuman: priceScaled / PRICE_SCALE,
+            gross: Number(gross),
+            fee: Number(fee),
+            net: Number(gross - fee),
+            priceHuman: priceScaled / 10 ** priceDecimals,
         };
     }
 
@@ -678,10 +1115,13 @@ export class OracleModule {
      * Simulate a redeem quote locally without hitting the blockchain.
      *
      * @param tokenAmount   - Token amount in base units (e.g. 572_000_000 = 572 tokens)
-     * @param priceScaled   - Price * PRICE_SCALE
+     * @param priceScaled   - Price * 10^priceDecimals
      * @param feedType      - Feed interpretation strategy
      * @param redeemFeeBps  - Redeem fee in basis points
      * @param cpiMultiplier - CPI multiplier * CPI_SCALE
+     * @param tokenDecimals - Mint decimals (default 6)
+     * @param priceDecimals - Feed `decimals` the price is scaled by (default 6)
+     * @param quoteDecimals - Oracle `quoteDenomination.decimals` (default 2, cents)
      *
      * @example
      * ```ts
@@ -695,34 +1135,41 @@ export class OracleModule {
         feedType: FeedType,
         redeemFeeBps: number,
         cpiMultiplier: number = CPI_SCALE,
+        tokenDecimals: number = 6,
+        priceDecimals: number = 6,
+        quoteDecimals: number = 2,
     ): { gross: number; fee: number; net: number; priceHuman: number } {
-        let gross: number;
+        const tokens = BigInt(tokenAmount);
+        const price = BigInt(priceScaled);
+        const tokenScale = 10n ** BigInt(tokenDecimals);
+        const priceScale = 10n ** BigInt(priceDecimals);
+        const quoteScale = 10n ** BigInt(quoteDecimals);
+        let gross: bigint;
 
         switch (feedType) {
             case FeedType.Direct:
-                gross = (tokenAmount * priceScaled * 100) / (TOKEN_SCALE * PRICE_SCALE);
+                gross = (tokens * price * quoteScale) / (tokenScale * priceScale);
                 break;
 
             case FeedType.Inverse:
-                gross = (tokenAmount * PRICE_SCALE * 100) / (TOKEN_SCALE * priceScaled);
+                gross = (tokens * priceScale * quoteScale) / (tokenScale * price);
                 break;
 
             case FeedType.CpiIndexed:
-                gross = (tokenAmount * cpiMultiplier * 100) / (TOKEN_SCALE * CPI_SCALE);
+                gross = (tokens * BigInt(cpiMultiplier) * quoteScale) / (tokenScale * BigInt(CPI_SCALE));
                 break;
 
             default:
                 throw new Error(`Unsupported feed type for simulation: ${feedType}`);
         }
 
-        const fee = Math.floor(gross * redeemFeeBps / 10_000);
-        const net = Math.floor(gross - fee);
+        const fee = ceilDiv(gross * BigInt(redeemFeeBps), 10_000n);
 
         return {
-            gross: Math.floor(gross),
-            fee,
-            net,
-            priceHuman: priceScaled / PRICE_SCALE,
+            gross: Number(gross),
+            fee: Number(fee),
+            net: Number(gross - fee),
+            priceHuman: priceScaled / 10 ** priceDecimals,
         };
     }
 
@@ -738,6 +1185,96 @@ export class OracleModule {
         return new Program(oracleIdl as any, { connection: this.connection });
     }
 
+    /**
+     * Named and remaining accounts a quote on `mint`'s oracle passes: the
+     * feed's accounts, then the peg-curve market feed's when the curve is on.
+     */
+    private async quoteAccounts(
+        program: any,
+        programId: PublicKey,
+        mint: PublicKey,
+        requester: PublicKey,
+    ): Promise<{ accounts: Record<string, PublicKey | null>; remainingAccounts: AccountMeta[] }> {
+        const [oraclePda] = OracleModule.findOracleConfigPda(mint, programId);
+        const oracle = await program.account.oracleConfig.fetch(oraclePda);
+        const [feedPda] = OracleModule.findFeedPda(oracle.feedSymbol, programId);
+        const remainingAccounts = await this.feedAccounts(program, programId, oracle.feedSymbol);
+
+        let marketFeed: PublicKey | null = null;
+        if (oracle.pegFeeCurve.points.length > 0) {
+            [marketFeed] = OracleModule.findFeedPda(oracle.pegFeeCurve.marketFeedSymbol, programId);
+            remainingAccounts.push(
+                ...await this.feedAccounts(program, programId, oracle.pegFeeCurve.marketFeedSymbol),
+            );
+        }
+
+        const entries = OracleModule.findComplianceEntryPdas(mint, requester);
+        return {
+            accounts: {
+                requester,
+                oracleConfig: oraclePda,
+                feed: feedPda,
+                accumulator: oracle.pricingMode.spot
+                    ? null
+                    : OracleModule.findAccumulatorPda(oracle.feedSymbol, programId)[0],
+                marketFeed,
+                coreConfig: OracleModule.findCoreConfigPda(mint)[0],
+                requesterAllowlist: entries.allowlist,
+                requesterBlacklist: entries.blacklist,
+                systemProgram: SystemProgram.programId,
+            },
+            remainingAccounts,
+        };
+    }
+
+    /**
+     * A feed's accounts as quotes lay them out: its sources in order or, for
+     * a composite feed, each leg's feed PDA followed by that leg's sources.
+     */
+    private async feedAccounts(
+        program: any,
+        programId: PublicKey,
+        symbol: string,
+    ): Promise<AccountMeta[]> {
+        const [feedPda] = OracleModule.findFeedPda(symbol, programId);
+        const feed = await program.account.feedEntry.fetch(feedPda);
+        if (!feed.feedType.composite) {
+            return feed.sources.map((s: any) => readonlyMeta(s.account));
+        }
+
+        const metas: AccountMeta[] = [];
+        for (const leg of feed.feedType.composite.legs) {
+            const [legPda] = OracleModule.findFeedPda(leg.symbol, programId);
+            const legFeed = await program.account.feedEntry.fetch(legPda);
+            metas.push(readonlyMeta(legPda), ...legFeed.sources.map((s: any) => readonlyMeta(s.account)));
+        }
+        return metas;
+    }
+
+    /** The user's UserUsage PDA if it has been created, else `null`. */
+    private async userUsageOrNull(
+        programId: PublicKey,
+        mint: PublicKey,
+        user: PublicKey,
+    ): Promise<PublicKey | null> {
+        const [usagePda] = OracleModule.findUserUsagePda(mint, user, programId);
+        const info = await this.connection.getAccountInfo(usagePda);
+        return info ? usagePda : null;
+    }
+
+    private async fetchQuoteResult(program: any, quotePda: PublicKey): Promise<QuoteResult> {
+        const quoteData = await program.account.pendingQuote.fetch(quotePda);
+
+        return {
+            outputAmount: quoteData.outputAmount.toNumber(),
+            feeAmount: quoteData.feeAmount.toNumber(),
+            priceUsed: quoteData.priceSnapshot.toNumber(),
+            priceHuman: quoteData.priceSnapshot.toNumber() / 10 ** quoteData.priceDecimals,
+            validUntil: new Date(quoteData.validUntil.toNumber() * 1000),
+            quoteAccount: quotePda,
+        };
+    }
+
     private encodeFeedType(feedType: FeedType): any {
         switch (feedType) {
             case FeedType.Direct: return { direct: {} };
@@ -747,13 +1284,6 @@ export class OracleModule {
             default: return { direct: {} };
         }
     }
-
-    private async getFeedAddress(programId: PublicKey, symbol: string): Promise<PublicKey> {
-        const feeds = await this.listFeeds(programId);
-        const feed = feeds.find(f => f.symbol === symbol);
-        if (!feed) throw new Error(`Feed not found in registry: ${symbol}`);
-        return feed.switchboardFeed;
-    }
 }
 
 /**
@@ -16,7 +16,7 @@ export type SssOracle = {
     {
       "name": "acceptAuthorityTransfer",
       "docs": [
-        "Accept a pending authority transfer (must be signed by new authority)"
+        "Accept a pending role transfer (must be signed by the new holder)"
       ],
       "discriminator": [
         239,
@@ -62,58 +62,40 @@ export type SssOracle = {
           }
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "oracleRole"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "getMintQuote",
+      "name": "acceptRegistryAuthority",
       "docs": [
-        "Get a mint quote — USD in → tokens out"
+        "Accept a pending registry authority transfer (signed by the new authority)"
       ],
       "discriminator": [
-        161,
-        124,
-        87,
-        175,
-        98,
-        25,
-        175,
-        169
+        228,
+        112,
+        232,
+        244,
+        155,
+        68,
+        83,
+        87
       ],
       "accounts": [
         {
-          "name": "requester",
-          "writable": true,
+          "name": "newAuthority",
           "signer": true
         },
-        {
-          "name": "oracleConfig",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  115,
-                  115,
-                  115,
-                  45,
-                  111,
-                  114,
-                  97,
-                  99,
-                  108,
-                  101
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "oracle_config.mint",
-                "account": "oracleConfig"
-              }
-            ]
-          }
-        },
         {
           "name": "registry",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -140,12 +122,28 @@ export type SssOracle = {
               }
             ]
           }
-        },
-        {
-          "name": "switchboardFeed"
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "applyConfigUpdate",
+      "docs": [
+        "Apply a queued risk-parameter update once its timelock has elapsed"
+      ],
+      "discriminator": [
+        211,
+        233,
+        51,
+        21,
+        33,
+        87,
+        209,
+        147
+      ],
+      "accounts": [
         {
-          "name": "quote",
+          "name": "oracleConfig",
           "writable": true,
           "pda": {
             "seeds": [
@@ -156,10 +154,11 @@ export type SssOracle = {
                   115,
                   115,
                   45,
-                  113,
-                  117,
                   111,
-                  116,
+                  114,
+                  97,
+                  99,
+                  108,
                   101
                 ]
               },
@@ -167,62 +166,43 @@ export type SssOracle = {
                 "kind": "account",
                 "path": "oracle_config.mint",
                 "account": "oracleConfig"
-              },
-              {
-                "kind": "account",
-                "path": "requester"
-              },
-              {
-                "kind": "arg",
-                "path": "params.nonce"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "getQuoteParams"
-            }
-          }
+          "name": "feed",
+          "docs": [
+            "The feed named by `feed_symbol`; required when the update changes it"
+          ],
+          "optional": true
         }
       ],
-      "returns": {
-        "defined": {
-          "name": "quoteResult"
-        }
-      }
+      "args": []
     },
     {
-      "name": "getRedeemQuote",
+      "name": "cancelConfigUpdate",
       "docs": [
-        "Get a redeem quote — tokens in → USD out"
+        "Cancel a queued risk-parameter update"
       ],
       "discriminator": [
-        104,
-        228,
-        76,
-        87,
-        172,
-        49,
-        183,
-        137
+        216,
+        180,
+        255,
+        207,
+        118,
+        146,
+        126,
+        89
       ],
       "accounts": [
         {
-          "name": "requester",
-          "writable": true,
+          "name": "admin",
           "signer": true
         },
         {
           "name": "oracleConfig",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -247,9 +227,33 @@ export type SssOracle = {
               }
             ]
           }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "clearManualPrice",
+      "docs": [
+        "Clear the manual price before it expires"
+      ],
+      "discriminator": [
+        37,
+        83,
+        196,
+        59,
+        226,
+        35,
+        193,
+        189
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
         },
         {
-          "name": "registry",
+          "name": "oracleConfig",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -259,26 +263,44 @@ export type SssOracle = {
                   115,
                   115,
                   45,
-                  102,
-                  101,
-                  101,
-                  100,
-                  45,
-                  114,
-                  101,
-                  103,
-                  105,
-                  115,
-                  116,
+                  111,
                   114,
-                  121
+                  97,
+                  99,
+                  108,
+                  101
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
               }
             ]
           }
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeExpiredQuote",
+      "docs": [
+        "Close an expired, unexecuted quote and refund its rent to the requester (anyone)"
+      ],
+      "discriminator": [
+        254,
+        84,
+        162,
+        33,
+        169,
+        155,
+        18,
+        53
+      ],
+      "accounts": [
         {
-          "name": "switchboardFeed"
+          "name": "caller",
+          "signer": true
         },
         {
           "name": "quote",
@@ -301,71 +323,74 @@ export type SssOracle = {
               },
               {
                 "kind": "account",
-                "path": "oracle_config.mint",
-                "account": "oracleConfig"
+                "path": "quote.mint",
+                "account": "pendingQuote"
               },
               {
                 "kind": "account",
-                "path": "requester"
+                "path": "quote.requester",
+                "account": "pendingQuote"
               },
               {
-                "kind": "arg",
-                "path": "params.nonce"
+                "kind": "account",
+                "path": "quote.nonce",
+                "account": "pendingQuote"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "requester",
+          "writable": true
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "closeExpiredQuotes",
+      "docs": [
+        "Batch close expired mint/redeem or swap quotes passed as (quote, requester) pairs in remaining accounts"
+      ],
+      "discriminator": [
+        52,
+        22,
+        170,
+        174,
+        108,
+        99,
+        179,
+        40
+      ],
+      "accounts": [
         {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "getQuoteParams"
-            }
-          }
+          "name": "caller",
+          "signer": true
         }
       ],
-      "returns": {
-        "defined": {
-          "name": "quoteResult"
-        }
-      }
+      "args": []
     },
     {
-      "name": "initializeOracle",
+      "name": "closeExpiredSwapQuote",
       "docs": [
-        "Initialize an oracle config for a specific SSS token mint"
+        "Close an expired, unexecuted swap quote and refund its rent to the requester (anyone)"
       ],
       "discriminator": [
         144,
-        223,
+        33,
+        230,
+        110,
+        55,
         131,
-        120,
-        196,
-        253,
-        181,
-        99
+        207,
+        40
       ],
       "accounts": [
         {
-          "name": "payer",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "authority",
+          "name": "caller",
           "signer": true
         },
         {
-          "name": "mint"
-        },
-        {
-          "name": "oracleConfig",
+          "name": "quote",
           "writable": true,
           "pda": {
             "seeds": [
@@ -376,94 +401,162 @@ export type SssOracle = {
                   115,
                   115,
                   45,
-                  111,
-                  114,
+                  115,
+                  119,
                   97,
-                  99,
-                  108,
+                  112,
+                  45,
+                  113,
+                  117,
+                  111,
+                  116,
                   101
                 ]
               },
               {
                 "kind": "account",
-                "path": "mint"
+                "path": "quote.mint_in",
+                "account": "pendingSwapQuote"
+              },
+              {
+                "kind": "account",
+                "path": "quote.requester",
+                "account": "pendingSwapQuote"
+              },
+              {
+                "kind": "account",
+                "path": "quote.nonce",
+                "account": "pendingSwapQuote"
               }
             ]
           }
         },
         {
-          "name": "registry",
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  115,
+          "name": "requester",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "confirmSettlement",
+      "docs": [
+        "Confirm the off-chain fiat payout for a redemption settlement"
+      ],
+      "discriminator": [
+        171,
+        180,
+        45,
+        20,
+        120,
+        221,
+        11,
+        31
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
                   115,
                   115,
-                  45,
-                  102,
-                  101,
-                  101,
-                  100,
-                  45,
-                  114,
-                  101,
-                  103,
-                  105,
                   115,
-                  116,
+                  45,
+                  111,
                   114,
-                  121
+                  97,
+                  99,
+                  108,
+                  101
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "settlement",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  115,
+                  101,
+                  116,
+                  116,
+                  108,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "settlement.redeemer",
+                "account": "redemptionSettlement"
+              },
+              {
+                "kind": "account",
+                "path": "settlement.nonce",
+                "account": "redemptionSettlement"
+              }
+            ]
+          }
         }
       ],
       "args": [
         {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "initializeOracleParams"
-            }
-          }
+          "name": "payoutReference",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "initializeRegistry",
+      "name": "deactivateFeed",
       "docs": [
-        "Initialize the global feed registry (one-time, admin only)"
+        "Deactivate a feed so it can no longer be quoted"
       ],
       "discriminator": [
+        182,
+        149,
         189,
-        181,
-        20,
-        17,
-        174,
-        57,
-        249,
-        59
+        108,
+        44,
+        58,
+        204,
+        154
       ],
       "accounts": [
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
-        },
         {
           "name": "authority",
           "signer": true
         },
         {
           "name": "registry",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -492,26 +585,53 @@ export type SssOracle = {
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "feed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feed.symbol",
+                "account": "feedEntry"
+              }
+            ]
+          }
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "reason",
+          "type": "string"
+        }
+      ]
     },
     {
-      "name": "mintWithOracle",
+      "name": "getMintQuote",
       "docs": [
-        "Execute a stored mint quote — validates + emits event atomically"
+        "Get a mint quote — USD in → tokens out"
       ],
       "discriminator": [
-        59,
-        36,
-        162,
-        197,
-        12,
-        79,
-        194,
-        72
+        161,
+        124,
+        87,
+        175,
+        98,
+        25,
+        175,
+        169
       ],
       "accounts": [
         {
@@ -548,8 +668,7 @@ export type SssOracle = {
           }
         },
         {
-          "name": "quote",
-          "writable": true,
+          "name": "feed",
           "pda": {
             "seeds": [
               {
@@ -559,56 +678,26 @@ export type SssOracle = {
                   115,
                   115,
                   45,
-                  113,
-                  117,
-                  111,
-                  116,
-                  101
+                  102,
+                  101,
+                  101,
+                  100
                 ]
               },
               {
                 "kind": "account",
-                "path": "oracle_config.mint",
+                "path": "oracle_config.feed_symbol",
                 "account": "oracleConfig"
-              },
-              {
-                "kind": "account",
-                "path": "requester"
-              },
-              {
-                "kind": "account",
-                "path": "quote.nonce",
-                "account": "pendingQuote"
               }
             ]
           }
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "pauseOracle",
-      "docs": [
-        "Pause oracle mint/redeem operations (emergencies)"
-      ],
-      "discriminator": [
-        170,
-        116,
-        3,
-        121,
-        247,
-        109,
-        147,
-        191
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "signer": true
         },
         {
-          "name": "oracleConfig",
-          "writable": true,
+          "name": "accumulator",
+          "docs": [
+            "Required unless `oracle_config.pricing_mode` is `Spot`"
+          ],
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -618,52 +707,36 @@ export type SssOracle = {
                   115,
                   115,
                   45,
-                  111,
-                  114,
                   97,
                   99,
+                  99,
+                  117,
+                  109,
+                  117,
                   108,
-                  101
+                  97,
+                  116,
+                  111,
+                  114
                 ]
               },
               {
                 "kind": "account",
-                "path": "oracle_config.mint",
+                "path": "oracle_config.feed_symbol",
                 "account": "oracleConfig"
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "reason",
-          "type": "string"
-        }
-      ]
-    },
-    {
-      "name": "proposeAuthorityTransfer",
-      "docs": [
-        "Propose a two-step authority transfer"
-      ],
-      "discriminator": [
-        57,
-        206,
-        225,
-        129,
-        35,
-        111,
-        174,
-        145
-      ],
-      "accounts": [
+        },
         {
-          "name": "authority",
-          "signer": true
+          "name": "marketFeed",
+          "docs": [
+            "The peg fee curve's market feed — required when `oracle_config.peg_fee_curve` is set"
+          ],
+          "optional": true
         },
         {
-          "name": "oracleConfig",
+          "name": "quote",
           "writable": true,
           "pda": {
             "seeds": [
@@ -674,11 +747,10 @@ export type SssOracle = {
                   115,
                   115,
                   45,
+                  113,
+                  117,
                   111,
-                  114,
-                  97,
-                  99,
-                  108,
+                  116,
                   101
                 ]
               },
@@ -686,46 +758,23 @@ export type SssOracle = {
                 "kind": "account",
                 "path": "oracle_config.mint",
                 "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              },
+              {
+                "kind": "arg",
+                "path": "params.nonce"
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "newAuthority",
-          "type": "pubkey"
-        }
-      ]
-    },
-    {
-      "name": "registerFeed",
-      "docs": [
-        "Register a new price feed (e.g. BRLUSD, EURUSD, JPYUSD)"
-      ],
-      "discriminator": [
-        138,
-        181,
-        114,
-        65,
-        71,
-        145,
-        61,
-        111
-      ],
-      "accounts": [
-        {
-          "name": "payer",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "authority",
-          "signer": true
         },
         {
-          "name": "registry",
-          "writable": true,
+          "name": "coreConfig",
+          "docs": [
+            "sss-core stablecoin config for the oracle's mint"
+          ],
           "pda": {
             "seeds": [
               {
@@ -735,26 +784,72 @@ export type SssOracle = {
                   115,
                   115,
                   45,
+                  99,
+                  111,
+                  110,
                   102,
-                  101,
-                  101,
-                  100,
-                  45,
-                  114,
-                  101,
-                  103,
                   105,
-                  115,
-                  116,
-                  114,
-                  121
+                  103
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
               }
-            ]
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                250,
+                140,
+                13,
+                25,
+                80,
+                68,
+                152,
+                150,
+                61,
+                54,
+                95,
+                148,
+                2,
+                144,
+                201,
+                76,
+                91,
+                242,
+                87,
+                160,
+                13,
+                131,
+                158,
+                34,
+                2,
+                171,
+                121,
+                185,
+                50,
+                52,
+                15
+              ]
+            }
           }
         },
         {
-          "name": "switchboardFeed"
+          "name": "requesterAllowlist",
+          "docs": [
+            "Required when the mint has an active allowlist or the oracle a `min_kyc_tier`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "requesterBlacklist",
+          "docs": [
+            "`check_participant`. Required when the mint has a blacklister"
+          ],
+          "optional": true
         },
         {
           "name": "systemProgram",
@@ -766,35 +861,35 @@ export type SssOracle = {
           "name": "params",
           "type": {
             "defined": {
-              "name": "registerFeedParams"
+              "name": "getQuoteParams"
             }
           }
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "quoteResult"
+        }
+      }
     },
     {
-      "name": "unpauseOracle",
+      "name": "getPriceDiagnostics",
       "docs": [
-        "Unpause oracle operations"
+        "Read-only price, fee and output preview with the validations a quote would fail (return data)"
       ],
       "discriminator": [
+        161,
+        75,
         187,
-        113,
-        244,
-        174,
-        46,
-        2,
-        129,
-        215
+        42,
+        233,
+        216,
+        181,
+        23
       ],
       "accounts": [
-        {
-          "name": "authority",
-          "signer": true
-        },
         {
           "name": "oracleConfig",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -819,28 +914,111 @@ export type SssOracle = {
               }
             ]
           }
+        },
+        {
+          "name": "feed",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.feed_symbol",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "accumulator",
+          "docs": [
+            "Required unless `oracle_config.pricing_mode` is `Spot`"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  97,
+                  99,
+                  99,
+                  117,
+                  109,
+                  117,
+                  108,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.feed_symbol",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "marketFeed",
+          "docs": [
+            "The peg fee curve's market feed — required when `oracle_config.peg_fee_curve` is set"
+          ],
+          "optional": true
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "diagnosticsParams"
+            }
+          }
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "priceDiagnostics"
+        }
+      }
     },
     {
-      "name": "updateCpiMultiplier",
+      "name": "getRedeemQuote",
       "docs": [
-        "Update CPI multiplier (monthly admin operation for CpiIndexed tokens)"
+        "Get a redeem quote — tokens in → USD out"
       ],
       "discriminator": [
-        173,
-        77,
-        84,
-        2,
-        36,
-        149,
-        224,
-        110
+        104,
+        228,
+        76,
+        87,
+        172,
+        49,
+        183,
+        137
       ],
       "accounts": [
         {
-          "name": "authority",
+          "name": "requester",
+          "writable": true,
           "signer": true
         },
         {
@@ -870,292 +1048,8670 @@ export type SssOracle = {
               }
             ]
           }
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "params",
-          "type": {
-            "defined": {
-              "name": "updateCpiParams"
-            }
+          "name": "feed",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.feed_symbol",
+                "account": "oracleConfig"
+              }
+            ]
           }
-        }
-      ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "feedRegistry",
-      "discriminator": [
-        165,
-        19,
-        142,
-        137,
-        18,
-        194,
-        61,
-        247
-      ]
-    },
-    {
-      "name": "oracleConfig",
-      "discriminator": [
-        133,
-        196,
-        152,
-        50,
-        27,
-        21,
-        145,
-        254
-      ]
-    },
-    {
-      "name": "pendingQuote",
-      "discriminator": [
-        205,
-        143,
-        177,
-        236,
-        152,
-        2,
-        209,
-        153
-      ]
-    }
-  ],
-  "events": [
-    {
-      "name": "authorityTransferProposed",
-      "discriminator": [
-        103,
-        244,
-        27,
-        116,
-        177,
-        4,
-        100,
-        119
-      ]
-    },
-    {
-      "name": "authorityTransferred",
-      "discriminator": [
-        245,
-        109,
-        179,
-        54,
-        135,
-        92,
-        22,
-        64
-      ]
+        },
+        {
+          "name": "accumulator",
+          "docs": [
+            "Required unless `oracle_config.pricing_mode` is `Spot`"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  97,
+                  99,
+                  99,
+                  117,
+                  109,
+                  117,
+                  108,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.feed_symbol",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "marketFeed",
+          "docs": [
+            "The peg fee curve's market feed — required when `oracle_config.peg_fee_curve` is set"
+          ],
+          "optional": true
+        },
+        {
+          "name": "quote",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  113,
+                  117,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              },
+              {
+                "kind": "arg",
+                "path": "params.nonce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "coreConfig",
+          "docs": [
+            "sss-core stablecoin config for the oracle's mint"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                250,
+                140,
+                13,
+                25,
+                80,
+                68,
+                152,
+                150,
+                61,
+                54,
+                95,
+                148,
+                2,
+                144,
+                201,
+                76,
+                91,
+                242,
+                87,
+                160,
+                13,
+                131,
+                158,
+                34,
+                2,
+                171,
+                121,
+                185,
+                50,
+                52,
+                15
+              ]
+            }
+          }
+        },
+        {
+          "name": "requesterAllowlist",
+          "docs": [
+            "Required when the mint has an active allowlist or the oracle a `min_kyc_tier`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "requesterBlacklist",
+          "docs": [
+            "`check_participant`. Required when the mint has a blacklister"
+          ],
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "getQuoteParams"
+            }
+          }
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "quoteResult"
+        }
+      }
     },
     {
-      "name": "cpiMultiplierUpdated",
+      "name": "getSwapQuote",
+      "docs": [
+        "Get a cross-stablecoin swap quote — token A in → token B out"
+      ],
       "discriminator": [
+        222,
         188,
-        2,
-        169,
-        182,
-        6,
-        143,
-        242,
-        65
-      ]
-    },
-    {
-      "name": "feedRegistered",
-      "discriminator": [
-        96,
-        127,
-        205,
-        247,
-        125,
-        203,
-        93,
-        60
-      ]
-    },
-    {
-      "name": "oracleInitialized",
-      "discriminator": [
-        42,
-        87,
-        109,
-        208,
-        1,
-        105,
-        101,
-        142
-      ]
-    },
-    {
-      "name": "oracleMint",
-      "discriminator": [
-        143,
-        237,
-        105,
-        171,
-        54,
-        159,
-        225,
-        132
-      ]
-    },
-    {
-      "name": "oraclePaused",
-      "discriminator": [
-        58,
-        194,
+        157,
+        50,
         226,
-        171,
-        90,
-        95,
-        183,
-        83
-      ]
-    },
-    {
-      "name": "oracleRedeem",
-      "discriminator": [
-        245,
-        239,
-        154,
-        129,
-        214,
-        88,
-        130,
-        236
-      ]
-    },
-    {
-      "name": "quoteGenerated",
-      "discriminator": [
-        48,
-        44,
-        96,
-        174,
-        113,
-        177,
-        104,
-        197
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "priceTooStale",
-      "msg": "Price feed is stale — last update exceeds max_staleness threshold"
-    },
-    {
-      "code": 6001,
-      "name": "invalidPrice",
-      "msg": "Price feed returned a negative or zero value"
-    },
-    {
-      "code": 6002,
-      "name": "confidenceTooWide",
-      "msg": "Price confidence interval is too wide — market is too volatile to price safely"
-    },
-    {
-      "code": 6003,
-      "name": "feedNotReady",
-      "msg": "Switchboard aggregator account data is invalid or unreadable"
-    },
-    {
-      "code": 6004,
-      "name": "quoteExpired",
-      "msg": "Quote has expired — please request a new quote"
-    },
-    {
-      "code": 6005,
-      "name": "quoteAlreadyUsed",
-      "msg": "Quote has already been used"
-    },
-    {
-      "code": 6006,
-      "name": "slippageExceeded",
-      "msg": "Token amount is below slippage threshold (min_output)"
-    },
-    {
-      "code": 6007,
+        235,
+        178,
+        94
+      ],
+      "accounts": [
+        {
+          "name": "requester",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "oracleIn",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_in.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oracleOut",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_out.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feedIn",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_in.feed_symbol",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feedOut",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_out.feed_symbol",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "accumulatorIn",
+          "docs": [
+            "Required unless `oracle_in.pricing_mode` is `Spot`"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  97,
+                  99,
+                  99,
+                  117,
+                  109,
+                  117,
+                  108,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_in.feed_symbol",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "accumulatorOut",
+          "docs": [
+            "Required unless `oracle_out.pricing_mode` is `Spot`"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  97,
+                  99,
+                  99,
+                  117,
+                  109,
+                  117,
+                  108,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_out.feed_symbol",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "quote",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  115,
+                  119,
+                  97,
+                  112,
+                  45,
+                  113,
+                  117,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_in.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              },
+              {
+                "kind": "arg",
+                "path": "params.nonce"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "getQuoteParams"
+            }
+          }
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "swapQuoteResult"
+        }
+      }
+    },
+    {
+      "name": "initializeAccumulator",
+      "docs": [
+        "Create the TWAP/EMA accumulator for a registered feed (registry authority)"
+      ],
+      "discriminator": [
+        4,
+        155,
+        7,
+        52,
+        125,
+        200,
+        102,
+        132
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100,
+                  45,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feed",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "params.feed_symbol"
+              }
+            ]
+          }
+        },
+        {
+          "name": "accumulator",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  97,
+                  99,
+                  99,
+                  117,
+                  109,
+                  117,
+                  108,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "params.feed_symbol"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "initializeAccumulatorParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeFeeVault",
+      "docs": [
+        "Create the oracle's fee vault token account"
+      ],
+      "discriminator": [
+        185,
+        140,
+        228,
+        234,
+        79,
+        203,
+        252,
+        50
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "feeManager",
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeOracle",
+      "docs": [
+        "Initialize an oracle config for a specific SSS token mint"
+      ],
+      "discriminator": [
+        144,
+        223,
+        131,
+        120,
+        196,
+        253,
+        181,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "Must be the mint's sss-core master authority"
+          ],
+          "signer": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "The SSS token mint; its decimals drive the quote math"
+          ]
+        },
+        {
+          "name": "coreConfig",
+          "docs": [
+            "sss-core stablecoin config for the mint"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                250,
+                140,
+                13,
+                25,
+                80,
+                68,
+                152,
+                150,
+                61,
+                54,
+                95,
+                148,
+                2,
+                144,
+                201,
+                76,
+                91,
+                242,
+                87,
+                160,
+                13,
+                131,
+                158,
+                34,
+                2,
+                171,
+                121,
+                185,
+                50,
+                52,
+                15
+              ]
+            }
+          }
+        },
+        {
+          "name": "oracleConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feed",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "params.feed_symbol"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "initializeOracleParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeRegistry",
+      "docs": [
+        "Initialize the global feed registry (one-time, admin only)"
+      ],
+      "discriminator": [
+        189,
+        181,
+        20,
+        17,
+        174,
+        57,
+        249,
+        59
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100,
+                  45,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeUserUsage",
+      "docs": [
+        "Create a requester's rolling USD usage account (permissionless)"
+      ],
+      "discriminator": [
+        60,
+        47,
+        149,
+        129,
+        62,
+        56,
+        134,
+        254
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "user"
+        },
+        {
+          "name": "oracleConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userUsage",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  117,
+                  115,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "user"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "mintAtMarket",
+      "docs": [
+        "Price and mint in one instruction — no quote account, bounded by min_output and max_price"
+      ],
+      "discriminator": [
+        68,
+        106,
+        250,
+        178,
+        237,
+        92,
+        235,
+        32
+      ],
+      "accounts": [
+        {
+          "name": "requester",
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feed",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.feed_symbol",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "accumulator",
+          "docs": [
+            "Required unless `oracle_config.pricing_mode` is `Spot`"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  97,
+                  99,
+                  99,
+                  117,
+                  109,
+                  117,
+                  108,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.feed_symbol",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "marketFeed",
+          "docs": [
+            "The peg fee curve's market feed — required when `oracle_config.peg_fee_curve` is set"
+          ],
+          "optional": true
+        },
+        {
+          "name": "coreConfig",
+          "docs": [
+            "sss-core stablecoin config for the oracle's mint"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                250,
+                140,
+                13,
+                25,
+                80,
+                68,
+                152,
+                150,
+                61,
+                54,
+                95,
+                148,
+                2,
+                144,
+                201,
+                76,
+                91,
+                242,
+                87,
+                160,
+                13,
+                131,
+                158,
+                34,
+                2,
+                171,
+                121,
+                185,
+                50,
+                52,
+                15
+              ]
+            }
+          }
+        },
+        {
+          "name": "minterConfig",
+          "docs": [
+            "sss-core minter entry registered for the oracle config PDA"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  109,
+                  105,
+                  110,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              },
+              {
+                "kind": "account",
+                "path": "oracleConfig"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                250,
+                140,
+                13,
+                25,
+                80,
+                68,
+                152,
+                150,
+                61,
+                54,
+                95,
+                148,
+                2,
+                144,
+                201,
+                76,
+                91,
+                242,
+                87,
+                160,
+                13,
+                131,
+                158,
+                34,
+                2,
+                171,
+                121,
+                185,
+                50,
+                52,
+                15
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "relations": [
+            "coreConfig"
+          ]
+        },
+        {
+          "name": "destination",
+          "writable": true
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "requesterAllowlist",
+          "docs": [
+            "Required when the mint has an active allowlist or the oracle a `min_kyc_tier`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "requesterBlacklist",
+          "docs": [
+            "`check_participant`. Required when the mint has a blacklister"
+          ],
+          "optional": true
+        },
+        {
+          "name": "recipientAllowlist",
+          "optional": true
+        },
+        {
+          "name": "recipientBlacklist",
+          "optional": true
+        },
+        {
+          "name": "userUsage",
+          "docs": [
+            "Requester's rolling USD usage — required when the oracle sets a per-user mint cap"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  117,
+                  115,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sssCoreProgram",
+          "address": "AVKfSdE7SLvR4NzK7C8kcSRK4BauKwSoUApZaUC5US9c"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "mintAtMarketParams"
+            }
+          }
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "quoteResult"
+        }
+      }
+    },
+    {
+      "name": "mintWithOracle",
+      "docs": [
+        "Execute a stored mint quote — validates and mints through sss-core atomically"
+      ],
+      "discriminator": [
+        59,
+        36,
+        162,
+        197,
+        12,
+        79,
+        194,
+        72
+      ],
+      "accounts": [
+        {
+          "name": "requester",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "quote",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  113,
+                  117,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              },
+              {
+                "kind": "account",
+                "path": "quote.nonce",
+                "account": "pendingQuote"
+              }
+            ]
+          }
+        },
+        {
+          "name": "coreConfig",
+          "docs": [
+            "sss-core stablecoin config for the oracle's mint"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                250,
+                140,
+                13,
+                25,
+                80,
+                68,
+                152,
+                150,
+                61,
+                54,
+                95,
+                148,
+                2,
+                144,
+                201,
+                76,
+                91,
+                242,
+                87,
+                160,
+                13,
+                131,
+                158,
+                34,
+                2,
+                171,
+                121,
+                185,
+                50,
+                52,
+                15
+              ]
+            }
+          }
+        },
+        {
+          "name": "minterConfig",
+          "docs": [
+            "sss-core minter entry registered for the oracle config PDA"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  109,
+                  105,
+                  110,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              },
+              {
+                "kind": "account",
+                "path": "oracleConfig"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                250,
+                140,
+                13,
+                25,
+                80,
+                68,
+                152,
+                150,
+                61,
+                54,
+                95,
+                148,
+                2,
+                144,
+                201,
+                76,
+                91,
+                242,
+                87,
+                160,
+                13,
+                131,
+                158,
+                34,
+                2,
+                171,
+                121,
+                185,
+                50,
+                52,
+                15
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "relations": [
+            "coreConfig"
+          ]
+        },
+        {
+          "name": "destination",
+          "writable": true
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "requesterAllowlist",
+          "docs": [
+            "Required when the mint has an active allowlist or the oracle a `min_kyc_tier`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "requesterBlacklist",
+          "docs": [
+            "`check_participant`. Required when the mint has a blacklister"
+          ],
+          "optional": true
+        },
+        {
+          "name": "recipientAllowlist",
+          "optional": true
+        },
+        {
+          "name": "recipientBlacklist",
+          "optional": true
+        },
+        {
+          "name": "userUsage",
+          "docs": [
+            "Requester's rolling USD usage — required when the oracle sets a per-user mint cap"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  117,
+                  115,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sssCoreProgram",
+          "address": "AVKfSdE7SLvR4NzK7C8kcSRK4BauKwSoUApZaUC5US9c"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "mintWithPsm",
+      "docs": [
+        "Execute a stored mint quote paid in PSM collateral — deposits and mints atomically"
+      ],
+      "discriminator": [
+        77,
+        225,
+        245,
+        58,
+        245,
+        139,
+        240,
+        14
+      ],
+      "accounts": [
+        {
+          "name": "requester",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "quote",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  113,
+                  117,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              },
+              {
+                "kind": "account",
+                "path": "quote.nonce",
+                "account": "pendingQuote"
+              }
+            ]
+          }
+        },
+        {
+          "name": "psmCollateral",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  112,
+                  115,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint"
+        },
+        {
+          "name": "collateralSource",
+          "docs": [
+            "Requester's collateral account the deposit is taken from"
+          ],
+          "writable": true
+        },
+        {
+          "name": "collateralVault",
+          "writable": true
+        },
+        {
+          "name": "coreConfig",
+          "docs": [
+            "sss-core stablecoin config for the oracle's mint"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                250,
+                140,
+                13,
+                25,
+                80,
+                68,
+                152,
+                150,
+                61,
+                54,
+                95,
+                148,
+                2,
+                144,
+                201,
+                76,
+                91,
+                242,
+                87,
+                160,
+                13,
+                131,
+                158,
+                34,
+                2,
+                171,
+                121,
+                185,
+                50,
+                52,
+                15
+              ]
+            }
+          }
+        },
+        {
+          "name": "minterConfig",
+          "docs": [
+            "sss-core minter entry registered for the oracle config PDA"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  109,
+                  105,
+                  110,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              },
+              {
+                "kind": "account",
+                "path": "oracleConfig"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                250,
+                140,
+                13,
+                25,
+                80,
+                68,
+                152,
+                150,
+                61,
+                54,
+                95,
+                148,
+                2,
+                144,
+                201,
+                76,
+                91,
+                242,
+                87,
+                160,
+                13,
+                131,
+                158,
+                34,
+                2,
+                171,
+                121,
+                185,
+                50,
+                52,
+                15
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "relations": [
+            "coreConfig"
+          ]
+        },
+        {
+          "name": "destination",
+          "writable": true
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "requesterAllowlist",
+          "docs": [
+            "Required when the mint has an active allowlist or the oracle a `min_kyc_tier`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "requesterBlacklist",
+          "docs": [
+            "`check_participant`. Required when the mint has a blacklister"
+          ],
+          "optional": true
+        },
+        {
+          "name": "recipientAllowlist",
+          "optional": true
+        },
+        {
+          "name": "recipientBlacklist",
+          "optional": true
+        },
+        {
+          "name": "userUsage",
+          "docs": [
+            "Requester's rolling USD usage — required when the oracle sets a per-user mint cap"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  117,
+                  115,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sssCoreProgram",
+          "address": "AVKfSdE7SLvR4NzK7C8kcSRK4BauKwSoUApZaUC5US9c"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "collateralTokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pauseOracle",
+      "docs": [
+        "Pause oracle mint/redeem operations (emergencies)"
+      ],
+      "discriminator": [
+        170,
+        116,
+        3,
+        121,
+        247,
+        109,
+        147,
+        191
+      ],
+      "accounts": [
+        {
+          "name": "pauser",
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "proposeAuthorityTransfer",
+      "docs": [
+        "Propose a two-step transfer of one role (its holder or the admin)"
+      ],
+      "discriminator": [
+        57,
+        206,
+        225,
+        129,
+        35,
+        111,
+        174,
+        145
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "oracleRole"
+            }
+          }
+        },
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "proposeRegistryAuthority",
+      "docs": [
+        "Propose a two-step registry authority transfer"
+      ],
+      "discriminator": [
+        125,
+        119,
+        150,
+        86,
+        165,
+        76,
+        48,
+        8
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100,
+                  45,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "queueConfigUpdate",
+      "docs": [
+        "Queue a risk-parameter update behind the config timelock"
+      ],
+      "discriminator": [
+        253,
+        118,
+        22,
+        101,
+        51,
+        203,
+        173,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feed",
+          "docs": [
+            "The feed named by `feed_symbol`; required when the update changes it"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "update",
+          "type": {
+            "defined": {
+              "name": "oracleParamsUpdate"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "redeemWithOracle",
+      "docs": [
+        "Execute a stored redeem quote — escrows/burns tokens and records the USD owed"
+      ],
+      "discriminator": [
+        53,
+        7,
+        126,
+        57,
+        244,
+        73,
+        90,
+        129
+      ],
+      "accounts": [
+        {
+          "name": "requester",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "quote",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  113,
+                  117,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              },
+              {
+                "kind": "account",
+                "path": "quote.nonce",
+                "account": "pendingQuote"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlement",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  115,
+                  101,
+                  116,
+                  116,
+                  108,
+                  101,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              },
+              {
+                "kind": "account",
+                "path": "quote.nonce",
+                "account": "pendingQuote"
+              }
+            ]
+          }
+        },
+        {
+          "name": "coreConfig",
+          "docs": [
+            "sss-core stablecoin config for the oracle's mint"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                250,
+                140,
+                13,
+                25,
+                80,
+                68,
+                152,
+                150,
+                61,
+                54,
+                95,
+                148,
+                2,
+                144,
+                201,
+                76,
+                91,
+                242,
+                87,
+                160,
+                13,
+                131,
+                158,
+                34,
+                2,
+                171,
+                121,
+                185,
+                50,
+                52,
+                15
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "relations": [
+            "coreConfig"
+          ]
+        },
+        {
+          "name": "source",
+          "docs": [
+            "Requester's token account the redeemed tokens are taken from"
+          ],
+          "writable": true
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Oracle escrow — associated token account of the oracle config PDA"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Oracle fee vault — receives the token share of the redeem fee"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "requesterAllowlist",
+          "docs": [
+            "Required when the mint has an active allowlist or the oracle a `min_kyc_tier`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "requesterBlacklist",
+          "docs": [
+            "`check_participant`. Required when the mint has a blacklister"
+          ],
+          "optional": true
+        },
+        {
+          "name": "userUsage",
+          "docs": [
+            "Requester's rolling USD usage — required when the oracle sets a per-user redeem cap"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  117,
+                  115,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sssCoreProgram",
+          "address": "AVKfSdE7SLvR4NzK7C8kcSRK4BauKwSoUApZaUC5US9c"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redeemWithPsm",
+      "docs": [
+        "Execute a stored redeem quote against PSM collateral — burns and releases atomically"
+      ],
+      "discriminator": [
+        174,
+        124,
+        201,
+        63,
+        28,
+        240,
+        103,
+        113
+      ],
+      "accounts": [
+        {
+          "name": "requester",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "quote",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  113,
+                  117,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              },
+              {
+                "kind": "account",
+                "path": "quote.nonce",
+                "account": "pendingQuote"
+              }
+            ]
+          }
+        },
+        {
+          "name": "psmCollateral",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  112,
+                  115,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint"
+        },
+        {
+          "name": "collateralVault",
+          "writable": true
+        },
+        {
+          "name": "collateralDestination",
+          "docs": [
+            "Collateral account the released collateral is paid to"
+          ],
+          "writable": true
+        },
+        {
+          "name": "coreConfig",
+          "docs": [
+            "sss-core stablecoin config for the oracle's mint"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                250,
+                140,
+                13,
+                25,
+                80,
+                68,
+                152,
+                150,
+                61,
+                54,
+                95,
+                148,
+                2,
+                144,
+                201,
+                76,
+                91,
+                242,
+                87,
+                160,
+                13,
+                131,
+                158,
+                34,
+                2,
+                171,
+                121,
+                185,
+                50,
+                52,
+                15
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "writable": true,
+          "relations": [
+            "coreConfig"
+          ]
+        },
+        {
+          "name": "source",
+          "docs": [
+            "Requester's token account the redeemed tokens are taken from"
+          ],
+          "writable": true
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Oracle escrow — associated token account of the oracle config PDA"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "feeVault",
+          "docs": [
+            "Oracle fee vault — receives the token share of the redeem fee"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "requesterAllowlist",
+          "docs": [
+            "Required when the mint has an active allowlist or the oracle a `min_kyc_tier`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "requesterBlacklist",
+          "docs": [
+            "`check_participant`. Required when the mint has a blacklister"
+          ],
+          "optional": true
+        },
+        {
+          "name": "userUsage",
+          "docs": [
+            "Requester's rolling USD usage — required when the oracle sets a per-user redeem cap"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  117,
+                  115,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sssCoreProgram",
+          "address": "AVKfSdE7SLvR4NzK7C8kcSRK4BauKwSoUApZaUC5US9c"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "collateralTokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerFeed",
+      "docs": [
+        "Register a new price feed (e.g. BRLUSD, EURUSD, JPYUSD)"
+      ],
+      "discriminator": [
+        138,
+        181,
+        114,
+        65,
+        71,
+        145,
+        61,
+        111
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "registry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100,
+                  45,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feed",
+          "docs": [
+            "Symbols are unique because the PDA can only be created once"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "params.symbol"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "registerFeedParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "registerPsmCollateral",
+      "docs": [
+        "Accept a USD stablecoin as PSM collateral for this mint, with a debt ceiling"
+      ],
+      "discriminator": [
+        30,
+        89,
+        204,
+        205,
+        168,
+        26,
+        226,
+        242
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint"
+        },
+        {
+          "name": "psmCollateral",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  112,
+                  115,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  112,
+                  115,
+                  109,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "collateralMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralTokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "debtCeilingUsd",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "rotateFeedSource",
+      "docs": [
+        "Replace a feed's source accounts (passed as remaining accounts)"
+      ],
+      "discriminator": [
+        188,
+        230,
+        96,
+        0,
+        246,
+        27,
+        90,
+        165
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100,
+                  45,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feed.symbol",
+                "account": "feedEntry"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "rotateFeedSourceParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setFeeSplits",
+      "docs": [
+        "Replace the fee-split recipients"
+      ],
+      "discriminator": [
+        128,
+        192,
+        6,
+        243,
+        95,
+        243,
+        227,
+        114
+      ],
+      "accounts": [
+        {
+          "name": "feeManager",
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "splits",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "feeSplit"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setManualPrice",
+      "docs": [
+        "Set an emergency manual price, bounded around the last good price"
+      ],
+      "discriminator": [
+        6,
+        210,
+        4,
+        51,
+        43,
+        53,
+        139,
+        140
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "manualPriceParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "swapWithOracle",
+      "docs": [
+        "Execute a stored swap quote — burns token A and mints token B through sss-core atomically"
+      ],
+      "discriminator": [
+        240,
+        161,
+        253,
+        138,
+        208,
+        164,
+        35,
+        74
+      ],
+      "accounts": [
+        {
+          "name": "requester",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "oracleIn",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_in.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oracleOut",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_out.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "quote",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  115,
+                  119,
+                  97,
+                  112,
+                  45,
+                  113,
+                  117,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_in.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              },
+              {
+                "kind": "account",
+                "path": "quote.nonce",
+                "account": "pendingSwapQuote"
+              }
+            ]
+          }
+        },
+        {
+          "name": "coreConfigIn",
+          "docs": [
+            "sss-core stablecoin config for token A"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mintIn"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                250,
+                140,
+                13,
+                25,
+                80,
+                68,
+                152,
+                150,
+                61,
+                54,
+                95,
+                148,
+                2,
+                144,
+                201,
+                76,
+                91,
+                242,
+                87,
+                160,
+                13,
+                131,
+                158,
+                34,
+                2,
+                171,
+                121,
+                185,
+                50,
+                52,
+                15
+              ]
+            }
+          }
+        },
+        {
+          "name": "mintIn",
+          "writable": true
+        },
+        {
+          "name": "source",
+          "docs": [
+            "Requester's token A account the swapped tokens are taken from"
+          ],
+          "writable": true
+        },
+        {
+          "name": "escrowIn",
+          "docs": [
+            "Input oracle escrow — associated token account of `oracle_in`"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "oracleIn"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mintIn"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "requesterAllowlistIn",
+          "docs": [
+            "Required when token A has an active allowlist or its oracle a `min_kyc_tier`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "requesterBlacklistIn",
+          "docs": [
+            "in `check_participant`. Required when token A has a blacklister"
+          ],
+          "optional": true
+        },
+        {
+          "name": "coreConfigOut",
+          "docs": [
+            "sss-core stablecoin config for token B"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mintOut"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                250,
+                140,
+                13,
+                25,
+                80,
+                68,
+                152,
+                150,
+                61,
+                54,
+                95,
+                148,
+                2,
+                144,
+                201,
+                76,
+                91,
+                242,
+                87,
+                160,
+                13,
+                131,
+                158,
+                34,
+                2,
+                171,
+                121,
+                185,
+                50,
+                52,
+                15
+              ]
+            }
+          }
+        },
+        {
+          "name": "minterConfigOut",
+          "docs": [
+            "sss-core minter entry registered for the output oracle config PDA"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  109,
+                  105,
+                  110,
+                  116,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mintOut"
+              },
+              {
+                "kind": "account",
+                "path": "oracleOut"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                250,
+                140,
+                13,
+                25,
+                80,
+                68,
+                152,
+                150,
+                61,
+                54,
+                95,
+                148,
+                2,
+                144,
+                201,
+                76,
+                91,
+                242,
+                87,
+                160,
+                13,
+                131,
+                158,
+                34,
+                2,
+                171,
+                121,
+                185,
+                50,
+                52,
+                15
+              ]
+            }
+          }
+        },
+        {
+          "name": "mintOut",
+          "writable": true
+        },
+        {
+          "name": "destination",
+          "writable": true
+        },
+        {
+          "name": "feeVaultOut",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mintOut"
+              }
+            ]
+          }
+        },
+        {
+          "name": "requesterAllowlist",
+          "docs": [
+            "Required when token B has an active allowlist or its oracle a `min_kyc_tier`"
+          ],
+          "optional": true
+        },
+        {
+          "name": "requesterBlacklist",
+          "docs": [
+            "in `check_participant`. Required when token B has a blacklister"
+          ],
+          "optional": true
+        },
+        {
+          "name": "recipientAllowlist",
+          "optional": true
+        },
+        {
+          "name": "recipientBlacklist",
+          "optional": true
+        },
+        {
+          "name": "userUsageIn",
+          "docs": [
+            "Requester's token A usage — required when `oracle_in` sets a per-user redeem cap"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  117,
+                  115,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_in.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              }
+            ]
+          }
+        },
+        {
+          "name": "userUsageOut",
+          "docs": [
+            "Requester's token B usage — required when `oracle_out` sets a per-user mint cap"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  117,
+                  115,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_out.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "requester"
+              }
+            ]
+          }
+        },
+        {
+          "name": "sssCoreProgram",
+          "address": "AVKfSdE7SLvR4NzK7C8kcSRK4BauKwSoUApZaUC5US9c"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unpauseOracle",
+      "docs": [
+        "Unpause oracle operations, acknowledging the current pause reason"
+      ],
+      "discriminator": [
+        187,
+        113,
+        244,
+        174,
+        46,
+        2,
+        129,
+        215
+      ],
+      "accounts": [
+        {
+          "name": "pauser",
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "unpauseParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateAccumulator",
+      "docs": [
+        "Fold the feed's current median into its accumulator (permissionless crank)"
+      ],
+      "discriminator": [
+        19,
+        190,
+        22,
+        228,
+        232,
+        37,
+        211,
+        35
+      ],
+      "accounts": [
+        {
+          "name": "accumulator",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  97,
+                  99,
+                  99,
+                  117,
+                  109,
+                  117,
+                  108,
+                  97,
+                  116,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "accumulator.feed_symbol",
+                "account": "priceAccumulator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feed",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "accumulator.feed_symbol",
+                "account": "priceAccumulator"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateCpiMultiplier",
+      "docs": [
+        "Update CPI multiplier (monthly CPI updater operation for CpiIndexed tokens)"
+      ],
+      "discriminator": [
+        173,
+        77,
+        84,
+        2,
+        36,
+        149,
+        224,
+        110
+      ],
+      "accounts": [
+        {
+          "name": "cpiUpdater",
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructions",
+          "docs": [
+            "Required when `oracle_config.cpi_attesters` is enabled."
+          ],
+          "optional": true,
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "updateCpiParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateFeedType",
+      "docs": [
+        "Change how a feed's price is interpreted (composite legs passed as remaining accounts)"
+      ],
+      "discriminator": [
+        210,
+        66,
+        207,
+        234,
+        82,
+        152,
+        249,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "registry",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100,
+                  45,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "feed.symbol",
+                "account": "feedEntry"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "feedType",
+          "type": {
+            "defined": {
+              "name": "feedType"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updatePsmCollateral",
+      "docs": [
+        "Change a PSM collateral's debt ceiling or active flag"
+      ],
+      "discriminator": [
+        207,
+        39,
+        65,
+        30,
+        176,
+        4,
+        110,
+        187
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "psmCollateral",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  112,
+                  115,
+                  109
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              },
+              {
+                "kind": "account",
+                "path": "psm_collateral.collateral_mint",
+                "account": "psmCollateral"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "updatePsmCollateralParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawFees",
+      "docs": [
+        "Pay `amount` out of the fee vault to the split recipients and treasury"
+      ],
+      "discriminator": [
+        198,
+        212,
+        171,
+        109,
+        144,
+        215,
+        174,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "feeManager",
+          "signer": true
+        },
+        {
+          "name": "oracleConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  111,
+                  114,
+                  97,
+                  99,
+                  108,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "oracle_config.mint",
+                "account": "oracleConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "feeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  115,
+                  115,
+                  45,
+                  102,
+                  101,
+                  101,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury token account receiving the non-split remainder"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "feedEntry",
+      "discriminator": [
+        89,
+        132,
+        41,
+        167,
+        16,
+        132,
+        1,
+        83
+      ]
+    },
+    {
+      "name": "feedRegistry",
+      "discriminator": [
+        165,
+        19,
+        142,
+        137,
+        18,
+        194,
+        61,
+        247
+      ]
+    },
+    {
+      "name": "minterConfig",
+      "discriminator": [
+        78,
+        211,
+        23,
+        6,
+        233,
+        19,
+        19,
+        236
+      ]
+    },
+    {
+      "name": "oracleConfig",
+      "discriminator": [
+        133,
+        196,
+        152,
+        50,
+        27,
+        21,
+        145,
+        254
+      ]
+    },
+    {
+      "name": "pendingQuote",
+      "discriminator": [
+        205,
+        143,
+        177,
+        236,
+        152,
+        2,
+        209,
+        153
+      ]
+    },
+    {
+      "name": "pendingSwapQuote",
+      "discriminator": [
+        102,
+        139,
+        43,
+        180,
+        47,
+        66,
+        197,
+        170
+      ]
+    },
+    {
+      "name": "priceAccumulator",
+      "discriminator": [
+        123,
+        50,
+        156,
+        92,
+        242,
+        133,
+        211,
+        173
+      ]
+    },
+    {
+      "name": "psmCollateral",
+      "discriminator": [
+        177,
+        147,
+        202,
+        155,
+        114,
+        86,
+        186,
+        180
+      ]
+    },
+    {
+      "name": "redemptionSettlement",
+      "discriminator": [
+        12,
+        144,
+        129,
+        217,
+        11,
+        80,
+        36,
+        39
+      ]
+    },
+    {
+      "name": "stablecoinConfig",
+      "discriminator": [
+        127,
+        25,
+        244,
+        213,
+        1,
+        192,
+        101,
+        6
+      ]
+    },
+    {
+      "name": "userUsage",
+      "discriminator": [
+        168,
+        76,
+        156,
+        168,
+        138,
+        82,
+        243,
+        195
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "accumulatorInitialized",
+      "discriminator": [
+        180,
+        138,
+        241,
+        166,
+        153,
+        163,
+        62,
+        70
+      ]
+    },
+    {
+      "name": "accumulatorUpdated",
+      "discriminator": [
+        107,
+        85,
+        107,
+        83,
+        31,
+        115,
+        47,
+        141
+      ]
+    },
+    {
+      "name": "authorityTransferProposed",
+      "discriminator": [
+        103,
+        244,
+        27,
+        116,
+        177,
+        4,
+        100,
+        119
+      ]
+    },
+    {
+      "name": "authorityTransferred",
+      "discriminator": [
+        245,
+        109,
+        179,
+        54,
+        135,
+        92,
+        22,
+        64
+      ]
+    },
+    {
+      "name": "circuitBreakerTripped",
+      "discriminator": [
+        188,
+        9,
+        111,
+        118,
+        136,
+        206,
+        199,
+        65
+      ]
+    },
+    {
+      "name": "configUpdateApplied",
+      "discriminator": [
+        133,
+        148,
+        99,
+        171,
+        143,
+        37,
+        110,
+        118
+      ]
+    },
+    {
+      "name": "configUpdateCancelled",
+      "discriminator": [
+        118,
+        51,
+        223,
+        222,
+        189,
+        52,
+        133,
+        14
+      ]
+    },
+    {
+      "name": "configUpdateQueued",
+      "discriminator": [
+        179,
+        132,
+        252,
+        232,
+        56,
+        112,
+        206,
+        78
+      ]
+    },
+    {
+      "name": "cpiMultiplierUpdated",
+      "discriminator": [
+        188,
+        2,
+        169,
+        182,
+        6,
+        143,
+        242,
+        65
+      ]
+    },
+    {
+      "name": "feeSplitsUpdated",
+      "discriminator": [
+        193,
+        99,
+        35,
+        34,
+        115,
+        223,
+        244,
+        200
+      ]
+    },
+    {
+      "name": "feeVaultInitialized",
+      "discriminator": [
+        135,
+        217,
+        48,
+        80,
+        145,
+        35,
+        200,
+        130
+      ]
+    },
+    {
+      "name": "feedDeactivated",
+      "discriminator": [
+        82,
+        150,
+        135,
+        228,
+        218,
+        82,
+        248,
+        115
+      ]
+    },
+    {
+      "name": "feedRegistered",
+      "discriminator": [
+        96,
+        127,
+        205,
+        247,
+        125,
+        203,
+        93,
+        60
+      ]
+    },
+    {
+      "name": "feedSourceRotated",
+      "discriminator": [
+        202,
+        187,
+        211,
+        64,
+        157,
+        182,
+        177,
+        248
+      ]
+    },
+    {
+      "name": "feedTypeUpdated",
+      "discriminator": [
+        173,
+        192,
+        251,
+        103,
+        111,
+        130,
+        175,
+        34
+      ]
+    },
+    {
+      "name": "feesWithdrawn",
+      "discriminator": [
+        234,
+        15,
+        0,
+        119,
+        148,
+        241,
+        40,
+        21
+      ]
+    },
+    {
+      "name": "issuanceCapExceeded",
+      "discriminator": [
+        17,
+        91,
+        229,
+        109,
+        14,
+        2,
+        184,
+        166
+      ]
+    },
+    {
+      "name": "manualPriceCleared",
+      "discriminator": [
+        6,
+        195,
+        26,
+        237,
+        221,
+        80,
+        83,
+        100
+      ]
+    },
+    {
+      "name": "manualPriceSet",
+      "discriminator": [
+        214,
+        121,
+        224,
+        81,
+        154,
+        2,
+        130,
+        105
+      ]
+    },
+    {
+      "name": "oracleInitialized",
+      "discriminator": [
+        42,
+        87,
+        109,
+        208,
+        1,
+        105,
+        101,
+        142
+      ]
+    },
+    {
+      "name": "oracleMint",
+      "discriminator": [
+        143,
+        237,
+        105,
+        171,
+        54,
+        159,
+        225,
+        132
+      ]
+    },
+    {
+      "name": "oraclePaused",
+      "discriminator": [
+        58,
+        194,
+        226,
+        171,
+        90,
+        95,
+        183,
+        83
+      ]
+    },
+    {
+      "name": "oracleRedeem",
+      "discriminator": [
+        245,
+        239,
+        154,
+        129,
+        214,
+        88,
+        130,
+        236
+      ]
+    },
+    {
+      "name": "psmCollateralRegistered",
+      "discriminator": [
+        30,
+        144,
+        161,
+        131,
+        20,
+        144,
+        255,
+        254
+      ]
+    },
+    {
+      "name": "psmCollateralUpdated",
+      "discriminator": [
+        251,
+        239,
+        179,
+        88,
+        40,
+        146,
+        154,
+        92
+      ]
+    },
+    {
+      "name": "psmMint",
+      "discriminator": [
+        49,
+        92,
+        246,
+        227,
+        66,
+        10,
+        207,
+        151
+      ]
+    },
+    {
+      "name": "psmRedeem",
+      "discriminator": [
+        153,
+        191,
+        140,
+        183,
+        240,
+        242,
+        166,
+        31
+      ]
+    },
+    {
+      "name": "quoteClosed",
+      "discriminator": [
+        122,
+        148,
+        0,
+        81,
+        131,
+        223,
+        234,
+        203
+      ]
+    },
+    {
+      "name": "quoteGenerated",
+      "discriminator": [
+        48,
+        44,
+        96,
+        174,
+        113,
+        177,
+        104,
+        197
+      ]
+    },
+    {
+      "name": "redemptionSettled",
+      "discriminator": [
+        75,
+        9,
+        197,
+        96,
+        249,
+        61,
+        27,
+        245
+      ]
+    },
+    {
+      "name": "registryAuthorityProposed",
+      "discriminator": [
+        192,
+        160,
+        68,
+        80,
+        58,
+        110,
+        6,
+        51
+      ]
+    },
+    {
+      "name": "registryAuthorityTransferred",
+      "discriminator": [
+        70,
+        11,
+        67,
+        54,
+        50,
+        206,
+        25,
+        115
+      ]
+    },
+    {
+      "name": "swapExecuted",
+      "discriminator": [
+        150,
+        166,
+        26,
+        225,
+        28,
+        89,
+        38,
+        79
+      ]
+    },
+    {
+      "name": "swapQuoteGenerated",
+      "discriminator": [
+        199,
+        113,
+        201,
+        168,
+        53,
+        58,
+        199,
+        157
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "priceTooStale",
+      "msg": "Price feed is stale — last update exceeds max_staleness threshold"
+    },
+    {
+      "code": 6001,
+      "name": "invalidPrice",
+      "msg": "Price feed returned a negative or zero value"
+    },
+    {
+      "code": 6002,
+      "name": "confidenceTooWide",
+      "msg": "Price confidence interval is too wide — market is too volatile to price safely"
+    },
+    {
+      "code": 6003,
+      "name": "feedNotReady",
+      "msg": "Switchboard aggregator account data is invalid or unreadable"
+    },
+    {
+      "code": 6004,
+      "name": "quoteExpired",
+      "msg": "Quote has expired — please request a new quote"
+    },
+    {
+      "code": 6005,
+      "name": "quoteAlreadyUsed",
+      "msg": "Quote has already been used"
+    },
+    {
+      "code": 6006,
+      "name": "slippageExceeded",
+      "msg": "Token amount is below slippage threshold (min_output)"
+    },
+    {
+      "code": 6007,
       "name": "zeroAmount",
       "msg": "Input amount must be greater than zero"
     },
     {
-      "code": 6008,
-      "name": "zeroOutput",
-      "msg": "Calculated output amount is zero after fee deduction"
+      "code": 6008,
+      "name": "zeroOutput",
+      "msg": "Calculated output amount is zero after fee deduction"
+    },
+    {
+      "code": 6009,
+      "name": "feedAlreadyRegistered",
+      "msg": "Feed with this symbol already exists in the registry"
+    },
+    {
+      "code": 6010,
+      "name": "feedNotFound",
+      "msg": "Feed symbol not found in registry"
+    },
+    {
+      "code": 6011,
+      "name": "symbolTooLong",
+      "msg": "Feed symbol exceeds maximum length of 12 characters"
+    },
+    {
+      "code": 6012,
+      "name": "registryFull",
+      "msg": "Registry is at maximum capacity (64 feeds)"
+    },
+    {
+      "code": 6013,
+      "name": "oraclePaused",
+      "msg": "Oracle is paused — mint and redeem operations are suspended"
+    },
+    {
+      "code": 6014,
+      "name": "unauthorized",
+      "msg": "Signer does not hold the oracle role or authority this instruction requires"
+    },
+    {
+      "code": 6015,
+      "name": "noPendingTransfer",
+      "msg": "No pending authority transfer exists"
+    },
+    {
+      "code": 6016,
+      "name": "invalidCpiMultiplier",
+      "msg": "CPI multiplier cannot be zero"
+    },
+    {
+      "code": 6017,
+      "name": "cpiUpdateTooSoon",
+      "msg": "CPI multiplier update too soon — minimum interval not reached"
+    },
+    {
+      "code": 6018,
+      "name": "mathOverflow",
+      "msg": "Arithmetic overflow in calculation"
+    },
+    {
+      "code": 6019,
+      "name": "divisionByZero",
+      "msg": "Division by zero in price calculation"
+    },
+    {
+      "code": 6020,
+      "name": "feedMismatch",
+      "msg": "Switchboard feed account mismatch — wrong feed passed"
+    },
+    {
+      "code": 6021,
+      "name": "invalidSourceCount",
+      "msg": "A feed needs between 1 and 3 source accounts, and min_sources within that count"
+    },
+    {
+      "code": 6022,
+      "name": "duplicateSource",
+      "msg": "The same source account was passed more than once"
+    },
+    {
+      "code": 6023,
+      "name": "insufficientSources",
+      "msg": "Not enough fresh price sources to produce a quote"
+    },
+    {
+      "code": 6024,
+      "name": "sourceSpreadTooWide",
+      "msg": "Spread between price sources exceeds max_spread_bps"
+    },
+    {
+      "code": 6025,
+      "name": "mintMismatch",
+      "msg": "Mint account does not match the oracle config mint"
+    },
+    {
+      "code": 6026,
+      "name": "alreadySettled",
+      "msg": "Redemption settlement has already been confirmed"
+    },
+    {
+      "code": 6027,
+      "name": "payoutReferenceTooLong",
+      "msg": "Payout reference exceeds maximum length of 64 characters"
+    },
+    {
+      "code": 6028,
+      "name": "insufficientVerification",
+      "msg": "Pyth price update is not fully verified"
+    },
+    {
+      "code": 6029,
+      "name": "sourceKindMismatch",
+      "msg": "source_kinds must list one provider per source account"
+    },
+    {
+      "code": 6030,
+      "name": "invalidCircuitBreakerConfig",
+      "msg": "Circuit breaker needs a positive window when max_price_move_bps is set"
+    },
+    {
+      "code": 6031,
+      "name": "pauseAcknowledgementMismatch",
+      "msg": "Unpause must acknowledge the current pause reason"
+    },
+    {
+      "code": 6032,
+      "name": "invalidAccumulatorConfig",
+      "msg": "Accumulator periods must be positive and the TWAP window must fit the observation buffer"
+    },
+    {
+      "code": 6033,
+      "name": "accumulatorUpdateTooSoon",
+      "msg": "Accumulator update too soon — minimum interval not reached"
+    },
+    {
+      "code": 6034,
+      "name": "accumulatorRequired",
+      "msg": "Pricing mode needs the feed's price accumulator account"
+    },
+    {
+      "code": 6035,
+      "name": "accumulatorNotReady",
+      "msg": "Accumulator has not been cranked for a full TWAP window yet"
+    },
+    {
+      "code": 6036,
+      "name": "accumulatorStale",
+      "msg": "Accumulator is stale — last crank exceeds max_staleness threshold"
+    },
+    {
+      "code": 6037,
+      "name": "feeTooHigh",
+      "msg": "Fee exceeds the maximum of 1000 bps"
+    },
+    {
+      "code": 6038,
+      "name": "riskParamOutOfBounds",
+      "msg": "Risk parameter is outside its allowed bounds"
+    },
+    {
+      "code": 6039,
+      "name": "descriptionTooLong",
+      "msg": "Description exceeds maximum length of 100 characters"
+    },
+    {
+      "code": 6040,
+      "name": "emptyConfigUpdate",
+      "msg": "Config update changes nothing"
+    },
+    {
+      "code": 6041,
+      "name": "configUpdatePending",
+      "msg": "A config update is already queued — cancel it first"
+    },
+    {
+      "code": 6042,
+      "name": "noPendingConfigUpdate",
+      "msg": "No config update is queued"
+    },
+    {
+      "code": 6043,
+      "name": "configTimelockNotElapsed",
+      "msg": "Config update timelock has not elapsed"
+    },
+    {
+      "code": 6044,
+      "name": "invalidFeeSplits",
+      "msg": "Fee splits: at most 4 distinct recipients, non-zero shares, 10000 bps total"
+    },
+    {
+      "code": 6045,
+      "name": "feeSplitAccountMismatch",
+      "msg": "Fee split recipient accounts do not match the configured splits"
+    },
+    {
+      "code": 6046,
+      "name": "insufficientFees",
+      "msg": "Withdrawal exceeds the fee vault balance"
+    },
+    {
+      "code": 6047,
+      "name": "unsupportedDecimals",
+      "msg": "Mint or feed decimals exceed the supported maximum of 12"
+    },
+    {
+      "code": 6048,
+      "name": "feedInactive",
+      "msg": "Feed has been deactivated"
+    },
+    {
+      "code": 6049,
+      "name": "manualPriceDisabled",
+      "msg": "Manual price overrides are disabled (manual_price_band_bps is 0)"
+    },
+    {
+      "code": 6050,
+      "name": "noReferencePrice",
+      "msg": "Manual price needs a last good price to bound it"
+    },
+    {
+      "code": 6051,
+      "name": "manualPriceOutOfBand",
+      "msg": "Manual price is outside the allowed band around the last good price"
+    },
+    {
+      "code": 6052,
+      "name": "invalidManualPrice",
+      "msg": "Manual price must be non-zero, last at most 24h and carry a reason of at most 64 chars"
+    },
+    {
+      "code": 6053,
+      "name": "noManualPrice",
+      "msg": "No manual price is active"
+    },
+    {
+      "code": 6054,
+      "name": "cpiChangeTooLarge",
+      "msg": "CPI update exceeds cpi_max_change_bps of the current multiplier"
+    },
+    {
+      "code": 6055,
+      "name": "invalidCpiAccrualWindow",
+      "msg": "CPI accrual window must be between 0 and 366 days"
+    },
+    {
+      "code": 6056,
+      "name": "invalidAttesterSet",
+      "msg": "CPI attesters: at most 5 distinct keys, threshold within 1..=N (0 with none), tolerance at most 500 bps"
+    },
+    {
+      "code": 6057,
+      "name": "malformedEd25519Instruction",
+      "msg": "Ed25519 instruction is malformed or references another instruction's data"
+    },
+    {
+      "code": 6058,
+      "name": "insufficientAttestations",
+      "msg": "Fewer CPI attestations than the attester threshold"
+    },
+    {
+      "code": 6059,
+      "name": "attestationsDisagree",
+      "msg": "Attested CPI multipliers disagree beyond the tolerance"
+    },
+    {
+      "code": 6060,
+      "name": "attestedMultiplierMismatch",
+      "msg": "CPI multiplier does not match the median of the attested values"
+    },
+    {
+      "code": 6061,
+      "name": "invalidReferenceMonth",
+      "msg": "Reference month must be YYYY-MM and later than the last attested month"
+    },
+    {
+      "code": 6062,
+      "name": "swapSameMint",
+      "msg": "Swap input and output mints must differ"
+    },
+    {
+      "code": 6063,
+      "name": "swapRequiresBurner",
+      "msg": "Swaps need the input oracle config to hold the sss-core burner role"
+    },
+    {
+      "code": 6064,
+      "name": "invalidCompositeLegs",
+      "msg": "Composite feeds need 2 to 3 distinct legs, each an active, registered, non-composite feed"
+    },
+    {
+      "code": 6065,
+      "name": "compositeFeedChange",
+      "msg": "Composite feeds have no sources, and cannot become or stop being composite"
+    },
+    {
+      "code": 6066,
+      "name": "psmCollateralInactive",
+      "msg": "PSM collateral is not accepting new mints"
+    },
+    {
+      "code": 6067,
+      "name": "debtCeilingExceeded",
+      "msg": "PSM mint would exceed the collateral's debt ceiling"
+    },
+    {
+      "code": 6068,
+      "name": "psmRequiresBurner",
+      "msg": "PSM redemptions need the oracle config to hold the sss-core burner role"
+    },
+    {
+      "code": 6069,
+      "name": "insufficientPsmCollateral",
+      "msg": "PSM redemption exceeds the debt outstanding against this collateral"
+    },
+    {
+      "code": 6070,
+      "name": "quoteNotExpired",
+      "msg": "Quote has not expired yet — only the requester can use it until valid_until"
+    },
+    {
+      "code": 6071,
+      "name": "invalidQuoteAccounts",
+      "msg": "Expired quotes must be passed as (quote, requester) account pairs"
+    },
+    {
+      "code": 6072,
+      "name": "priceAboveMax",
+      "msg": "All-in mint price exceeds max_price"
+    },
+    {
+      "code": 6073,
+      "name": "complianceAccountMismatch",
+      "msg": "sss-core allowlist or blacklist entry missing or not the participant's PDA"
+    },
+    {
+      "code": 6074,
+      "name": "participantBlacklisted",
+      "msg": "Participant is blacklisted for this mint"
+    },
+    {
+      "code": 6075,
+      "name": "notAllowlisted",
+      "msg": "Participant has no sss-core allowlist entry valid for receiving this mint"
+    },
+    {
+      "code": 6076,
+      "name": "kycTierTooLow",
+      "msg": "Participant's KYC tier is below the oracle's min_kyc_tier"
+    },
+    {
+      "code": 6077,
+      "name": "globalMintCapExceeded",
+      "msg": "Mint would exceed the oracle's rolling 24h global mint cap"
+    },
+    {
+      "code": 6078,
+      "name": "globalRedeemCapExceeded",
+      "msg": "Redemption would exceed the oracle's rolling 24h global redeem cap"
+    },
+    {
+      "code": 6079,
+      "name": "userMintCapExceeded",
+      "msg": "Mint would exceed the requester's rolling 24h mint cap"
+    },
+    {
+      "code": 6080,
+      "name": "userRedeemCapExceeded",
+      "msg": "Redemption would exceed the requester's rolling 24h redeem cap"
+    },
+    {
+      "code": 6081,
+      "name": "userUsageRequired",
+      "msg": "Per-user caps are set — pass the requester's UserUsage account"
+    },
+    {
+      "code": 6082,
+      "name": "invalidFeeCurve",
+      "msg": "Peg fee curve needs 2..=8 points with strictly increasing deviation and a market feed"
+    },
+    {
+      "code": 6083,
+      "name": "marketFeedRequired",
+      "msg": "Peg fee curve is set — pass its market feed and that feed's sources"
+    },
+    {
+      "code": 6084,
+      "name": "invalidQuoteDenomination",
+      "msg": "Quote denomination needs an uppercase ISO 4217 code and at most 12 decimals"
+    },
+    {
+      "code": 6085,
+      "name": "quoteDenominationMismatch",
+      "msg": "Swap legs' oracles quote in different denominations"
+    },
+    {
+      "code": 6086,
+      "name": "psmRequiresUsdQuote",
+      "msg": "The peg stability module only backs oracles quoted in USD"
+    },
+    {
+      "code": 6087,
+      "name": "feedCurrencyMismatch",
+      "msg": "Feed's quote currency differs from the oracle's quote denomination"
+    },
+    {
+      "code": 6088,
+      "name": "redeemRequiresBurner",
+      "msg": "Redemptions need the oracle config to hold the sss-core burner role"
+    }
+  ],
+  "types": [
+    {
+      "name": "accumulatorInitialized",
+      "docs": [
+        "Emitted when a feed's price accumulator is created"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feedSymbol",
+            "type": "string"
+          },
+          {
+            "name": "twapWindowSecs",
+            "type": "i64"
+          },
+          {
+            "name": "emaPeriodSecs",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "accumulatorUpdated",
+      "docs": [
+        "Emitted on every accumulator crank"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feedSymbol",
+            "type": "string"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "emaPrice",
+            "type": "u64"
+          },
+          {
+            "name": "cumulativePrice",
+            "type": "u128"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityTransferProposed",
+      "docs": [
+        "Emitted when a transfer of an oracle role is proposed"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "role",
+            "type": {
+              "defined": {
+                "name": "oracleRole"
+              }
+            }
+          },
+          {
+            "name": "current",
+            "type": "pubkey"
+          },
+          {
+            "name": "proposed",
+            "type": "pubkey"
+          },
+          {
+            "name": "proposedBy",
+            "docs": [
+              "The role's holder or the admin"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityTransferred",
+      "docs": [
+        "Emitted when a transfer of an oracle role is accepted"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "role",
+            "type": {
+              "defined": {
+                "name": "oracleRole"
+              }
+            }
+          },
+          {
+            "name": "from",
+            "type": "pubkey"
+          },
+          {
+            "name": "to",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "circuitBreakerTripped",
+      "docs": [
+        "Emitted when a feed reading breaches the price band and pauses the oracle"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "feedSymbol",
+            "type": "string"
+          },
+          {
+            "name": "lastGoodPrice",
+            "type": "u64"
+          },
+          {
+            "name": "lastGoodAt",
+            "type": "i64"
+          },
+          {
+            "name": "newPrice",
+            "type": "u64"
+          },
+          {
+            "name": "moveBps",
+            "type": "u64"
+          },
+          {
+            "name": "allowedBps",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "compositeLeg",
+      "docs": [
+        "One registered feed inside a composite feed"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "symbol",
+            "docs": [
+              "Symbol of the registered feed"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "op",
+            "docs": [
+              "Whether the leg's price multiplies or divides the running price"
+            ],
+            "type": {
+              "defined": {
+                "name": "compositeOp"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "compositeOp",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "multiply"
+          },
+          {
+            "name": "divide"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configUpdateApplied",
+      "docs": [
+        "Emitted when a queued risk-parameter update takes effect"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "update",
+            "type": {
+              "defined": {
+                "name": "oracleParamsUpdate"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configUpdateCancelled",
+      "docs": [
+        "Emitted when a queued risk-parameter update is cancelled"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "update",
+            "type": {
+              "defined": {
+                "name": "oracleParamsUpdate"
+              }
+            }
+          },
+          {
+            "name": "by",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "configUpdateQueued",
+      "docs": [
+        "Emitted when a risk-parameter update is queued behind the timelock"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "update",
+            "type": {
+              "defined": {
+                "name": "oracleParamsUpdate"
+              }
+            }
+          },
+          {
+            "name": "eta",
+            "type": "i64"
+          },
+          {
+            "name": "by",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "cpiAttesterSet",
+      "docs": [
+        "M-of-N attesters for CPI updates. Each attester signs",
+        "`(reference_month, multiplier)` through the Ed25519 precompile."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "attesters",
+            "docs": [
+              "Attester signing keys (0..=MAX_CPI_ATTESTERS, distinct)"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "Signatures required (M); 0 only with no attesters"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "toleranceBps",
+            "docs": [
+              "Max spread between the attested multipliers, in bps of their median"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "cpiMultiplierUpdated",
+      "docs": [
+        "Emitted when CPI multiplier is updated"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldMultiplier",
+            "docs": [
+              "Multiplier in effect when the update landed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "newMultiplier",
+            "docs": [
+              "Target reached at `accrual_end`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "changeBps",
+            "type": "u64"
+          },
+          {
+            "name": "accrualStart",
+            "type": "i64"
+          },
+          {
+            "name": "accrualEnd",
+            "type": "i64"
+          },
+          {
+            "name": "referenceMonth",
+            "type": "string"
+          },
+          {
+            "name": "dataSource",
+            "type": "string"
+          },
+          {
+            "name": "attestedBy",
+            "docs": [
+              "Attesters whose signatures backed the update (empty without an attester set)"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "updatedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "diagnosticsParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "direction",
+            "type": {
+              "defined": {
+                "name": "quoteDirection"
+              }
+            }
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Quote units for mint, token base units for redeem"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeCurvePoint",
+      "docs": [
+        "One point of a peg fee curve: the fees at a market deviation from peg."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "deviationBps",
+            "docs": [
+              "Market price vs. the oracle's fair value, in bps (negative = below peg)"
+            ],
+            "type": "i32"
+          },
+          {
+            "name": "mintFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "redeemFeeBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeSplit",
+      "docs": [
+        "A revenue-share recipient: receives `bps` of every fee withdrawal, the",
+        "remainder goes to the treasury."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "docs": [
+              "Recipient token account (same mint)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bps",
+            "docs": [
+              "Share of each withdrawal in bps"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeSplitsUpdated",
+      "docs": [
+        "Emitted when fee-split recipients are replaced"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "splits",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeSplit"
+                }
+              }
+            }
+          },
+          {
+            "name": "by",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeVaultInitialized",
+      "docs": [
+        "Emitted when the fee vault is created"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "feeVault",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feedDeactivated",
+      "docs": [
+        "Emitted when a feed is deactivated"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "reason",
+            "type": "string"
+          },
+          {
+            "name": "by",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feedEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "symbol",
+            "docs": [
+              "Feed symbol, max 12 chars: \"BRLUSD\", \"EURUSD\""
+            ],
+            "type": "string"
+          },
+          {
+            "name": "sources",
+            "docs": [
+              "Price source accounts backing this feed (1..=MAX_FEED_SOURCES).",
+              "The quote price is the median of the fresh sources. Empty for",
+              "composite feeds, whose legs carry the sources."
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "priceSource"
+                }
+              }
+            }
+          },
+          {
+            "name": "minSources",
+            "docs": [
+              "Minimum number of fresh sources required to quote (0 for composite feeds)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "maxSpreadBps",
+            "docs": [
+              "Max allowed spread between the fresh sources, as bps of the median (0 = no check)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feedType",
+            "docs": [
+              "How to interpret the price value"
+            ],
+            "type": {
+              "defined": {
+                "name": "feedType"
+              }
+            }
+          },
+          {
+            "name": "baseCurrency",
+            "docs": [
+              "Base currency code, max 8 chars: \"BRL\", \"EUR\""
+            ],
+            "type": "string"
+          },
+          {
+            "name": "quoteCurrency",
+            "docs": [
+              "Quote currency code, max 8 chars: \"USD\""
+            ],
+            "type": "string"
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "Decimals quotes price this feed at (fixed-point scale of `price_scaled`)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "active",
+            "docs": [
+              "Whether this feed is currently active"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "registeredAt",
+            "docs": [
+              "When was this feed registered"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "registeredBy",
+            "docs": [
+              "Who registered it"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "updatedAt",
+            "docs": [
+              "Last time the sources or feed type were changed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feedRegistered",
+      "docs": [
+        "Emitted when a new price feed is registered globally"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "sources",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "priceSource"
+                }
+              }
+            }
+          },
+          {
+            "name": "minSources",
+            "type": "u8"
+          },
+          {
+            "name": "maxSpreadBps",
+            "type": "u16"
+          },
+          {
+            "name": "feedType",
+            "type": "string"
+          },
+          {
+            "name": "baseCurrency",
+            "type": "string"
+          },
+          {
+            "name": "quoteCurrency",
+            "type": "string"
+          },
+          {
+            "name": "registeredBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feedRegistry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "docs": [
+              "Authority who can register and manage feeds"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAuthority",
+            "docs": [
+              "Proposed new authority (two-step transfer)"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "feedCount",
+            "docs": [
+              "Number of feeds ever registered (deactivated feeds included)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feedSourceRotated",
+      "docs": [
+        "Emitted when a feed's source accounts are replaced"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "oldSources",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "priceSource"
+                }
+              }
+            }
+          },
+          {
+            "name": "newSources",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "priceSource"
+                }
+              }
+            }
+          },
+          {
+            "name": "minSources",
+            "type": "u8"
+          },
+          {
+            "name": "maxSpreadBps",
+            "type": "u16"
+          },
+          {
+            "name": "by",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feedType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "direct"
+          },
+          {
+            "name": "inverse"
+          },
+          {
+            "name": "cpiIndexed"
+          },
+          {
+            "name": "custom",
+            "fields": [
+              {
+                "name": "numerator",
+                "type": "u64"
+              },
+              {
+                "name": "denominator",
+                "type": "u64"
+              },
+              {
+                "name": "baseType",
+                "docs": [
+                  "0 = Direct after scaling, 1 = Inverse after scaling"
+                ],
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "composite",
+            "fields": [
+              {
+                "name": "legs",
+                "docs": [
+                  "2..=MAX_COMPOSITE_LEGS registered, non-composite feeds"
+                ],
+                "type": {
+                  "vec": {
+                    "defined": {
+                      "name": "compositeLeg"
+                    }
+                  }
+                }
+              },
+              {
+                "name": "baseType",
+                "docs": [
+                  "0 = Direct after composing, 1 = Inverse after composing"
+                ],
+                "type": "u8"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "feedTypeUpdated",
+      "docs": [
+        "Emitted when a feed's price interpretation changes"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "oldType",
+            "type": "string"
+          },
+          {
+            "name": "newType",
+            "type": "string"
+          },
+          {
+            "name": "by",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feesWithdrawn",
+      "docs": [
+        "Emitted when fees are paid out of the fee vault"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "treasury",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "toTreasury",
+            "type": "u64"
+          },
+          {
+            "name": "splitAmounts",
+            "docs": [
+              "Paid to each `fee_splits` recipient, in order"
+            ],
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "by",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "getQuoteParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "inputAmount",
+            "docs": [
+              "Input amount: quote units (`OracleConfig.quote_denomination`) for mint,",
+              "token base units for redeem"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minOutput",
+            "docs": [
+              "Minimum acceptable output (slippage floor)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Caller-provided nonce to avoid PDA collision between quotes"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "initializeAccumulatorParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feedSymbol",
+            "type": "string"
+          },
+          {
+            "name": "twapWindowSecs",
+            "docs": [
+              "TWAP lookback window; at most (MAX_OBSERVATIONS - 1) crank intervals"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "emaPeriodSecs",
+            "type": "i64"
+          },
+          {
+            "name": "minUpdateIntervalSecs",
+            "type": "i64"
+          },
+          {
+            "name": "maxStalenessSecs",
+            "type": "i64"
+          },
+          {
+            "name": "maxConfidenceBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "initializeOracleParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feedSymbol",
+            "type": "string"
+          },
+          {
+            "name": "description",
+            "type": "string"
+          },
+          {
+            "name": "quoteDenomination",
+            "docs": [
+              "Unit of mint inputs, redeem outputs and caps; the feed must price the",
+              "token in its currency. Fixed for the oracle's lifetime."
+            ],
+            "type": {
+              "defined": {
+                "name": "quoteDenomination"
+              }
+            }
+          },
+          {
+            "name": "maxStalenessSecs",
+            "type": "i64"
+          },
+          {
+            "name": "mintFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "redeemFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "swapFeeBps",
+            "docs": [
+              "Fee on swaps into this mint"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxConfidenceBps",
+            "type": "u16"
+          },
+          {
+            "name": "quoteValiditySecs",
+            "type": "i64"
+          },
+          {
+            "name": "cpiMultiplier",
+            "type": "u64"
+          },
+          {
+            "name": "cpiMinUpdateInterval",
+            "type": "i64"
+          },
+          {
+            "name": "cpiMaxChangeBps",
+            "docs": [
+              "Max change of one CPI update, in bps of the current multiplier"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "cpiAttesters",
+            "docs": [
+              "M-of-N attesters required for CPI updates (empty = CPI updater alone)"
+            ],
+            "type": {
+              "defined": {
+                "name": "cpiAttesterSet"
+              }
+            }
+          },
+          {
+            "name": "cpiDataSource",
+            "type": "string"
+          },
+          {
+            "name": "maxPriceMoveBps",
+            "docs": [
+              "Max price move per window in bps (0 = circuit breaker off)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "priceMoveWindowSecs",
+            "type": "i64"
+          },
+          {
+            "name": "pricingMode",
+            "docs": [
+              "Spot, TWAP, EMA or Conservative (non-Spot modes need an accumulator)"
+            ],
+            "type": {
+              "defined": {
+                "name": "pricingMode"
+              }
+            }
+          },
+          {
+            "name": "configTimelockSecs",
+            "docs": [
+              "Delay for later risk-parameter updates"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "manualPriceBandBps",
+            "docs": [
+              "Band around the last good price for emergency manual prices (0 = disabled)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "minKycTier",
+            "docs": [
+              "Minimum sss-core allowlist KYC tier for oracle issuance (0 = none)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "globalMintCapUsd",
+            "docs": [
+              "Rolling 24h caps in `quote_denomination` units despite the `_usd`",
+              "suffix, all users then per requester (0 = none)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "globalRedeemCapUsd",
+            "type": "u64"
+          },
+          {
+            "name": "userMintCapUsd",
+            "type": "u64"
+          },
+          {
+            "name": "userRedeemCapUsd",
+            "type": "u64"
+          },
+          {
+            "name": "pegFeeCurve",
+            "docs": [
+              "Peg-defense fee curve over the flat fees (no points = off)"
+            ],
+            "type": {
+              "defined": {
+                "name": "pegFeeCurve"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "issuanceCapExceeded",
+      "docs": [
+        "Emitted when an oracle mint or redemption is rejected by a rolling issuance cap.",
+        "Amounts are in the oracle's `quote_denomination` units."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "docs": [
+              "The requester, for a per-user cap; `None` for a global cap"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "direction",
+            "docs": [
+              "\"Mint\" or \"Redeem\""
+            ],
+            "type": "string"
+          },
+          {
+            "name": "attemptedUsd",
+            "docs": [
+              "Value the rejected operation would have added"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "windowUsage",
+            "docs": [
+              "Value already used in the rolling window"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capUsd",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "manualPriceCleared",
+      "docs": [
+        "Emitted when the admin clears the manual price before it expires"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "by",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "manualPriceParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "docs": [
+              "Price in the feed's decimals"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "durationSecs",
+            "docs": [
+              "How long the price applies (at most MAX_MANUAL_PRICE_DURATION_SECS)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "reason",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "manualPriceSet",
+      "docs": [
+        "Emitted when the admin sets an emergency manual price"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "feedSymbol",
+            "type": "string"
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "referencePrice",
+            "docs": [
+              "Last good feed price the override was bounded against"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "deviationBps",
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "type": "i64"
+          },
+          {
+            "name": "reason",
+            "type": "string"
+          },
+          {
+            "name": "by",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "mintAtMarketParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "inputAmount",
+            "docs": [
+              "Quote units paid (`OracleConfig.quote_denomination`)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minOutput",
+            "docs": [
+              "Minimum net tokens to receive (slippage floor)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxPrice",
+            "docs": [
+              "Highest all-in price accepted: quote currency per whole token, fee included (PRICE_SCALE)"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "minterConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "minter",
+            "type": "pubkey"
+          },
+          {
+            "name": "isActive",
+            "type": "bool"
+          },
+          {
+            "name": "quotaPerPeriod",
+            "type": "u64"
+          },
+          {
+            "name": "periodSeconds",
+            "type": "i64"
+          },
+          {
+            "name": "mintedThisPeriod",
+            "type": "u64"
+          },
+          {
+            "name": "periodStart",
+            "type": "i64"
+          },
+          {
+            "name": "totalMinted",
+            "type": "u64"
+          },
+          {
+            "name": "mintCount",
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "observation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "timestamp",
+            "type": "i64"
+          },
+          {
+            "name": "cumulativePrice",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracleConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Schema version (`ORACLE_CONFIG_VERSION`)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The SSS token mint this oracle prices"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenDecimals",
+            "docs": [
+              "Decimals of `mint`, read at initialization"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "quoteDenomination",
+            "docs": [
+              "Unit of mint inputs, redeem outputs, caps and USD stats; fixed at initialization"
+            ],
+            "type": {
+              "defined": {
+                "name": "quoteDenomination"
+              }
+            }
+          },
+          {
+            "name": "admin",
+            "docs": [
+              "Admin: risk parameters, manual price, PSM, settlements, role transfers"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "docs": [
+              "Two-step admin transfer target"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "pauser",
+            "docs": [
+              "Pauser: pause and unpause"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingPauser",
+            "docs": [
+              "Two-step pauser transfer target"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "cpiUpdater",
+            "docs": [
+              "CPI updater: publishes CPI multipliers"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingCpiUpdater",
+            "docs": [
+              "Two-step CPI updater transfer target"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "feeManager",
+            "docs": [
+              "Fee manager: fee vault, fee splits and withdrawals"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingFeeManager",
+            "docs": [
+              "Two-step fee manager transfer target"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "feedSymbol",
+            "docs": [
+              "Symbol of the registered feed to use (must exist in FeedRegistry)"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "description",
+            "docs": [
+              "Human-readable description"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "maxStalenessSecs",
+            "docs": [
+              "Maximum seconds since last feed update before price is rejected"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "mintFeeBps",
+            "docs": [
+              "Mint fee in basis points (100 bps = 1%)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "redeemFeeBps",
+            "docs": [
+              "Redeem fee in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "swapFeeBps",
+            "docs": [
+              "Fee on swaps into this mint, in basis points of the output"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxConfidenceBps",
+            "docs": [
+              "Max allowed confidence interval as % of price (in bps)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "quoteValiditySecs",
+            "docs": [
+              "Quote validity window in seconds"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "cpiMultiplier",
+            "docs": [
+              "CPI multiplier at `cpi_accrual_start` (fixed-point * 1_000_000). 1.083 = 1_083_000"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "cpiTargetMultiplier",
+            "docs": [
+              "Multiplier the schedule reaches at `cpi_accrual_end`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "cpiAccrualStart",
+            "docs": [
+              "Start of the current accrual window"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "cpiAccrualEnd",
+            "docs": [
+              "End of the current accrual window"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "cpiMaxChangeBps",
+            "docs": [
+              "Max change of one CPI update, in bps of the current multiplier"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "cpiLastUpdated",
+            "docs": [
+              "Timestamp of last CPI update"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "cpiMinUpdateInterval",
+            "docs": [
+              "Minimum seconds between CPI updates"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "cpiDataSource",
+            "docs": [
+              "Source description for CPI data audit trail"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "cpiAttesters",
+            "docs": [
+              "Attesters whose signatures CPI updates need (empty = CPI updater alone)"
+            ],
+            "type": {
+              "defined": {
+                "name": "cpiAttesterSet"
+              }
+            }
+          },
+          {
+            "name": "cpiReferenceMonth",
+            "docs": [
+              "Reference month (\"YYYY-MM\") of the last CPI update"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Whether minting/redeeming is suspended"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "pauseReason",
+            "docs": [
+              "Reason for current pause"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "pauseCode",
+            "docs": [
+              "Machine-readable cause of the current pause"
+            ],
+            "type": {
+              "defined": {
+                "name": "pauseReason"
+              }
+            }
+          },
+          {
+            "name": "lastGoodPrice",
+            "docs": [
+              "Last feed price accepted by a quote (feed decimals, 0 = none yet)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastGoodPriceAt",
+            "docs": [
+              "Timestamp of `last_good_price`"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxPriceMoveBps",
+            "docs": [
+              "Max price move per window, in bps of `last_good_price` (0 = breaker off)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "priceMoveWindowSecs",
+            "docs": [
+              "Window the move limit applies to, in seconds"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "trippedPrice",
+            "docs": [
+              "Feed price that tripped the circuit breaker (0 = not tripped)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "manualPriceBandBps",
+            "docs": [
+              "Max distance of a manual price from `last_good_price`, in bps (0 = overrides disabled)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "manualPrice",
+            "docs": [
+              "Emergency manual price (feed decimals, 0 = no override)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "manualPriceExpiresAt",
+            "docs": [
+              "Time the manual price stops applying"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "manualPriceReason",
+            "docs": [
+              "Why the manual price was set"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "pricingMode",
+            "docs": [
+              "Which price quotes use: spot, accumulator average, or the safer of both"
+            ],
+            "type": {
+              "defined": {
+                "name": "pricingMode"
+              }
+            }
+          },
+          {
+            "name": "configTimelockSecs",
+            "docs": [
+              "Delay between queueing and applying a risk-parameter update"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "pendingUpdate",
+            "docs": [
+              "Queued risk-parameter update, if any"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "oracleParamsUpdate"
+                }
+              }
+            }
+          },
+          {
+            "name": "pendingUpdateEta",
+            "docs": [
+              "Earliest time `pending_update` can be applied"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "feeSplits",
+            "docs": [
+              "Revenue-share recipients paid out of `withdraw_fees`"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeSplit"
+                }
+              }
+            }
+          },
+          {
+            "name": "minKycTier",
+            "docs": [
+              "Minimum sss-core allowlist `kyc_tier` for oracle issuance (0 = none)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "globalMintCapUsd",
+            "docs": [
+              "Rolling 24h cap on value minted through the oracle, all users, in",
+              "`quote_denomination` units despite the `_usd` suffix (0 = none)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "globalRedeemCapUsd",
+            "docs": [
+              "Rolling 24h cap on value redeemed through the oracle, all users, in",
+              "`quote_denomination` units despite the `_usd` suffix (0 = none)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "userMintCapUsd",
+            "docs": [
+              "Rolling 24h cap on value minted per requester, in `quote_denomination`",
+              "units despite the `_usd` suffix (0 = none)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "userRedeemCapUsd",
+            "docs": [
+              "Rolling 24h cap on value redeemed per requester, in `quote_denomination`",
+              "units despite the `_usd` suffix (0 = none)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "globalMintUsage",
+            "docs": [
+              "Value minted through the oracle in the rolling window, all users (`quote_denomination` units)"
+            ],
+            "type": {
+              "defined": {
+                "name": "usageWindow"
+              }
+            }
+          },
+          {
+            "name": "globalRedeemUsage",
+            "docs": [
+              "Value redeemed through the oracle in the rolling window, all users (`quote_denomination` units)"
+            ],
+            "type": {
+              "defined": {
+                "name": "usageWindow"
+              }
+            }
+          },
+          {
+            "name": "pegFeeCurve",
+            "docs": [
+              "Peg-defense fee curve replacing the flat mint/redeem fees (no points = off)"
+            ],
+            "type": {
+              "defined": {
+                "name": "pegFeeCurve"
+              }
+            }
+          },
+          {
+            "name": "totalMintedUsd",
+            "docs": [
+              "Lifetime stats: total value minted through oracle, in `quote_denomination`",
+              "units despite the `_usd` suffix"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalRedeemedUsd",
+            "docs": [
+              "Lifetime stats: total value redeemed through oracle, in `quote_denomination`",
+              "units despite the `_usd` suffix"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesCollected",
+            "docs": [
+              "Lifetime stats: total fees collected in token base units"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lastUpdatedAt",
+            "docs": [
+              "Last modification timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracleInitialized",
+      "docs": [
+        "Emitted when a new oracle config is initialized for a mint"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "docs": [
+              "Initial holder of every role"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "feedSymbol",
+            "type": "string"
+          },
+          {
+            "name": "mintFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "redeemFeeBps",
+            "type": "u16"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracleMint",
+      "docs": [
+        "Emitted on a successful oracle-priced mint execution"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "recipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "quoteAmount",
+            "docs": [
+              "Amount paid, in `denomination` units"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "denomination",
+            "type": {
+              "defined": {
+                "name": "quoteDenomination"
+              }
+            }
+          },
+          {
+            "name": "tokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "priceUsed",
+            "type": "u64"
+          },
+          {
+            "name": "feedSymbol",
+            "type": "string"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracleParamsUpdate",
+      "docs": [
+        "Risk-parameter changes queued behind the config timelock. `None` fields",
+        "are left unchanged."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mintFeeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "redeemFeeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "swapFeeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "maxStalenessSecs",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "maxConfidenceBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "quoteValiditySecs",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "feedSymbol",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "description",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "maxPriceMoveBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "priceMoveWindowSecs",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "pricingMode",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "pricingMode"
+                }
+              }
+            }
+          },
+          {
+            "name": "configTimelockSecs",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "manualPriceBandBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "cpiMaxChangeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "cpiAttesters",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "cpiAttesterSet"
+                }
+              }
+            }
+          },
+          {
+            "name": "minKycTier",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "globalMintCapUsd",
+            "docs": [
+              "Caps in the oracle's `quote_denomination` units, as on `OracleConfig`"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "globalRedeemCapUsd",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "userMintCapUsd",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "userRedeemCapUsd",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "pegFeeCurve",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "pegFeeCurve"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "oraclePaused",
+      "docs": [
+        "Emitted when oracle is paused/unpaused"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "code",
+            "type": {
+              "defined": {
+                "name": "pauseReason"
+              }
+            }
+          },
+          {
+            "name": "reason",
+            "type": "string"
+          },
+          {
+            "name": "by",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "feedAlreadyRegistered",
-      "msg": "Feed with this symbol already exists in the registry"
+      "name": "oracleRedeem",
+      "docs": [
+        "Emitted on a successful oracle-priced redemption"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "redeemer",
+            "type": "pubkey"
+          },
+          {
+            "name": "settlement",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "quoteAmount",
+            "docs": [
+              "Amount owed after the fee, in `denomination` units"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "denomination",
+            "type": {
+              "defined": {
+                "name": "quoteDenomination"
+              }
+            }
+          },
+          {
+            "name": "feeAmount",
+            "docs": [
+              "Fee withheld, in `denomination` units"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "priceUsed",
+            "type": "u64"
+          },
+          {
+            "name": "feedSymbol",
+            "type": "string"
+          },
+          {
+            "name": "feeTokens",
+            "docs": [
+              "Token share of the fee moved to the fee vault"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "feedNotFound",
-      "msg": "Feed symbol not found in registry"
+      "name": "oracleRole",
+      "docs": [
+        "Operator roles of an oracle, each held by one key and handed over in two",
+        "steps (`propose_authority_transfer` / `accept_authority_transfer`)."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "admin"
+          },
+          {
+            "name": "pauser"
+          },
+          {
+            "name": "cpiUpdater"
+          },
+          {
+            "name": "feeManager"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "symbolTooLong",
-      "msg": "Feed symbol exceeds maximum length of 12 characters"
+      "name": "pauseReason",
+      "docs": [
+        "Why the oracle is paused"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "none"
+          },
+          {
+            "name": "manual"
+          },
+          {
+            "name": "circuitBreaker"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "registryFull",
-      "msg": "Registry is at maximum capacity (64 feeds)"
+      "name": "pegFeeCurve",
+      "docs": [
+        "Mint and redeem fees as piecewise-linear functions of how far the token's",
+        "secondary market trades from peg, e.g. cheaper redemptions and dearer",
+        "mints below peg. Between points the fees are interpolated (rounded up);",
+        "beyond the ends they stay at the end values."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "marketFeedSymbol",
+            "docs": [
+              "Registered feed quoting the token's market price in quote currency per whole token"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "points",
+            "docs": [
+              "0 (curve off) or 2..=MAX_FEE_CURVE_POINTS points, strictly increasing deviation"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feeCurvePoint"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingQuote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The SSS token mint"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "requester",
+            "docs": [
+              "Who requested the quote"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "direction",
+            "docs": [
+              "Mint or Redeem"
+            ],
+            "type": {
+              "defined": {
+                "name": "quoteDirection"
+              }
+            }
+          },
+          {
+            "name": "feedSymbol",
+            "docs": [
+              "Feed symbol used"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "inputAmount",
+            "docs": [
+              "Input amount (quote units for mint, token units for redeem)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "outputAmount",
+            "docs": [
+              "Output amount (token units for mint, quote units for redeem)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "docs": [
+              "Fee amount in output units"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "priceSnapshot",
+            "docs": [
+              "Exact price snapshot (fixed-point, feed decimals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "priceDecimals",
+            "docs": [
+              "Decimals of `price_snapshot`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pricingMode",
+            "docs": [
+              "How `price_snapshot` was derived"
+            ],
+            "type": {
+              "defined": {
+                "name": "pricingMode"
+              }
+            }
+          },
+          {
+            "name": "sourcesUsed",
+            "docs": [
+              "Source accounts whose readings made up the median price"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "manualOverride",
+            "docs": [
+              "Priced off the emergency manual price instead of the sources"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "validUntil",
+            "docs": [
+              "Unix timestamp after which this quote is invalid"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "minOutput",
+            "docs": [
+              "Minimum acceptable output (slippage protection)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "used",
+            "docs": [
+              "Whether consumed"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Caller nonce to prevent PDA collision"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "oraclePaused",
-      "msg": "Oracle is paused — mint and redeem operations are suspended"
+      "name": "pendingSwapQuote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mintIn",
+            "docs": [
+              "SSS mint being swapped out of (burned)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "mintOut",
+            "docs": [
+              "SSS mint being swapped into (minted)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "requester",
+            "docs": [
+              "Who requested the quote"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "feedSymbolIn",
+            "docs": [
+              "Feed symbol pricing `mint_in`"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "feedSymbolOut",
+            "docs": [
+              "Feed symbol pricing `mint_out`"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "inputAmount",
+            "docs": [
+              "Tokens of `mint_in` to burn (base units)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "outputAmount",
+            "docs": [
+              "Tokens of `mint_out` minted to the requester after fees (base units)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "docs": [
+              "Swap fee minted to the `mint_out` fee vault (base units)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "usdValue",
+            "docs": [
+              "Value of the input in the `quote_denomination` both oracles share",
+              "(not necessarily USD)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "priceIn",
+            "docs": [
+              "Price snapshot of the input feed (fixed-point, feed decimals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "priceDecimalsIn",
+            "docs": [
+              "Decimals of `price_in`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "priceOut",
+            "docs": [
+              "Price snapshot of the output feed (fixed-point, feed decimals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "priceDecimalsOut",
+            "docs": [
+              "Decimals of `price_out`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "manualOverride",
+            "docs": [
+              "Either leg priced off an emergency manual price"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "validUntil",
+            "docs": [
+              "Unix timestamp after which this quote is invalid"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "minOutput",
+            "docs": [
+              "Minimum acceptable output (slippage protection)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Caller nonce to prevent PDA collision"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "unauthorized",
-      "msg": "Signer is not the oracle authority"
+      "name": "priceAccumulator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feedSymbol",
+            "docs": [
+              "Registry feed this accumulator tracks"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "lastPrice",
+            "docs": [
+              "Latest cranked median price (fixed-point, feed decimals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastUpdate",
+            "docs": [
+              "Timestamp of `last_price`"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "cumulativePrice",
+            "docs": [
+              "Σ price * seconds since the first crank, up to `last_update`"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "emaPrice",
+            "docs": [
+              "Exponential moving average (fixed-point, feed decimals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "twapWindowSecs",
+            "docs": [
+              "TWAP lookback window in seconds"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "emaPeriodSecs",
+            "docs": [
+              "EMA time constant in seconds"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "minUpdateIntervalSecs",
+            "docs": [
+              "Minimum seconds between cranks"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxStalenessSecs",
+            "docs": [
+              "Per-source staleness limit applied by the crank"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxConfidenceBps",
+            "docs": [
+              "Per-source confidence limit applied by the crank"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "observations",
+            "docs": [
+              "Ring buffer of (timestamp, cumulative) observations"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "observation"
+                  }
+                },
+                24
+              ]
+            }
+          },
+          {
+            "name": "observationIndex",
+            "docs": [
+              "Slot the next observation is written to"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "observationCount",
+            "docs": [
+              "Number of observations written (≤ MAX_OBSERVATIONS)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "noPendingTransfer",
-      "msg": "No pending authority transfer exists"
+      "name": "priceDiagnostics",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rawPrice",
+            "docs": [
+              "Median of every readable source, ignoring the staleness and",
+              "confidence limits (feed decimals); 0 if no source is readable"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "priceDecimals",
+            "docs": [
+              "Decimals of `raw_price` and `price_used`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "confidenceBps",
+            "docs": [
+              "Confidence of `raw_price` as bps of it"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "ageSecs",
+            "docs": [
+              "Seconds since the oldest reading in `raw_price`, -1 if none"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "feedType",
+            "type": {
+              "defined": {
+                "name": "feedType"
+              }
+            }
+          },
+          {
+            "name": "pricingMode",
+            "type": {
+              "defined": {
+                "name": "pricingMode"
+              }
+            }
+          },
+          {
+            "name": "manualOverride",
+            "docs": [
+              "An emergency manual price is in force; the sources are not used"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "priceUsed",
+            "docs": [
+              "Price a quote would use now; 0 if it would fail"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "docs": [
+              "Fee for the direction asked: off the peg fee curve when set, else the flat fee"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "grossOutput",
+            "docs": [
+              "Output before the fee (token units for mint, quote units for redeem)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "netOutput",
+            "type": "u64"
+          },
+          {
+            "name": "sources",
+            "docs": [
+              "Per-source readings behind `raw_price`"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "sourceReport"
+                }
+              }
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "The oracle is paused"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "stale",
+            "docs": [
+              "A quote would fail on staleness"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "confidenceTooWide",
+            "docs": [
+              "A quote would fail on the confidence limit"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "breakerWouldTrip",
+            "docs": [
+              "A quote would trip the circuit breaker on the current reading"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "errorCode",
+            "docs": [
+              "Error code a quote would fail with now: 0 if it would succeed,",
+              "u32::MAX for a runtime error without an Anchor code"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "invalidCpiMultiplier",
-      "msg": "CPI multiplier cannot be zero"
+      "name": "priceSource",
+      "docs": [
+        "One price source account and the provider that owns its layout"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "kind",
+            "docs": [
+              "Which parser reads this account"
+            ],
+            "type": {
+              "defined": {
+                "name": "sourceKind"
+              }
+            }
+          },
+          {
+            "name": "account",
+            "docs": [
+              "Source account address"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "cpiUpdateTooSoon",
-      "msg": "CPI multiplier update too soon — minimum interval not reached"
+      "name": "pricingMode",
+      "docs": [
+        "Price a quote is computed from"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "spot"
+          },
+          {
+            "name": "twap"
+          },
+          {
+            "name": "ema"
+          },
+          {
+            "name": "conservative"
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "mathOverflow",
-      "msg": "Arithmetic overflow in calculation"
+      "name": "psmCollateral",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The SSS token mint"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "collateralMint",
+            "docs": [
+              "Accepted collateral mint (a USD stablecoin, e.g. USDC)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "collateralDecimals",
+            "docs": [
+              "Decimals of `collateral_mint`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "vault",
+            "docs": [
+              "Vault token account holding the deposited collateral"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "debtCeilingUsd",
+            "docs": [
+              "Max USD value of SSS tokens outstanding against this collateral, in the",
+              "oracle's quote decimals (PSM oracles always quote in USD)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "debtUsd",
+            "docs": [
+              "USD value of SSS tokens outstanding against this collateral, in the",
+              "oracle's quote decimals.",
+              "Equals the par value of the collateral held in the vault."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalDeposited",
+            "docs": [
+              "Lifetime collateral deposited (collateral base units)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalReleased",
+            "docs": [
+              "Lifetime collateral released (collateral base units)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "active",
+            "docs": [
+              "Whether new PSM mints are accepted (redemptions always are)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "docs": [
+              "Last modification timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "divisionByZero",
-      "msg": "Division by zero in price calculation"
+      "name": "psmCollateralRegistered",
+      "docs": [
+        "Emitted when a collateral is added to a mint's peg stability module"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "collateralMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "vault",
+            "type": "pubkey"
+          },
+          {
+            "name": "debtCeilingUsd",
+            "docs": [
+              "USD, in the oracle's quote decimals"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "by",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "feedMismatch",
-      "msg": "Switchboard feed account mismatch — wrong feed passed"
-    }
-  ],
-  "types": [
-    {
-      "name": "authorityTransferProposed",
+      "name": "psmCollateralUpdated",
       "docs": [
-        "Emitted when oracle authority transfer is proposed"
+        "Emitted when a PSM collateral's debt ceiling or active flag changes"
       ],
       "type": {
         "kind": "struct",
@@ -1165,11 +9721,22 @@ export type SssOracle = {
             "type": "pubkey"
           },
           {
-            "name": "current",
+            "name": "collateralMint",
             "type": "pubkey"
           },
           {
-            "name": "proposed",
+            "name": "debtCeilingUsd",
+            "docs": [
+              "USD, in the oracle's quote decimals"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "active",
+            "type": "bool"
+          },
+          {
+            "name": "by",
             "type": "pubkey"
           },
           {
@@ -1180,9 +9747,9 @@ export type SssOracle = {
       }
     },
     {
-      "name": "authorityTransferred",
+      "name": "psmMint",
       "docs": [
-        "Emitted when oracle authority transfer is accepted"
+        "Emitted when collateral is deposited and SSS tokens minted against it"
       ],
       "type": {
         "kind": "struct",
@@ -1192,13 +9759,46 @@ export type SssOracle = {
             "type": "pubkey"
           },
           {
-            "name": "from",
+            "name": "collateralMint",
             "type": "pubkey"
           },
           {
-            "name": "to",
+            "name": "recipient",
             "type": "pubkey"
           },
+          {
+            "name": "collateralAmount",
+            "docs": [
+              "Collateral deposited (collateral base units)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "usdAmount",
+            "docs": [
+              "Par value of the collateral (USD, in the oracle's quote decimals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "tokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "priceUsed",
+            "type": "u64"
+          },
+          {
+            "name": "debtUsd",
+            "docs": [
+              "Debt outstanding against the collateral afterwards (USD, quote decimals)"
+            ],
+            "type": "u64"
+          },
           {
             "name": "timestamp",
             "type": "i64"
@@ -1207,9 +9807,9 @@ export type SssOracle = {
       }
     },
     {
-      "name": "cpiMultiplierUpdated",
+      "name": "psmRedeem",
       "docs": [
-        "Emitted when CPI multiplier is updated"
+        "Emitted when SSS tokens are burned and collateral released for them"
       ],
       "type": {
         "kind": "struct",
@@ -1219,23 +9819,92 @@ export type SssOracle = {
             "type": "pubkey"
           },
           {
-            "name": "oldMultiplier",
+            "name": "collateralMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "redeemer",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenAmount",
             "type": "u64"
           },
           {
-            "name": "newMultiplier",
+            "name": "feeTokens",
+            "docs": [
+              "Token share of the fee moved to the fee vault"
+            ],
             "type": "u64"
           },
           {
-            "name": "referenceMonth",
-            "type": "string"
+            "name": "usdAmount",
+            "docs": [
+              "Value redeemed after fees (USD, in the oracle's quote decimals)"
+            ],
+            "type": "u64"
           },
           {
-            "name": "dataSource",
-            "type": "string"
+            "name": "collateralAmount",
+            "docs": [
+              "Collateral released (collateral base units)"
+            ],
+            "type": "u64"
           },
           {
-            "name": "updatedBy",
+            "name": "priceUsed",
+            "type": "u64"
+          },
+          {
+            "name": "debtUsd",
+            "docs": [
+              "Debt outstanding against the collateral afterwards (USD, quote decimals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "quoteClosed",
+      "docs": [
+        "Emitted when an expired, unexecuted quote is closed and its rent refunded"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "quoteId",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The quote's mint; the input mint for a swap quote"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "requester",
+            "type": "pubkey"
+          },
+          {
+            "name": "validUntil",
+            "type": "i64"
+          },
+          {
+            "name": "rentRefunded",
+            "docs": [
+              "Lamports returned to the requester"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "closedBy",
             "type": "pubkey"
           },
           {
@@ -1246,113 +9915,232 @@ export type SssOracle = {
       }
     },
     {
-      "name": "feedEntry",
+      "name": "quoteDenomination",
       "docs": [
-        "A single registered price feed entry"
+        "Unit that an oracle's quote amounts are counted in: mint inputs, redeem",
+        "outputs, settlements, issuance caps and lifetime stats. The oracle's feed",
+        "must price the token in `currency`."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "symbol",
+            "name": "currency",
             "docs": [
-              "Feed symbol, max 12 chars: \"BRLUSD\", \"EURUSD\""
+              "ISO 4217 code, e.g. `*b\"USD\"` or `*b\"EUR\"`"
             ],
-            "type": "string"
+            "type": {
+              "array": [
+                "u8",
+                3
+              ]
+            }
           },
           {
-            "name": "switchboardFeed",
+            "name": "decimals",
             "docs": [
-              "Switchboard aggregator account address"
+              "Decimals of one quote unit: 2 = cents, 6 = micro-units"
             ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "quoteDirection",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "mint"
+          },
+          {
+            "name": "redeem"
+          }
+        ]
+      }
+    },
+    {
+      "name": "quoteGenerated",
+      "docs": [
+        "Emitted every time a quote is requested"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "quoteId",
             "type": "pubkey"
           },
           {
-            "name": "feedType",
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "feedSymbol",
+            "type": "string"
+          },
+          {
+            "name": "direction",
+            "type": "string"
+          },
+          {
+            "name": "inputAmount",
+            "type": "u64"
+          },
+          {
+            "name": "outputAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
             "docs": [
-              "How to interpret the price value"
+              "Fee charged, in bps: the flat fee or the peg fee curve's"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "deviationBps",
+            "docs": [
+              "Market deviation from peg the curve was read at (`None` = flat fee)"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "priceUsed",
+            "type": "u64"
+          },
+          {
+            "name": "priceDecimals",
+            "docs": [
+              "Decimals of `price_used` and `spot_price`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pricingMode",
+            "docs": [
+              "How `price_used` was derived"
             ],
             "type": {
               "defined": {
-                "name": "feedType"
+                "name": "pricingMode"
               }
             }
           },
           {
-            "name": "baseCurrency",
+            "name": "spotPrice",
             "docs": [
-              "Base currency code, max 8 chars: \"BRL\", \"EUR\""
+              "Median spot price at quote time"
             ],
-            "type": "string"
+            "type": "u64"
           },
           {
-            "name": "quoteCurrency",
+            "name": "sources",
             "docs": [
-              "Quote currency code, max 8 chars: \"USD\""
+              "Per-source price, confidence, staleness and whether it was used"
             ],
-            "type": "string"
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "sourceReport"
+                }
+              }
+            }
+          },
+          {
+            "name": "manualOverride",
+            "docs": [
+              "Priced off the emergency manual price (`sources` is then empty)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "validUntil",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "quoteResult",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "outputAmount",
+            "type": "u64"
           },
           {
-            "name": "decimals",
-            "docs": [
-              "Price decimals from the feed"
-            ],
-            "type": "u8"
+            "name": "feeAmount",
+            "type": "u64"
           },
           {
-            "name": "active",
-            "docs": [
-              "Whether this feed is currently active"
-            ],
-            "type": "bool"
+            "name": "priceUsed",
+            "type": "u64"
           },
           {
-            "name": "registeredAt",
-            "docs": [
-              "When was this feed registered"
-            ],
+            "name": "validUntil",
             "type": "i64"
           },
           {
-            "name": "registeredBy",
+            "name": "quoteAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "tripped",
             "docs": [
-              "Who registered it"
+              "True when the reading tripped the circuit breaker: the oracle is now",
+              "paused, no quote was stored and the amounts are zero"
             ],
-            "type": "pubkey"
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "feedRegistered",
+      "name": "redemptionSettled",
       "docs": [
-        "Emitted when a new price feed is registered globally"
+        "Emitted when the fiat payout for a redemption is confirmed"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "symbol",
-            "type": "string"
+            "name": "mint",
+            "type": "pubkey"
           },
           {
-            "name": "switchboardFeed",
+            "name": "settlement",
             "type": "pubkey"
           },
           {
-            "name": "feedType",
-            "type": "string"
+            "name": "redeemer",
+            "type": "pubkey"
           },
           {
-            "name": "baseCurrency",
-            "type": "string"
+            "name": "usdOwed",
+            "docs": [
+              "Amount paid out, in the oracle's `quote_denomination` units"
+            ],
+            "type": "u64"
           },
           {
-            "name": "quoteCurrency",
+            "name": "payoutReference",
             "type": "string"
           },
           {
-            "name": "registeredBy",
+            "name": "settledBy",
             "type": "pubkey"
           },
           {
@@ -1363,612 +10151,612 @@ export type SssOracle = {
       }
     },
     {
-      "name": "feedRegistry",
+      "name": "redemptionSettlement",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
+            "name": "mint",
             "docs": [
-              "Authority who can register new feeds"
+              "The SSS token mint"
             ],
             "type": "pubkey"
           },
           {
-            "name": "feedCount",
+            "name": "redeemer",
             "docs": [
-              "Current number of registered feeds"
+              "Who redeemed the tokens"
             ],
-            "type": "u8"
+            "type": "pubkey"
           },
           {
-            "name": "feeds",
+            "name": "tokenAmount",
             "docs": [
-              "All registered feeds"
+              "Tokens taken from the redeemer (token base units)"
             ],
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "feedEntry"
-                }
-              }
-            }
+            "type": "u64"
           },
           {
-            "name": "bump",
+            "name": "usdOwed",
             "docs": [
-              "PDA bump"
+              "Amount owed to the redeemer after fees, in the oracle's `quote_denomination`",
+              "units despite the `usd_` prefix"
             ],
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "feedType",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "direct"
-          },
-          {
-            "name": "inverse"
-          },
-          {
-            "name": "cpiIndexed"
+            "type": "u64"
           },
           {
-            "name": "custom",
-            "fields": [
-              {
-                "name": "numerator",
-                "type": "u64"
-              },
-              {
-                "name": "denominator",
-                "type": "u64"
-              },
-              {
-                "name": "baseType",
-                "docs": [
-                  "0 = Direct after scaling, 1 = Inverse after scaling"
-                ],
-                "type": "u8"
-              }
-            ]
-          }
-        ]
-      }
-    },
-    {
-      "name": "getQuoteParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "inputAmount",
+            "name": "feeAmount",
             "docs": [
-              "Input amount: USD cents for mint, token base units for redeem"
+              "Redeem fee withheld (`quote_denomination` units)"
             ],
             "type": "u64"
           },
           {
-            "name": "minOutput",
+            "name": "priceUsed",
             "docs": [
-              "Minimum acceptable output (slippage floor)"
+              "Price snapshot from the consumed quote (fixed-point, feed decimals)"
             ],
             "type": "u64"
           },
           {
-            "name": "nonce",
+            "name": "feedSymbol",
             "docs": [
-              "Caller-provided nonce to avoid PDA collision between quotes"
+              "Feed symbol used"
             ],
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "initializeOracleParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "feedSymbol",
-            "type": "string"
-          },
-          {
-            "name": "description",
             "type": "string"
           },
           {
-            "name": "maxStalenessSecs",
-            "type": "i64"
-          },
-          {
-            "name": "mintFeeBps",
-            "type": "u16"
+            "name": "settled",
+            "docs": [
+              "Whether the fiat payout has been confirmed by the oracle admin"
+            ],
+            "type": "bool"
           },
           {
-            "name": "redeemFeeBps",
-            "type": "u16"
+            "name": "payoutReference",
+            "docs": [
+              "Off-chain payout reference recorded on confirmation"
+            ],
+            "type": "string"
           },
           {
-            "name": "maxConfidenceBps",
-            "type": "u16"
+            "name": "createdAt",
+            "docs": [
+              "Redemption timestamp"
+            ],
+            "type": "i64"
           },
           {
-            "name": "quoteValiditySecs",
+            "name": "settledAt",
+            "docs": [
+              "Payout confirmation timestamp (0 until settled)"
+            ],
             "type": "i64"
           },
           {
-            "name": "cpiMultiplier",
+            "name": "nonce",
+            "docs": [
+              "Nonce of the consumed quote"
+            ],
             "type": "u64"
           },
           {
-            "name": "cpiMinUpdateInterval",
-            "type": "i64"
-          },
-          {
-            "name": "cpiDataSource",
-            "type": "string"
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "oracleConfig",
+      "name": "registerFeedParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "version",
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "minSources",
             "docs": [
-              "Schema version for future migrations"
+              "Minimum number of fresh sources required to quote"
             ],
             "type": "u8"
           },
           {
-            "name": "mint",
+            "name": "maxSpreadBps",
             "docs": [
-              "The SSS token mint this oracle prices"
+              "Max spread between sources as bps of the median (0 = no check)"
             ],
-            "type": "pubkey"
+            "type": "u16"
           },
           {
-            "name": "authority",
+            "name": "sourceKinds",
             "docs": [
-              "Authority that can update config, update CPI, pause/unpause"
+              "Provider of each source account, in `remaining_accounts` order"
             ],
-            "type": "pubkey"
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "sourceKind"
+                }
+              }
+            }
           },
           {
-            "name": "pendingAuthority",
-            "docs": [
-              "Two-step authority transfer target"
-            ],
+            "name": "feedType",
             "type": {
-              "option": "pubkey"
+              "defined": {
+                "name": "feedType"
+              }
             }
           },
           {
-            "name": "feedSymbol",
-            "docs": [
-              "Symbol of the registered feed to use (must exist in FeedRegistry)"
-            ],
+            "name": "baseCurrency",
             "type": "string"
           },
           {
-            "name": "description",
-            "docs": [
-              "Human-readable description"
-            ],
+            "name": "quoteCurrency",
             "type": "string"
           },
           {
-            "name": "maxStalenessSecs",
-            "docs": [
-              "Maximum seconds since last feed update before price is rejected"
-            ],
-            "type": "i64"
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "registryAuthorityProposed",
+      "docs": [
+        "Emitted when a registry authority transfer is proposed"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "current",
+            "type": "pubkey"
           },
           {
-            "name": "mintFeeBps",
-            "docs": [
-              "Mint fee in basis points (100 bps = 1%)"
-            ],
-            "type": "u16"
+            "name": "proposed",
+            "type": "pubkey"
           },
           {
-            "name": "redeemFeeBps",
-            "docs": [
-              "Redeem fee in basis points"
-            ],
-            "type": "u16"
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "registryAuthorityTransferred",
+      "docs": [
+        "Emitted when a registry authority transfer is accepted"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "from",
+            "type": "pubkey"
           },
           {
-            "name": "maxConfidenceBps",
-            "docs": [
-              "Max allowed confidence interval as % of price (in bps)"
-            ],
-            "type": "u16"
+            "name": "to",
+            "type": "pubkey"
           },
           {
-            "name": "quoteValiditySecs",
-            "docs": [
-              "Quote validity window in seconds"
-            ],
+            "name": "timestamp",
             "type": "i64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "rotateFeedSourceParams",
+      "docs": [
+        "Replacement source set for `rotate_feed_source`. The new source accounts",
+        "are passed as `remaining_accounts`, as in `register_feed`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "cpiMultiplier",
+            "name": "minSources",
             "docs": [
-              "CPI multiplier (fixed-point * 1_000_000). 1.083 = 1_083_000"
+              "Minimum number of fresh sources required to quote"
             ],
-            "type": "u64"
+            "type": "u8"
           },
           {
-            "name": "cpiLastUpdated",
+            "name": "maxSpreadBps",
             "docs": [
-              "Timestamp of last CPI update"
+              "Max spread between sources as bps of the median (0 = no check)"
             ],
-            "type": "i64"
+            "type": "u16"
           },
           {
-            "name": "cpiMinUpdateInterval",
+            "name": "sourceKinds",
             "docs": [
-              "Minimum seconds between CPI updates"
+              "Provider of each source account, in `remaining_accounts` order"
             ],
-            "type": "i64"
-          },
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "sourceKind"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "sourceKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "cpiDataSource",
-            "docs": [
-              "Source description for CPI data audit trail"
-            ],
-            "type": "string"
+            "name": "switchboard"
           },
           {
-            "name": "paused",
-            "docs": [
-              "Whether minting/redeeming is suspended"
-            ],
-            "type": "bool"
-          },
+            "name": "pyth"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sourceReport",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "pauseReason",
+            "name": "source",
             "docs": [
-              "Reason for current pause"
+              "Source account"
             ],
-            "type": "string"
+            "type": "pubkey"
           },
           {
-            "name": "totalMintedUsd",
+            "name": "kind",
             "docs": [
-              "Lifetime stats: total USD value minted through oracle"
+              "Provider of the source account"
             ],
-            "type": "u64"
+            "type": {
+              "defined": {
+                "name": "sourceKind"
+              }
+            }
           },
           {
-            "name": "totalRedeemedUsd",
+            "name": "price",
             "docs": [
-              "Lifetime stats: total USD value redeemed through oracle"
+              "Price read (fixed-point, feed decimals), 0 if unreadable"
             ],
             "type": "u64"
           },
           {
-            "name": "totalFeesCollected",
+            "name": "confidenceBps",
             "docs": [
-              "Lifetime stats: total fees collected in token base units"
+              "Confidence as bps of price, 0 if unreadable"
             ],
             "type": "u64"
           },
           {
-            "name": "createdAt",
-            "docs": [
-              "Creation timestamp"
-            ],
-            "type": "i64"
-          },
-          {
-            "name": "lastUpdatedAt",
+            "name": "ageSecs",
             "docs": [
-              "Last modification timestamp"
+              "Seconds since the source's last update, -1 if unreadable"
             ],
             "type": "i64"
           },
           {
-            "name": "bump",
+            "name": "used",
             "docs": [
-              "PDA bump"
+              "Whether the reading passed staleness/confidence and entered the median"
             ],
-            "type": "u8"
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "oracleInitialized",
-      "docs": [
-        "Emitted when a new oracle config is initialized for a mint"
-      ],
+      "name": "stablecoinConfig",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "mint",
-            "type": "pubkey"
+            "name": "version",
+            "type": "u8"
           },
           {
-            "name": "authority",
+            "name": "preset",
+            "type": {
+              "defined": {
+                "name": "stablecoinPreset"
+              }
+            }
+          },
+          {
+            "name": "mint",
             "type": "pubkey"
           },
           {
-            "name": "feedSymbol",
+            "name": "name",
             "type": "string"
           },
           {
-            "name": "mintFeeBps",
-            "type": "u16"
+            "name": "symbol",
+            "type": "string"
           },
           {
-            "name": "redeemFeeBps",
-            "type": "u16"
+            "name": "uri",
+            "type": "string"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "oracleMint",
-      "docs": [
-        "Emitted on a successful oracle-priced mint execution"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "type": "pubkey"
+            "name": "decimals",
+            "type": "u8"
           },
           {
-            "name": "recipient",
-            "type": "pubkey"
+            "name": "enablePermanentDelegate",
+            "type": "bool"
           },
           {
-            "name": "usdAmount",
-            "type": "u64"
+            "name": "enableTransferHook",
+            "type": "bool"
           },
           {
-            "name": "tokenAmount",
-            "type": "u64"
+            "name": "defaultAccountFrozen",
+            "type": "bool"
           },
           {
-            "name": "feeAmount",
-            "type": "u64"
+            "name": "masterAuthority",
+            "type": "pubkey"
           },
           {
-            "name": "priceUsed",
-            "type": "u64"
+            "name": "pendingMasterAuthority",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "feedSymbol",
-            "type": "string"
+            "name": "pauser",
+            "type": "pubkey"
           },
           {
-            "name": "timestamp",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "oraclePaused",
-      "docs": [
-        "Emitted when oracle is paused/unpaused"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
+            "name": "minterAuthority",
             "type": "pubkey"
           },
           {
-            "name": "paused",
-            "type": "bool"
+            "name": "burner",
+            "type": "pubkey"
           },
           {
-            "name": "reason",
-            "type": "string"
+            "name": "blacklister",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "by",
-            "type": "pubkey"
+            "name": "seizer",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "timestamp",
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "oracleRedeem",
-      "docs": [
-        "Emitted on a successful oracle-priced redemption"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "type": "pubkey"
+            "name": "hookAuthority",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "redeemer",
-            "type": "pubkey"
+            "name": "paused",
+            "type": "bool"
           },
           {
-            "name": "tokenAmount",
+            "name": "totalSupply",
             "type": "u64"
           },
           {
-            "name": "usdAmount",
+            "name": "totalMintedAllTime",
             "type": "u64"
           },
           {
-            "name": "feeAmount",
+            "name": "totalBurnedAllTime",
             "type": "u64"
           },
           {
-            "name": "priceUsed",
-            "type": "u64"
+            "name": "blacklistCount",
+            "type": "u32"
           },
           {
-            "name": "feedSymbol",
-            "type": "string"
+            "name": "hookProgramId",
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "timestamp",
+            "name": "createdAt",
             "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "pendingQuote",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "docs": [
-              "The SSS token mint"
-            ],
-            "type": "pubkey"
           },
           {
-            "name": "requester",
-            "docs": [
-              "Who requested the quote"
-            ],
-            "type": "pubkey"
+            "name": "lastUpdatedAt",
+            "type": "i64"
           },
           {
-            "name": "direction",
-            "docs": [
-              "Mint or Redeem"
-            ],
-            "type": {
-              "defined": {
-                "name": "quoteDirection"
-              }
-            }
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "feedSymbol",
+            "name": "maxSupply",
             "docs": [
-              "Feed symbol used"
+              "Maximum token supply. 0 = unlimited.",
+              "Enforced in mint instruction: current_supply + amount <= max_supply (if > 0)"
             ],
-            "type": "string"
+            "type": "u64"
           },
           {
-            "name": "inputAmount",
+            "name": "totalMintOperations",
             "docs": [
-              "Input amount (USD cents for mint, token units for redeem)"
+              "Total number of successful mint operations (operation count, not amount)"
             ],
             "type": "u64"
           },
           {
-            "name": "outputAmount",
+            "name": "totalBurnOperations",
             "docs": [
-              "Output amount (token units for mint, USD cents for redeem)"
+              "Total number of successful burn operations"
             ],
             "type": "u64"
           },
           {
-            "name": "feeAmount",
+            "name": "largestSingleMint",
             "docs": [
-              "Fee amount in output units"
+              "Largest single mint amount ever (in token base units)"
             ],
             "type": "u64"
           },
           {
-            "name": "priceSnapshot",
+            "name": "lastMintAt",
             "docs": [
-              "Exact price snapshot (fixed-point * 1_000_000)"
+              "Timestamp of the most recent mint operation"
             ],
-            "type": "u64"
+            "type": "i64"
           },
           {
-            "name": "validUntil",
+            "name": "lastBurnAt",
             "docs": [
-              "Unix timestamp after which this quote is invalid"
+              "Timestamp of the most recent burn operation"
             ],
             "type": "i64"
           },
           {
-            "name": "minOutput",
+            "name": "confidentialTransfersEnabled",
             "docs": [
-              "Minimum acceptable output (slippage protection)"
+              "Whether confidential transfers are enabled on this mint"
             ],
-            "type": "u64"
+            "type": "bool"
           },
           {
-            "name": "used",
+            "name": "allowlistActive",
             "docs": [
-              "Whether consumed"
+              "Whether scoped allowlist enforcement is active.",
+              "false = SSS-1/SSS-2 (blacklist model), true = SSS-3 (allowlist model)"
             ],
             "type": "bool"
           },
           {
-            "name": "createdAt",
+            "name": "allowlistCount",
             "docs": [
-              "Creation timestamp"
+              "Count of active allowlist entries (for dashboard display)"
             ],
-            "type": "i64"
+            "type": "u32"
           },
           {
-            "name": "nonce",
+            "name": "minterCount",
             "docs": [
-              "Caller nonce to prevent PDA collision"
+              "Count of active minters"
             ],
-            "type": "u64"
+            "type": "u32"
           },
           {
-            "name": "bump",
+            "name": "multisigEnabled",
             "docs": [
-              "PDA bump"
+              "Whether SSS-3 Multisig governance is enabled"
             ],
-            "type": "u8"
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "quoteDirection",
+      "name": "stablecoinPreset",
+      "repr": {
+        "kind": "rust"
+      },
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "mint"
+            "name": "sss1"
           },
           {
-            "name": "redeem"
+            "name": "sss2"
+          },
+          {
+            "name": "custom"
+          },
+          {
+            "name": "sss3"
           }
         ]
       }
     },
     {
-      "name": "quoteGenerated",
+      "name": "swapExecuted",
       "docs": [
-        "Emitted every time a quote is requested"
+        "Emitted when a swap quote is executed: `input_amount` of `mint_in`",
+        "burned, `output_amount` of `mint_out` minted"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mintIn",
+            "type": "pubkey"
+          },
+          {
+            "name": "mintOut",
+            "type": "pubkey"
+          },
+          {
+            "name": "requester",
+            "type": "pubkey"
+          },
+          {
+            "name": "recipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "inputAmount",
+            "type": "u64"
+          },
+          {
+            "name": "outputAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "usdValue",
+            "docs": [
+              "Value of the input in the `quote_denomination` both oracles share"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "priceIn",
+            "type": "u64"
+          },
+          {
+            "name": "priceOut",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "swapQuoteGenerated",
+      "docs": [
+        "Emitted when a cross-stablecoin swap quote is stored"
       ],
       "type": {
         "kind": "struct",
@@ -1978,15 +10766,19 @@ export type SssOracle = {
             "type": "pubkey"
           },
           {
-            "name": "mint",
+            "name": "mintIn",
             "type": "pubkey"
           },
           {
-            "name": "feedSymbol",
+            "name": "mintOut",
+            "type": "pubkey"
+          },
+          {
+            "name": "feedSymbolIn",
             "type": "string"
           },
           {
-            "name": "direction",
+            "name": "feedSymbolOut",
             "type": "string"
           },
           {
@@ -2002,9 +10794,54 @@ export type SssOracle = {
             "type": "u64"
           },
           {
-            "name": "priceUsed",
+            "name": "usdValue",
+            "docs": [
+              "Value of the input in the `quote_denomination` both oracles share",
+              "(not necessarily USD)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "priceIn",
+            "type": "u64"
+          },
+          {
+            "name": "priceOut",
             "type": "u64"
           },
+          {
+            "name": "sourcesIn",
+            "docs": [
+              "Per-source readings of the input feed"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "sourceReport"
+                }
+              }
+            }
+          },
+          {
+            "name": "sourcesOut",
+            "docs": [
+              "Per-source readings of the output feed"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "sourceReport"
+                }
+              }
+            }
+          },
+          {
+            "name": "manualOverride",
+            "docs": [
+              "Either leg priced off an emergency manual price"
+            ],
+            "type": "bool"
+          },
           {
             "name": "validUntil",
             "type": "i64"
@@ -2017,7 +10854,7 @@ export type SssOracle = {
       }
     },
     {
-      "name": "quoteResult",
+      "name": "swapQuoteResult",
       "type": {
         "kind": "struct",
         "fields": [
@@ -2030,7 +10867,11 @@ export type SssOracle = {
             "type": "u64"
           },
           {
-            "name": "priceUsed",
+            "name": "priceIn",
+            "type": "u64"
+          },
+          {
+            "name": "priceOut",
             "type": "u64"
           },
           {
@@ -2040,38 +10881,33 @@ export type SssOracle = {
           {
             "name": "quoteAccount",
             "type": "pubkey"
+          },
+          {
+            "name": "tripped",
+            "docs": [
+              "True when either feed tripped its oracle's circuit breaker: that",
+              "oracle is now paused, no quote was stored and the amounts are zero"
+            ],
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "registerFeedParams",
+      "name": "unpauseParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "symbol",
-            "type": "string"
-          },
-          {
-            "name": "feedType",
+            "name": "acknowledge",
+            "docs": [
+              "Must equal `oracle_config.pause_code`"
+            ],
             "type": {
               "defined": {
-                "name": "feedType"
+                "name": "pauseReason"
               }
             }
-          },
-          {
-            "name": "baseCurrency",
-            "type": "string"
-          },
-          {
-            "name": "quoteCurrency",
-            "type": "string"
-          },
-          {
-            "name": "decimals",
-            "type": "u8"
           }
         ]
       }
@@ -2084,10 +10920,17 @@ export type SssOracle = {
           {
             "name": "newMultiplier",
             "docs": [
-              "New CPI multiplier (fixed-point * 1_000_000). 1.083 = 1_083_000"
+              "Target CPI multiplier (fixed-point * 1_000_000). 1.083 = 1_083_000"
             ],
             "type": "u64"
           },
+          {
+            "name": "accrualWindowSecs",
+            "docs": [
+              "Seconds over which the multiplier moves to the target (0 = at once)"
+            ],
+            "type": "i64"
+          },
           {
             "name": "referenceMonth",
             "docs": [
@@ -2104,6 +10947,121 @@ export type SssOracle = {
           }
         ]
       }
+    },
+    {
+      "name": "updatePsmCollateralParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "debtCeilingUsd",
+            "docs": [
+              "USD, in the oracle's quote decimals"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "active",
+            "type": {
+              "option": "bool"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "usageWindow",
+      "docs": [
+        "Volume in the oracle's `quote_denomination` units over the trailing `USAGE_WINDOW_SECS`, kept as a sliding-window",
+        "counter: the volume of the current day-aligned window plus the part of",
+        "the previous window's that still falls in the trailing one."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "windowStart",
+            "docs": [
+              "Start of the current window"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "currentUsd",
+            "docs": [
+              "`quote_denomination` units recorded in the current window"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "previousUsd",
+            "docs": [
+              "`quote_denomination` units recorded in the window before it"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userUsage",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The SSS token mint"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "docs": [
+              "The requester this usage belongs to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "mintUsage",
+            "docs": [
+              "Value minted in the rolling window (`quote_denomination` units)"
+            ],
+            "type": {
+              "defined": {
+                "name": "usageWindow"
+              }
+            }
+          },
+          {
+            "name": "redeemUsage",
+            "docs": [
+              "Value redeemed in the rolling window (`quote_denomination` units)"
+            ],
+            "type": {
+              "defined": {
+                "name": "usageWindow"
+              }
+            }
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     }
   ]
 };
@@ -1,28 +1,49 @@
 /**
  * Integration Test: Oracle Lifecycle
  *
- * Flow: initializeRegistry → registerFeed → initializeOracle → simulateMintQuote → simulateRedeemQuote
+ * Flow: initializeRegistry → registerFeed → initializeOracle → simulateMintQuote → simulateRedeemQuote,
+ * then quote execution through the sss-core CPIs.
  *
- * NOTE: On-chain getMintQuote/getRedeemQuote require a live Switchboard feed account.
- * On localnet, these use a cloned/mock feed. The integration test covers the full
- * SDK flow for registry and config initialization, plus pure-TS simulation.
+ * NOTE: On-chain quotes need a price source updated within the last 60 s (the
+ * parsers' hard age cap). The execution suite reads it from ORACLE_TEST_SOURCE /
+ * ORACLE_TEST_SOURCE_KIND, defaulting to the BRLUSD devnet Switchboard feed cloned
+ * to localnet (see tests/README.md). Without a fresh source those tests log and
+ * return early; registry setup and pure-TS simulation always run.
  */
 import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
 import {
     Keypair,
     LAMPORTS_PER_SOL,
     PublicKey,
+    SystemProgram,
+    Transaction,
 } from "@solana/web3.js";
+import {
+    TOKEN_2022_PROGRAM_ID,
+    TOKEN_PROGRAM_ID,
+    createAssociatedTokenAccountInstruction,
+    createMint,
+    getAccount,
+    getAssociatedTokenAddressSync,
+    getMint,
+    mintTo,
+} from "@solana/spl-token";
 import { expect } from "chai";
 import {
     OracleModule,
     FeedType,
+    SourceKind,
     KNOWN_FEEDS,
     PRICE_SCALE,
     CPI_SCALE,
+    InitializeOracleParams,
 } from "../../sdk/src/modules/oracle";
 import oracleIdl from "../../sdk/src/idl/sss_oracle.json";
-import { SolanaNetwork } from "../../sdk/src/types";
+import { SolanaStablecoin } from "../../sdk/src/SolanaStablecoin";
+import { StablecoinPreset, SolanaNetwork } from "../../sdk/src/types";
+import { SssCore } from "../../target/types/sss_core";
+import { SssOracle } from "../../target/types/sss_oracle";
 
 // Oracle Program ID (from Anchor.toml / environment / IDL)
 const ORACLE_PROGRAM_ID = new PublicKey(
@@ -137,7 +158,7 @@ describe("Oracle Module Integration — initRegistry → registerFeed → initOr
             baseCurrency: "BRL",
             quoteCurrency: "USD",
             decimals: 8,
-            switchboardFeed: BRLUSD_DEVNET_FEED,
+            sources: [{ account: BRLUSD_DEVNET_FEED, kind: SourceKind.Switchboard }],
         });
         expect(txSig).to.be.a("string");
         console.log("  → registerFeed tx:", txSig);
@@ -146,6 +167,7 @@ describe("Oracle Module Integration — initRegistry → registerFeed → initOr
         const brlusd = feeds2.find((f) => f.symbol === "BRLUSD");
         expect(brlusd).to.exist;
         expect(brlusd!.feedType).to.equal("inverse");
+        expect(brlusd!.sources[0].account.toBase58()).to.equal(BRLUSD_DEVNET_FEED.toBase58());
     });
 
     // ─── 5. Local Simulation Tests (No RPC Required) ────────────────────────────
@@ -247,3 +269,692 @@ describe("Oracle Module Integration — initRegistry → registerFeed → initOr
         });
     });
 });
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Quote execution — every path that moves tokens through sss-core
+// ═══════════════════════════════════════════════════════════════════════════════
+describe("Oracle execution — sss-core mint/burn CPIs against a live price source", () => {
+    anchor.setProvider(anchor.AnchorProvider.env());
+
+    const provider = anchor.getProvider() as anchor.AnchorProvider;
+    const connection = provider.connection;
+    const authority = (provider.wallet as anchor.Wallet).payer;
+    const program = anchor.workspace.SssOracle as Program<SssOracle>;
+    const coreProgram = anchor.workspace.SssCore as Program<SssCore>;
+
+    const oracle = new OracleModule(SolanaNetwork.LOCALNET);
+
+    // Price source backing the test feed, and the feed's own symbol so its
+    // parameters are known regardless of what earlier suites registered.
+    const SOURCE = new PublicKey(process.env.ORACLE_TEST_SOURCE ?? KNOWN_FEEDS.BRLUSD.devnet);
+    const SOURCE_KIND = (process.env.ORACLE_TEST_SOURCE_KIND as SourceKind) ?? SourceKind.Switchboard;
+    const FEED = "BRLEXEC";
+
+    const user1 = Keypair.generate();
+
+    let live = false;
+    let mintA: PublicKey;
+    let sdkA: SolanaStablecoin;
+    let oracleA: PublicKey;
+    let feeVaultA: PublicKey;
+    let escrowA: PublicKey;
+    let user1AtaA: PublicKey;
+    let settlementA: PublicKey;
+
+    let lastNonce = BigInt(Date.now());
+    const nextNonce = (): bigint => ++lastNonce;
+
+    const tokenBalance = async (account: PublicKey, programId = TOKEN_2022_PROGRAM_ID): Promise<number> =>
+        Number((await getAccount(connection, account, "confirmed", programId)).amount);
+
+    const supply = async (mint: PublicKey): Promise<number> =>
+        Number((await getMint(connection, mint, "confirmed", TOKEN_2022_PROGRAM_ID)).supply);
+
+    const skipUnlessLive = (): boolean => {
+        if (!live) console.log("  → No fresh price source at", SOURCE.toBase58(), "— skipping.");
+        return !live;
+    };
+
+    /** Resolve to the error `promise` rejects with, failing if it resolves. */
+    const expectError = async (promise: Promise<unknown>, code: string): Promise<void> => {
+        let error: any = null;
+        try {
+            await promise;
+        } catch (err) {
+            error = err;
+        }
+        expect(error, `expected ${code}`).to.not.be.null;
+        expect(String(error?.message ?? error)).to.include(code);
+        console.log("  → Correctly rejected:", code);
+    };
+
+    const createAta = async (
+        mint: PublicKey,
+        owner: PublicKey,
+        tokenProgram = TOKEN_2022_PROGRAM_ID,
+    ): Promise<PublicKey> => {
+        const ata = getAssociatedTokenAddressSync(mint, owner, true, tokenProgram);
+        if (!(await connection.getAccountInfo(ata))) {
+            await provider.sendAndConfirm(
+                new Transaction().add(
+                    createAssociatedTokenAccountInstruction(authority.publicKey, ata, owner, mint, tokenProgram)
+                ),
+                [authority]
+            );
+        }
+        return ata;
+    };
+
+    const createStablecoin = async (symbol: string, preset: StablecoinPreset): Promise<SolanaStablecoin> => {
+        const { mintAddress } = await SolanaStablecoin.create(
+            {
+                name: `${symbol} Oracle Test`,
+                symbol,
+                uri: `https://example.com/${symbol.toLowerCase()}.json`,
+                decimals: 6,
+                preset,
+                authority,
+                ...(preset === StablecoinPreset.SSS_2
+                    ? { blacklister: authority.publicKey, seizer: authority.publicKey }
+                    : {}),
+            },
+            SolanaNetwork.LOCALNET
+        );
+        return SolanaStablecoin.load(SolanaNetwork.LOCALNET, mintAddress);
+    };
+
+    /**
+     * Initialize an oracle for `sdk`'s mint on the test feed and wire it into
+     * sss-core: the oracle config PDA becomes a minter and the burner, and the
+     * fee vault and escrow are created.
+     */
+    const setUpOracle = async (
+        sdk: SolanaStablecoin,
+        overrides: Partial<InitializeOracleParams> = {},
+    ): Promise<PublicKey> => {
+        const mint = sdk.mintAddress;
+        const [oraclePda] = OracleModule.findOracleConfigPda(mint, ORACLE_PROGRAM_ID);
+
+        await oracle.initializeOracle(authority, ORACLE_PROGRAM_ID, {
+            mint,
+            feedSymbol: FEED,
+            description: "Oracle execution test",
+            maxStalenessSecs: 60,
+            mintFeeBps: 30,
+            redeemFeeBps: 30,
+            maxConfidenceBps: 0,
+            quoteValiditySecs: 60,
+            cpiMultiplier: CPI_SCALE,
+            cpiMinUpdateInterval: 0,
+            cpiDataSource: "",
+            configTimelockSecs: 3_600,
+            ...overrides,
+        });
+        await sdk.addMinter(authority, oraclePda, { amount: 1_000_000_000_000 });
+        await sdk.updateRoles(authority, { newBurner: oraclePda });
+
+        await program.methods
+            .initializeFeeVault()
+            .accountsPartial({
+                payer: authority.publicKey,
+                feeManager: authority.publicKey,
+                oracleConfig: oraclePda,
+                mint,
+                feeVault: OracleModule.findFeeVaultPda(mint, ORACLE_PROGRAM_ID)[0],
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([authority])
+            .rpc();
+        await createAta(mint, oraclePda);
+
+        return oraclePda;
+    };
+
+    before(async () => {
+        const sig = await connection.requestAirdrop(user1.publicKey, 10 * LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig, "confirmed");
+
+        if (!(await connection.getAccountInfo(SOURCE))) {
+            console.log("  → Price source not on this cluster — on-chain execution tests will skip.");
+            return;
+        }
+
+        const [registryPda] = OracleModule.findRegistryPda(ORACLE_PROGRAM_ID);
+        if (!(await connection.getAccountInfo(registryPda))) {
+            await oracle.initializeRegistry(authority, ORACLE_PROGRAM_ID);
+        }
+        const [feedPda] = OracleModule.findFeedPda(FEED, ORACLE_PROGRAM_ID);
+        if (!(await connection.getAccountInfo(feedPda))) {
+            await oracle.registerFeed(authority, ORACLE_PROGRAM_ID, {
+                symbol: FEED,
+                feedType: FeedType.Inverse,
+                baseCurrency: "BRL",
+                quoteCurrency: "USD",
+                decimals: 6,
+                sources: [{ account: SOURCE, kind: SOURCE_KIND }],
+            });
+        }
+
+        sdkA = await createStablecoin("BRLA", StablecoinPreset.SSS_1);
+        mintA = sdkA.mintAddress;
+        oracleA = await setUpOracle(sdkA);
+        [feeVaultA] = OracleModule.findFeeVaultPda(mintA, ORACLE_PROGRAM_ID);
+        escrowA = getAssociatedTokenAddressSync(mintA, oracleA, true, TOKEN_2022_PROGRAM_ID);
+        user1AtaA = await createAta(mintA, user1.publicKey);
+
+        // Probe the source: a stale reading means nobody is updating it here.
+        try {
+            await oracle.getMintQuote(user1, ORACLE_PROGRAM_ID, mintA, {
+                inputAmount: 100,
+                minOutput: 1,
+                nonce: nextNonce(),
+            });
+            live = true;
+        } catch (err: any) {
+            console.log("  → Probe quote failed (", err?.message ?? err, ") — on-chain execution tests will skip.");
+        }
+    });
+
+    // ─── mint_with_oracle: sss-core mint CPI ──────────────────────────────────
+    it("mint_with_oracle mints the quote through sss-core and credits the fee vault", async () => {
+        if (skipUnlessLive()) return;
+
+        const supplyBefore = await supply(mintA);
+        const nonce = nextNonce();
+        const quote = await oracle.getMintQuote(user1, ORACLE_PROGRAM_ID, mintA, {
+            inputAmount: 10_000, // $100.00
+            minOutput: 1,
+            nonce,
+        });
+
+        const txSig = await oracle.mintWithOracle(user1, ORACLE_PROGRAM_ID, mintA, nonce);
+        expect(txSig).to.be.a("string");
+        console.log("  → mintWithOracle tx:", txSig);
+
+        expect(await tokenBalance(user1AtaA)).to.equal(quote.outputAmount);
+        expect(await tokenBalance(feeVaultA)).to.equal(quote.feeAmount);
+        expect(await supply(mintA)).to.equal(supplyBefore + quote.outputAmount + quote.feeAmount);
+        expect(await connection.getAccountInfo(quote.quoteAccount)).to.be.null; // closed to requester
+
+        const info = await oracle.getOracleInfo(ORACLE_PROGRAM_ID, mintA);
+        expect(Number(info.totalMintedUsd)).to.equal(10_000);
+    });
+
+    it("mint_with_oracle rejects a quote that was already executed", async () => {
+        if (skipUnlessLive()) return;
+
+        const nonce = nextNonce();
+        await oracle.getMintQuote(user1, ORACLE_PROGRAM_ID, mintA, { inputAmount: 1_000, minOutput: 1, nonce });
+        await oracle.mintWithOracle(user1, ORACLE_PROGRAM_ID, mintA, nonce);
+
+        // The quote account was closed, so a replay fails to load it
+        await expectError(oracle.mintWithOracle(user1, ORACLE_PROGRAM_ID, mintA, nonce), "AccountNotInitialized");
+    });
+
+    // ─── redeem_with_oracle: escrow + sss-core burn CPI ───────────────────────
+    it("redeem_with_oracle burns through the escrow and records the amount owed", async () => {
+        if (skipUnlessLive()) return;
+
+        const balanceBefore = await tokenBalance(user1AtaA);
+        const vaultBefore = await tokenBalance(feeVaultA);
+        const supplyBefore = await supply(mintA);
+        const amount = Math.floor(balanceBefore / 2);
+
+        const nonce = nextNonce();
+        const quote = await oracle.getRedeemQuote(user1, ORACLE_PROGRAM_ID, mintA, {
+            inputAmount: amount,
+            minOutput: 1,
+            nonce,
+        });
+        const txSig = await oracle.redeemWithOracle(user1, ORACLE_PROGRAM_ID, mintA, nonce);
+        console.log("  → redeemWithOracle tx:", txSig);
+
+        // Fee share to the vault, the rest escrowed and burned in the same instruction
+        const feeTokens = (await tokenBalance(feeVaultA)) - vaultBefore;
+        expect(feeTokens).to.be.greaterThan(0);
+        expect(await tokenBalance(user1AtaA)).to.equal(balanceBefore - amount);
+        expect(await tokenBalance(escrowA)).to.equal(0);
+        expect(await supply(mintA)).to.equal(supplyBefore - (amount - feeTokens));
+
+        [settlementA] = OracleModule.findSettlementPda(mintA, user1.publicKey, nonce, ORACLE_PROGRAM_ID);
+        const settlement = await program.account.redemptionSettlement.fetch(settlementA);
+        expect(settlement.redeemer.toBase58()).to.equal(user1.publicKey.toBase58());
+        expect(settlement.tokenAmount.toNumber()).to.equal(amount);
+        expect(settlement.usdOwed.toNumber()).to.equal(quote.outputAmount);
+        expect(settlement.feeAmount.toNumber()).to.equal(quote.feeAmount);
+        expect(settlement.settled).to.be.false;
+    });
+
+    it("confirm_settlement records the fiat payout once", async () => {
+        if (skipUnlessLive()) return;
+
+        await program.methods
+            .confirmSettlement("wire-2026-0001")
+            .accountsPartial({
+                admin: authority.publicKey,
+                oracleConfig: oracleA,
+                settlement: settlementA,
+            })
+            .signers([authority])
+            .rpc();
+
+        const settlement = await program.account.redemptionSettlement.fetch(settlementA);
+        expect(settlement.settled).to.be.true;
+        expect(settlement.payoutReference).to.equal("wire-2026-0001");
+        expect(settlement.settledAt.toNumber()).to.be.greaterThan(0);
+
+        await expectError(
+            program.methods
+                .confirmSettlement("wire-2026-0002")
+                .accountsPartial({
+                    admin: authority.publicKey,
+                    oracleConfig: oracleA,
+                    settlement: settlementA,
+                })
+                .signers([authority])
+                .rpc(),
+            "AlreadySettled"
+        );
+    });
+
+    it("confirm_settlement rejects a signer other than the oracle admin", async () => {
+        if (skipUnlessLive()) return;
+
+        await expectError(
+            program.methods
+                .confirmSettlement("wire-forged")
+                .accountsPartial({
+                    admin: user1.publicKey,
+                    oracleConfig: oracleA,
+                    settlement: settlementA,
+                })
+                .signers([user1])
+                .rpc(),
+            "Unauthorized"
+        );
+    });
+
+    // ─── withdraw_fees: fee-vault split payouts ───────────────────────────────
+    it("withdraw_fees pays each split its share and the remainder to the treasury", async () => {
+        if (skipUnlessLive()) return;
+
+        const partner = Keypair.generate();
+        const partnerAta = await createAta(mintA, partner.publicKey);
+        const treasuryAta = await createAta(mintA, authority.publicKey);
+
+        await program.methods
+            .setFeeSplits([{ recipient: partnerAta, bps: 3_333 }])
+            .accountsPartial({ feeManager: authority.publicKey, oracleConfig: oracleA })
+            .signers([authority])
+            .rpc();
+
+        const withdraw = (amount: number, recipients: PublicKey[]) =>
+            program.methods
+                .withdrawFees(new BN(amount))
+                .accountsPartial({
+                    feeManager: authority.publicKey,
+                    oracleConfig: oracleA,
+                    mint: mintA,
+                    feeVault: feeVaultA,
+                    treasury: treasuryAta,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .remainingAccounts(recipients.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+                .signers([authority])
+                .rpc();
+
+        const vault = await tokenBalance(feeVaultA);
+        expect(vault).to.be.greaterThan(0);
+
+        // The split recipients must be passed, in fee_splits order
+        await expectError(withdraw(vault, []), "FeeSplitAccountMismatch");
+        await expectError(withdraw(vault, [treasuryAta]), "FeeSplitAccountMismatch");
+
+        const treasuryBefore = await tokenBalance(treasuryAta);
+        await withdraw(vault, [partnerAta]);
+
+        const partnerShare = Math.floor((vault * 3_333) / 10_000);
+        expect(await tokenBalance(partnerAta)).to.equal(partnerShare);
+        expect(await tokenBalance(treasuryAta)).to.equal(treasuryBefore + vault - partnerShare);
+        expect(await tokenBalance(feeVaultA)).to.equal(0);
+
+        await expectError(withdraw(1, [partnerAta]), "InsufficientFees");
+    });
+
+    // ─── swap_with_oracle: escrow + burn of A, mint of C ──────────────────────
+    it("swap_with_oracle burns token A through the escrow and mints token C", async () => {
+        if (skipUnlessLive()) return;
+
+        const sdkC = await createStablecoin("BRLC", StablecoinPreset.SSS_1);
+        const mintC = sdkC.mintAddress;
+        const oracleC = await setUpOracle(sdkC, { swapFeeBps: 50 });
+        const [feeVaultC] = OracleModule.findFeeVaultPda(mintC, ORACLE_PROGRAM_ID);
+        const user1AtaC = await createAta(mintC, user1.publicKey);
+        const [feedPda] = OracleModule.findFeedPda(FEED, ORACLE_PROGRAM_ID);
+
+        const amount = 1_000_000; // 1 BRLA
+        const balanceBefore = await tokenBalance(user1AtaA);
+        const supplyBefore = await supply(mintA);
+
+        const nonce = nextNonce();
+        const [quotePda] = OracleModule.findSwapQuotePda(mintA, user1.publicKey, nonce, ORACLE_PROGRAM_ID);
+        const source = { pubkey: SOURCE, isSigner: false, isWritable: false };
+        await program.methods
+            .getSwapQuote({ inputAmount: new BN(amount), minOutput: new BN(1), nonce: new BN(nonce.toString()) })
+            .accountsPartial({
+                requester: user1.publicKey,
+                oracleIn: oracleA,
+                oracleOut: oracleC,
+                feedIn: feedPda,
+                feedOut: feedPda,
+                accumulatorIn: null,
+                accumulatorOut: null,
+                quote: quotePda,
+                systemProgram: SystemProgram.programId,
+            })
+            .remainingAccounts([source, source]) // feed_in's sources, then feed_out's
+            .signers([user1])
+            .rpc();
+
+        const quote = await program.account.pendingSwapQuote.fetch(quotePda);
+        expect(quote.mintOut.toBase58()).to.equal(mintC.toBase58());
+        expect(quote.inputAmount.toNumber()).to.equal(amount);
+        expect(quote.feeAmount.toNumber()).to.be.greaterThan(0);
+
+        const txSig = await program.methods
+            .swapWithOracle()
+            .accountsPartial({
+                requester: user1.publicKey,
+                oracleIn: oracleA,
+                oracleOut: oracleC,
+                quote: quotePda,
+                coreConfigIn: OracleModule.findCoreConfigPda(mintA)[0],
+                mintIn: mintA,
+                source: user1AtaA,
+                escrowIn: escrowA,
+                requesterAllowlistIn: null,
+                requesterBlacklistIn: null,
+                coreConfigOut: OracleModule.findCoreConfigPda(mintC)[0],
+                minterConfigOut: OracleModule.findMinterConfigPda(mintC, oracleC)[0],
+                mintOut: mintC,
+                destination: user1AtaC,
+                feeVaultOut: feeVaultC,
+                requesterAllowlist: null,
+                requesterBlacklist: null,
+                recipientAllowlist: null,
+                recipientBlacklist: null,
+                userUsageIn: null,
+                userUsageOut: null,
+                sssCoreProgram: coreProgram.programId,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+            })
+            .signers([user1])
+            .rpc();
+        console.log("  → swapWithOracle tx:", txSig);
+
+        // Token A escrowed and burned, token C minted with the fee to C's vault
+        expect(await tokenBalance(user1AtaA)).to.equal(balanceBefore - amount);
+        expect(await tokenBalance(escrowA)).to.equal(0);
+        expect(await supply(mintA)).to.equal(supplyBefore - amount);
+        expect(await tokenBalance(user1AtaC)).to.equal(quote.outputAmount.toNumber());
+        expect(await tokenBalance(feeVaultC)).to.equal(quote.feeAmount.toNumber());
+        expect(await connection.getAccountInfo(quotePda)).to.be.null;
+    });
+
+    // ─── PSM: collateral in, sss-core mint; sss-core burn, collateral out ─────
+    it("mint_with_psm and redeem_with_psm move collateral at par around the sss-core CPIs", async () => {
+        if (skipUnlessLive()) return;
+
+        const sdkP = await createStablecoin("BRLP", StablecoinPreset.SSS_1);
+        const mintP = sdkP.mintAddress;
+        const oracleP = await setUpOracle(sdkP);
+        const [feeVaultP] = OracleModule.findFeeVaultPda(mintP, ORACLE_PROGRAM_ID);
+        const escrowP = getAssociatedTokenAddressSync(mintP, oracleP, true, TOKEN_2022_PROGRAM_ID);
+        const user1AtaP = await createAta(mintP, user1.publicKey);
+
+        // A classic SPL "USDC" with 6 decimals as the collateral
+        const usdc = await createMint(connection, authority, authority.publicKey, null, 6);
+        const user1Usdc = await createAta(usdc, user1.publicKey, TOKEN_PROGRAM_ID);
+        await mintTo(connection, authority, usdc, user1Usdc, authority, 1_000_000_000); // 1,000 USDC
+
+        const [psmPda] = OracleModule.findPsmCollateralPda(mintP, usdc, ORACLE_PROGRAM_ID);
+        const [vault] = PublicKey.findProgramAddressSync(
+            [Buffer.from("sss-psm-vault"), mintP.toBuffer(), usdc.toBuffer()],
+            ORACLE_PROGRAM_ID
+        );
+        await program.methods
+            .registerPsmCollateral(new BN(15_000)) // $150.00 debt ceiling
+            .accountsPartial({
+                payer: authority.publicKey,
+                admin: authority.publicKey,
+                oracleConfig: oracleP,
+                collateralMint: usdc,
+                psmCollateral: psmPda,
+                collateralVault: vault,
+                collateralTokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers([authority])
+            .rpc();
+
+        const mintWithPsm = (nonce: bigint) =>
+            program.methods
+                .mintWithPsm()
+                .accountsPartial({
+                    requester: user1.publicKey,
+                    oracleConfig: oracleP,
+                    quote: OracleModule.findQuotePda(mintP, user1.publicKey, nonce, ORACLE_PROGRAM_ID)[0],
+                    psmCollateral: psmPda,
+                    collateralMint: usdc,
+                    collateralSource: user1Usdc,
+                    collateralVault: vault,
+                    coreConfig: OracleModule.findCoreConfigPda(mintP)[0],
+                    minterConfig: OracleModule.findMinterConfigPda(mintP, oracleP)[0],
+                    mint: mintP,
+                    destination: user1AtaP,
+                    feeVault: feeVaultP,
+                    requesterAllowlist: null,
+                    requesterBlacklist: null,
+                    recipientAllowlist: null,
+                    recipientBlacklist: null,
+                    userUsage: null,
+                    sssCoreProgram: coreProgram.programId,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                    collateralTokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .signers([user1])
+                .rpc();
+
+        // $100.00 in: 100 USDC to the vault, the quote's tokens minted
+        const mintNonce = nextNonce();
+        const mintQuote = await oracle.getMintQuote(user1, ORACLE_PROGRAM_ID, mintP, {
+            inputAmount: 10_000,
+            minOutput: 1,
+            nonce: mintNonce,
+        });
+        await mintWithPsm(mintNonce);
+
+        expect(await tokenBalance(vault, TOKEN_PROGRAM_ID)).to.equal(100_000_000);
+        expect(await tokenBalance(user1Usdc, TOKEN_PROGRAM_ID)).to.equal(900_000_000);
+        expect(await tokenBalance(user1AtaP)).to.equal(mintQuote.outputAmount);
+        expect(await tokenBalance(feeVaultP)).to.equal(mintQuote.feeAmount);
+        expect((await program.account.psmCollateral.fetch(psmPda)).debtUsd.toNumber()).to.equal(10_000);
+
+        // Another $100.00 would take the debt past the $150.00 ceiling
+        const overNonce = nextNonce();
+        await oracle.getMintQuote(user1, ORACLE_PROGRAM_ID, mintP, { inputAmount: 10_000, minOutput: 1, nonce: overNonce });
+        await expectError(mintWithPsm(overNonce), "DebtCeilingExceeded");
+
+        // Redeem half: the net quote value comes back in USDC, rounded down
+        const amount = Math.floor(mintQuote.outputAmount / 2);
+        const supplyBefore = await supply(mintP);
+        const vaultFeesBefore = await tokenBalance(feeVaultP);
+        const redeemNonce = nextNonce();
+        const redeemQuote = await oracle.getRedeemQuote(user1, ORACLE_PROGRAM_ID, mintP, {
+            inputAmount: amount,
+            minOutput: 1,
+            nonce: redeemNonce,
+        });
+        await program.methods
+            .redeemWithPsm()
+            .accountsPartial({
+                requester: user1.publicKey,
+                oracleConfig: oracleP,
+                quote: OracleModule.findQuotePda(mintP, user1.publicKey, redeemNonce, ORACLE_PROGRAM_ID)[0],
+                psmCollateral: psmPda,
+                collateralMint: usdc,
+                collateralVault: vault,
+                collateralDestination: user1Usdc,
+                coreConfig: OracleModule.findCoreConfigPda(mintP)[0],
+                mint: mintP,
+                source: user1AtaP,
+                escrow: escrowP,
+                feeVault: feeVaultP,
+                requesterAllowlist: null,
+                requesterBlacklist: null,
+                userUsage: null,
+                sssCoreProgram: coreProgram.programId,
+                tokenProgram: TOKEN_2022_PROGRAM_ID,
+                collateralTokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .signers([user1])
+            .rpc();
+
+        const released = redeemQuote.outputAmount * 10_000; // cents → 6-decimal USDC
+        const feeTokens = (await tokenBalance(feeVaultP)) - vaultFeesBefore;
+        expect(await tokenBalance(vault, TOKEN_PROGRAM_ID)).to.equal(100_000_000 - released);
+        expect(await tokenBalance(user1Usdc, TOKEN_PROGRAM_ID)).to.equal(900_000_000 + released);
+        expect(await tokenBalance(user1AtaP)).to.equal(mintQuote.outputAmount - amount);
+        expect(await tokenBalance(escrowP)).to.equal(0);
+        expect(await supply(mintP)).to.equal(supplyBefore - (amount - feeTokens));
+
+        const psm = await program.account.psmCollateral.fetch(psmPda);
+        expect(psm.debtUsd.toNumber()).to.equal(10_000 - redeemQuote.outputAmount);
+        expect(psm.totalReleased.toNumber()).to.equal(released);
+    });
+
+    // ─── mint_at_market: price and sss-core mint in one instruction ───────────
+    it("mint_at_market mints at the live price within the caller's max price", async () => {
+        if (skipUnlessLive()) return;
+
+        const mintAtMarket = (inputAmount: number, maxPrice: BN) =>
+            program.methods
+                .mintAtMarket({ inputAmount: new BN(inputAmount), minOutput: new BN(1), maxPrice })
+                .accountsPartial({
+                    requester: user1.publicKey,
+                    oracleConfig: oracleA,
+                    feed: OracleModule.findFeedPda(FEED, ORACLE_PROGRAM_ID)[0],
+                    accumulator: null,
+                    marketFeed: null,
+                    coreConfig: OracleModule.findCoreConfigPda(mintA)[0],
+                    minterConfig: OracleModule.findMinterConfigPda(mintA, oracleA)[0],
+                    mint: mintA,
+                    destination: user1AtaA,
+                    feeVault: feeVaultA,
+                    requesterAllowlist: null,
+                    requesterBlacklist: null,
+                    recipientAllowlist: null,
+                    recipientBlacklist: null,
+                    userUsage: null,
+                    sssCoreProgram: coreProgram.programId,
+                    tokenProgram: TOKEN_2022_PROGRAM_ID,
+                })
+                .remainingAccounts([{ pubkey: SOURCE, isSigner: false, isWritable: false }])
+                .signers([user1])
+                .rpc();
+
+        // No whole token costs less than 1e-6 USD
+        await expectError(mintAtMarket(5_000, new BN(1)), "PriceAboveMax");
+
+        const balanceBefore = await tokenBalance(user1AtaA);
+        const vaultBefore = await tokenBalance(feeVaultA);
+        const supplyBefore = await supply(mintA);
+        const mintedUsdBefore = Number((await oracle.getOracleInfo(ORACLE_PROGRAM_ID, mintA)).totalMintedUsd);
+
+        await mintAtMarket(5_000, new BN("18446744073709551615")); // $50.00, any price
+
+        const minted = (await tokenBalance(user1AtaA)) - balanceBefore;
+        const fee = (await tokenBalance(feeVaultA)) - vaultBefore;
+        expect(minted).to.be.greaterThan(0);
+        expect(fee).to.be.greaterThan(0);
+        expect(await supply(mintA)).to.equal(supplyBefore + minted + fee);
+        expect(Number((await oracle.getOracleInfo(ORACLE_PROGRAM_ID, mintA)).totalMintedUsd))
+            .to.equal(mintedUsdBefore + 5_000);
+    });
+
+    // ─── Compliance: sss-core blacklist enforced on quotes and execution ──────
+    it("rejects a blacklisted requester and a blacklisted recipient on an SSS-2 mint", async () => {
+        if (skipUnlessLive()) return;
+
+        const sdkB = await createStablecoin("BRLB", StablecoinPreset.SSS_2);
+        const mintB = sdkB.mintAddress;
+        await setUpOracle(sdkB);
+
+        const user2 = Keypair.generate();
+        const sig = await connection.requestAirdrop(user2.publicKey, LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig, "confirmed");
+
+        // SSS-2 accounts start frozen; blacklisting freezes user2's again
+        const user1AtaB = await createAta(mintB, user1.publicKey);
+        await sdkB.thaw(authority, user1AtaB);
+        await createAta(mintB, user2.publicKey);
+        await sdkB.compliance.blacklistAdd(authority, { address: user2.publicKey, reason: "oracle test" });
+
+        // A blacklisted wallet cannot even take a quote
+        await expectError(
+            oracle.getMintQuote(user2, ORACLE_PROGRAM_ID, mintB, { inputAmount: 1_000, minOutput: 1, nonce: nextNonce() }),
+            "ParticipantBlacklisted"
+        );
+
+        // A clean requester cannot mint to a blacklisted wallet
+        const nonce = nextNonce();
+        const quote = await oracle.getMintQuote(user1, ORACLE_PROGRAM_ID, mintB, { inputAmount: 1_000, minOutput: 1, nonce });
+        await expectError(
+            oracle.mintWithOracle(user1, ORACLE_PROGRAM_ID, mintB, nonce, user2.publicKey),
+            "ParticipantBlacklisted"
+        );
+
+        // ...but the same quote still executes to the requester
+        await oracle.mintWithOracle(user1, ORACLE_PROGRAM_ID, mintB, nonce);
+        expect(await tokenBalance(user1AtaB)).to.equal(quote.outputAmount);
+    });
+
+    // ─── Issuance caps: per-user and global rolling windows ───────────────────
+    it("rejects mints past the per-user and the global mint caps", async () => {
+        if (skipUnlessLive()) return;
+
+        const sdkK = await createStablecoin("BRLK", StablecoinPreset.SSS_1);
+        const mintK = sdkK.mintAddress;
+        await setUpOracle(sdkK, { userMintCap: 5_000, globalMintCap: 8_000 }); // $50.00 / $80.00
+
+        const user3 = Keypair.generate();
+        const sig = await connection.requestAirdrop(user3.publicKey, LAMPORTS_PER_SOL);
+        await connection.confirmTransaction(sig, "confirmed");
+        await createAta(mintK, user1.publicKey);
+        await createAta(mintK, user3.publicKey);
+
+        const quoteAndMint = async (user: Keypair, inputAmount: number): Promise<string> => {
+            const nonce = nextNonce();
+            await oracle.getMintQuote(user, ORACLE_PROGRAM_ID, mintK, { inputAmount, minOutput: 1, nonce });
+            return oracle.mintWithOracle(user, ORACLE_PROGRAM_ID, mintK, nonce);
+        };
+
+        // A per-user cap needs the requester's usage account
+        await expectError(quoteAndMint(user1, 1_000), "UserUsageRequired");
+        await oracle.initializeUserUsage(authority, ORACLE_PROGRAM_ID, mintK, user1.publicKey);
+        await oracle.initializeUserUsage(authority, ORACLE_PROGRAM_ID, mintK, user3.publicKey);
+
+        // user1: $40 fits, another $20 would pass their $50 window
+        await quoteAndMint(user1, 4_000);
+        await expectError(quoteAndMint(user1, 2_000), "UserMintCapExceeded");
+
+        // user3: $30 fits, but another $20 would pass the $80 global window
+        await quoteAndMint(user3, 3_000);
+        await expectError(quoteAndMint(user3, 2_000), "GlobalMintCapExceeded");
+
+        const [usagePda] = OracleModule.findUserUsagePda(mintK, user1.publicKey, ORACLE_PROGRAM_ID);
+        const usage = await program.account.userUsage.fetch(usagePda);
+        expect(usage.mintUsage.currentUsd.toNumber()).to.equal(4_000); // the rejected mint recorded nothing
+    });
+});
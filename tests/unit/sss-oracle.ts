@@ -1,5 +1,6 @@
 import { expect } from "chai";
-import { OraclePresets, FeedType, PRICE_SCALE, CPI_SCALE } from "../../sdk/src/modules/oracle";
+import { PublicKey } from "@solana/web3.js";
+import { OracleModule, OraclePresets, FeedType, PRICE_SCALE, CPI_SCALE } from "../../sdk/src/modules/oracle";
 
 describe("OracleModule SDK — Pure TS Math Simulation", () => {
   const oracle = OraclePresets.brl(); // uses BRL as preset, but math is universal
@@ -86,3 +87,57 @@ describe("OracleModule SDK — Pure TS Math Simulation", () => {
     expect(result.gross).to.equal(10_800);
   });
 });
+
+describe("OracleModule SDK — PDA derivation", () => {
+  const programId = new PublicKey("CUKfNWS1uWT29GccLKtGMoRB1sqascAjH7GoEddHSfEi");
+  const mint = new PublicKey("So11111111111111111111111111111111111111112");
+  const wallet = new PublicKey("Vote111111111111111111111111111111111111111");
+
+  const nonceBytes = (nonce: bigint): Buffer => {
+    const buffer = Buffer.alloc(8);
+    buffer.writeBigUInt64LE(nonce);
+    return buffer;
+  };
+
+  it("Derives the settlement PDA from the redeem quote's nonce", () => {
+    const nonce = BigInt(42);
+    const [settlement] = OracleModule.findSettlementPda(mint, wallet, nonce, programId);
+    const [expected] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sss-settlement"), mint.toBuffer(), wallet.toBuffer(), nonceBytes(nonce)],
+      programId
+    );
+
+    expect(settlement.toBase58()).to.equal(expected.toBase58());
+    // A different nonce is a different settlement
+    const [other] = OracleModule.findSettlementPda(mint, wallet, nonce + BigInt(1), programId);
+    expect(other.toBase58()).to.not.equal(settlement.toBase58());
+  });
+
+  it("Derives the swap quote PDA from the input mint", () => {
+    const nonce = BigInt(7);
+    const mintOut = new PublicKey("SysvarRent111111111111111111111111111111111");
+    const [quote] = OracleModule.findSwapQuotePda(mint, wallet, nonce, programId);
+    const [expected] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sss-swap-quote"), mint.toBuffer(), wallet.toBuffer(), nonceBytes(nonce)],
+      programId
+    );
+
+    expect(quote.toBase58()).to.equal(expected.toBase58());
+    // Swapping from the other mint with the same nonce uses a different account
+    const [reverse] = OracleModule.findSwapQuotePda(mintOut, wallet, nonce, programId);
+    expect(reverse.toBase58()).to.not.equal(quote.toBase58());
+  });
+
+  it("Derives one PSM collateral PDA per collateral mint", () => {
+    const usdc = new PublicKey("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+    const [psm] = OracleModule.findPsmCollateralPda(mint, usdc, programId);
+    const [expected] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sss-psm"), mint.toBuffer(), usdc.toBuffer()],
+      programId
+    );
+
+    expect(psm.toBase58()).to.equal(expected.toBase58());
+    const [other] = OracleModule.findPsmCollateralPda(mint, wallet, programId);
+    expect(other.toBase58()).to.not.equal(psm.toBase58());
+  });
+});